@@ -1,9 +1,15 @@
-//! Object acl module
+//! Bucket and object acl module
+
+use std::time::Instant;
 
 use async_trait::async_trait;
 
 use crate::{
-    acl_common::{build_get_object_acl_request, build_put_object_acl_request, parse_object_acl_from_xml},
+    acl_common::{
+        build_get_bucket_acl_request, build_get_object_acl_request, build_put_object_acl_request, AccessAudit, BucketAccess, BucketAclResult,
+        CachedBucketAccess, ObjectAclResult,
+    },
+    bucket::BucketOperations,
     common::VersionIdOnlyOptions,
     object_common::ObjectAcl,
     Client, Result,
@@ -12,12 +18,37 @@ use crate::{
 pub type PutObjectAclOptions = VersionIdOnlyOptions;
 pub type GetObjectAclOptions = VersionIdOnlyOptions;
 
+#[async_trait]
+pub trait BucketAclOperations {
+    /// Get bucket acl and owner. All grants are collected, use `BucketAclResult::effective_acl` to get the most permissive one
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketacl>
+    async fn get_bucket_acl<S>(&self, bucket_name: S) -> Result<BucketAclResult>
+    where
+        S: AsRef<str> + Send;
+}
+
+#[async_trait]
+impl BucketAclOperations for Client {
+    /// Get bucket acl and owner. All grants are collected, use `BucketAclResult::effective_acl` to get the most permissive one
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketacl>
+    async fn get_bucket_acl<S>(&self, bucket_name: S) -> Result<BucketAclResult>
+    where
+        S: AsRef<str> + Send,
+    {
+        let request = build_get_bucket_acl_request(bucket_name.as_ref())?;
+        let (_, content) = self.do_request::<String>(request).await?;
+        BucketAclResult::from_xml(&content)
+    }
+}
+
 #[async_trait]
 pub trait ObjectAclOperations {
-    /// Get an object's acl.
+    /// Get an object's acl and owner.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobjectacl>
-    async fn get_object_acl<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectAclOptions>) -> Result<ObjectAcl>
+    async fn get_object_acl<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectAclOptions>) -> Result<ObjectAclResult>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send;
@@ -25,31 +56,45 @@ pub trait ObjectAclOperations {
     /// Put an object's acl. If you want to restore the object's acl to follow bucket acl settings, pass acl as `ObjectAcl::Default`
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobjectacl>
-    async fn put_object_acl<S1, S2>(&self, bucket_name: S1, object_key: S2, acl: ObjectAcl, options: Option<GetObjectAclOptions>) -> Result<()>
+    async fn put_object_acl<S1, S2>(&self, bucket_name: S1, object_key: S2, acl: ObjectAcl, options: Option<PutObjectAclOptions>) -> Result<()>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+
+    /// Audit the effective access of an object by combining bucket info (block public access and bucket acl) and object acl.
+    /// Bucket level settings are cached in the client for 5 minutes, so auditing many objects in the same bucket calls GetBucketInfo only once.
+    /// Call `clear_access_audit_cache` if bucket settings may have changed within that time
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketinfo>,
+    /// <https://help.aliyun.com/zh/oss/developer-reference/getobjectacl>
+    async fn audit_object_access<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<AccessAudit>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send;
+
+    /// Clear bucket level settings cached by `audit_object_access`
+    fn clear_access_audit_cache(&self);
 }
 
 #[async_trait]
 impl ObjectAclOperations for Client {
-    /// Get an object's acl.
+    /// Get an object's acl and owner.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobjectacl>
-    async fn get_object_acl<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectAclOptions>) -> Result<ObjectAcl>
+    async fn get_object_acl<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectAclOptions>) -> Result<ObjectAclResult>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send,
     {
         let request = build_get_object_acl_request(bucket_name.as_ref(), object_key.as_ref(), &options)?;
         let (_, content) = self.do_request::<String>(request).await?;
-        parse_object_acl_from_xml(&content)
+        ObjectAclResult::from_xml(&content)
     }
 
     /// Put an object's acl. If you want to restore the object's acl to follow bucket acl settings, pass acl as `ObjectAcl::Default`
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobjectacl>
-    async fn put_object_acl<S1, S2>(&self, bucket_name: S1, object_key: S2, acl: ObjectAcl, options: Option<GetObjectAclOptions>) -> Result<()>
+    async fn put_object_acl<S1, S2>(&self, bucket_name: S1, object_key: S2, acl: ObjectAcl, options: Option<PutObjectAclOptions>) -> Result<()>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send,
@@ -58,6 +103,38 @@ impl ObjectAclOperations for Client {
         let _ = self.do_request::<()>(request).await?;
         Ok(())
     }
+
+    /// Audit the effective access of an object. Bucket level settings are cached per bucket name
+    async fn audit_object_access<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<AccessAudit>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let bucket_name = bucket_name.as_ref();
+
+        // 不能跨越 await 持有锁，所以先取出缓存，没有或者已经过期的话请求之后再写回
+        let cached = self.bucket_access_cache.lock().ok().and_then(|cache| cache.get(bucket_name).copied());
+        let bucket_access = match cached.and_then(|c| c.fresh_at(Instant::now())) {
+            Some(access) => access,
+            None => {
+                let access = BucketAccess::from(&self.get_bucket_info(bucket_name).await?);
+                if let Ok(mut cache) = self.bucket_access_cache.lock() {
+                    cache.insert(bucket_name.to_string(), CachedBucketAccess::new(access));
+                }
+                access
+            }
+        };
+
+        let object_acl = self.get_object_acl(bucket_name, object_key, None).await?.acl;
+
+        Ok(AccessAudit::new(bucket_access, object_acl))
+    }
+
+    fn clear_access_audit_cache(&self) {
+        if let Ok(mut cache) = self.bucket_access_cache.lock() {
+            cache.clear();
+        }
+    }
 }
 
 #[cfg(test)]
@@ -67,7 +144,13 @@ mod test_object_acl_async {
     use reqwest::StatusCode;
     use uuid::Uuid;
 
-    use crate::{acl::ObjectAclOperations, object::ObjectOperations, object_common::ObjectAcl, Client};
+    use crate::{
+        acl::{BucketAclOperations, ObjectAclOperations},
+        bucket_common::BucketAcl,
+        object::ObjectOperations,
+        object_common::ObjectAcl,
+        Client,
+    };
 
     static INIT: Once = Once::new();
 
@@ -99,7 +182,8 @@ mod test_object_acl_async {
         let response = client.get_object_acl(bucket_name, &object_key, None).await;
         assert!(response.is_ok());
         let ret = response.unwrap();
-        assert_eq!(ObjectAcl::PublicRead, ret);
+        assert_eq!(ObjectAcl::PublicRead, ret.acl);
+        assert!(!ret.owner.id.is_empty());
 
         let url = format!("https://{}.oss-cn-beijing.aliyuncs.com/{}", bucket_name, object_key);
         let status = reqwest::get(&url).await.unwrap().status();
@@ -111,7 +195,7 @@ mod test_object_acl_async {
         let response = client.get_object_acl(bucket_name, &object_key, None).await;
         assert!(response.is_ok());
         let ret = response.unwrap();
-        assert_eq!(ObjectAcl::Default, ret);
+        assert_eq!(ObjectAcl::Default, ret.acl);
 
         let url = format!("https://{}.oss-cn-beijing.aliyuncs.com/{}", bucket_name, object_key);
         let status = reqwest::get(&url).await.unwrap().status();
@@ -119,4 +203,43 @@ mod test_object_acl_async {
 
         client.delete_object(bucket_name, &object_key, None).await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_get_bucket_acl_async() {
+        log::debug!("test get bucket acl");
+        setup();
+
+        let client = Client::from_env();
+
+        let ret = client.get_bucket_acl("yuanyq").await.unwrap();
+        assert!(!ret.access_control_list.is_empty());
+        assert_eq!(BucketAcl::Private, ret.effective_acl());
+        assert!(!ret.owner.id.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_audit_object_access_async() {
+        log::debug!("test audit object access");
+        setup();
+
+        let client = Client::from_env();
+
+        let bucket_name = "yuanyq";
+        let object_key = format!("rust-sdk-test/{}.txt", Uuid::new_v4());
+
+        client.put_object(bucket_name, &object_key, b"hello".as_slice(), None).await.unwrap();
+
+        let audit = client.audit_object_access(bucket_name, &object_key).await.unwrap();
+        assert_eq!(ObjectAcl::Default, audit.object_acl);
+        assert!(!audit.is_public());
+
+        client.put_object_acl(bucket_name, &object_key, ObjectAcl::PublicRead, None).await.unwrap();
+
+        // bucket 级别的设置来自缓存，object acl 每次都重新获取
+        let audit = client.audit_object_access(bucket_name, &object_key).await.unwrap();
+        assert_eq!(ObjectAcl::PublicRead, audit.object_acl);
+        assert_eq!(!audit.bucket_block_public, audit.is_public());
+
+        client.delete_object(bucket_name, &object_key, None).await.unwrap();
+    }
 }
@@ -1,33 +1,205 @@
+use std::time::{Duration, Instant};
+
 use quick_xml::events::Event;
 
 use crate::{
-    acl::GetObjectAclOptions,
+    acl::{GetObjectAclOptions, PutObjectAclOptions},
+    bucket_common::{BucketAcl, BucketDetail},
+    common::Owner,
     error::Error,
     object_common::ObjectAcl,
     request::{OssRequest, RequestMethod},
-    util::{validate_bucket_name, validate_object_key},
+    util::{explain_invalid_object_key, validate_bucket_name, validate_object_key},
     Result,
 };
 
-pub(crate) fn parse_object_acl_from_xml(xml: &str) -> Result<ObjectAcl> {
-    let mut reader = quick_xml::Reader::from_str(xml);
-    let mut tag = String::new();
-    let mut acl_string = String::new();
-
-    loop {
-        match reader.read_event()? {
-            Event::Eof => break,
-            Event::Start(t) => tag = String::from_utf8_lossy(t.local_name().as_ref()).to_string(),
-            Event::Text(text) if tag.as_str() == "Grant" => {
-                acl_string = text.unescape()?.trim().to_string();
-                break;
+/// Result of getting object acl
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct ObjectAclResult {
+    pub acl: ObjectAcl,
+
+    /// The owner of the object, which may be different from the bucket owner in cross-account scenarios
+    pub owner: Owner,
+}
+
+/// Result of getting bucket acl
+///
+/// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketacl>
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct BucketAclResult {
+    /// All `<Grant>` entries in the response
+    pub access_control_list: Vec<BucketAcl>,
+    pub owner: Owner,
+}
+
+impl BucketAclResult {
+    pub(crate) fn from_xml(xml: &str) -> Result<Self> {
+        let mut reader = quick_xml::Reader::from_str(xml);
+        let mut tags = vec![];
+        let mut data = Self::default();
+
+        loop {
+            match reader.read_event()? {
+                Event::Eof => break,
+                Event::Start(t) => match t.local_name().as_ref() {
+                    b"Owner" => data.owner = Owner::from_xml_reader(&mut reader)?,
+                    _ => tags.push(String::from_utf8_lossy(t.local_name().as_ref()).to_string()),
+                },
+                Event::Text(text) => {
+                    let s = text.unescape()?.trim().to_string();
+                    if tags.last().map(|t| t.as_str()) == Some("Grant") && tags.iter().rev().nth(1).map(|t| t.as_str()) == Some("AccessControlList") {
+                        data.access_control_list.push(BucketAcl::try_from(s)?);
+                    }
+                }
+                Event::End(_) => {
+                    tags.pop();
+                }
+                _ => {}
             }
-            Event::End(_) => tag.clear(),
-            _ => {}
         }
+
+        Ok(data)
     }
 
-    ObjectAcl::try_from(acl_string)
+    /// The most permissive acl among all grants. `BucketAcl::Private` if there is no grant
+    pub fn effective_acl(&self) -> BucketAcl {
+        most_permissive_acl(&self.access_control_list)
+    }
+}
+
+/// Resolve the most permissive acl: `public-read-write` > `public-read` > `private`
+pub(crate) fn most_permissive_acl(acls: &[BucketAcl]) -> BucketAcl {
+    let rank = |acl: &BucketAcl| match acl {
+        BucketAcl::Private => 0,
+        BucketAcl::PublicRead => 1,
+        BucketAcl::PublicReadWrite => 2,
+    };
+
+    acls.iter().copied().max_by_key(rank).unwrap_or_default()
+}
+
+/// Bucket level access settings which are cached by client while auditing object access
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct BucketAccess {
+    pub block_public_access: bool,
+    pub acl: BucketAcl,
+}
+
+impl From<&BucketDetail> for BucketAccess {
+    fn from(detail: &BucketDetail) -> Self {
+        Self {
+            block_public_access: detail.block_public_access,
+            acl: most_permissive_acl(&detail.access_control_list),
+        }
+    }
+}
+
+/// How long the cached bucket access settings are used before GetBucketInfo is called again,
+/// so changes of bucket acl or block public access are picked up by long-running clients
+pub(crate) const BUCKET_ACCESS_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// [`BucketAccess`] with the time it was cached
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CachedBucketAccess {
+    access: BucketAccess,
+    cached_at: Instant,
+}
+
+impl CachedBucketAccess {
+    pub(crate) fn new(access: BucketAccess) -> Self {
+        Self {
+            access,
+            cached_at: Instant::now(),
+        }
+    }
+
+    /// Returns `None` if the entry is older than [`BUCKET_ACCESS_CACHE_TTL`] at `now`
+    pub(crate) fn fresh_at(&self, now: Instant) -> Option<BucketAccess> {
+        if now.saturating_duration_since(self.cached_at) < BUCKET_ACCESS_CACHE_TTL {
+            Some(self.access)
+        } else {
+            None
+        }
+    }
+}
+
+/// The effective access of an object, combining bucket settings and object acl.
+/// Useful for security scanners to check if an object is public
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct AccessAudit {
+    /// 是否开启了 Bucket 的阻止公共访问
+    pub bucket_block_public: bool,
+    pub bucket_acl: BucketAcl,
+
+    /// `ObjectAcl::Default` 表示继承 Bucket 的读写权限
+    pub object_acl: ObjectAcl,
+}
+
+impl AccessAudit {
+    pub(crate) fn new(bucket_access: BucketAccess, object_acl: ObjectAcl) -> Self {
+        Self {
+            bucket_block_public: bucket_access.block_public_access,
+            bucket_acl: bucket_access.acl,
+            object_acl,
+        }
+    }
+
+    /// Whether the object can be read anonymously.
+    /// Blocking public access on bucket overrides any public acl
+    pub fn is_public(&self) -> bool {
+        if self.bucket_block_public {
+            return false;
+        }
+
+        match self.object_acl {
+            ObjectAcl::Default => self.bucket_acl != BucketAcl::Private,
+            ObjectAcl::PublicRead | ObjectAcl::PublicReadWrite => true,
+            ObjectAcl::Private => false,
+        }
+    }
+}
+
+impl ObjectAclResult {
+    pub(crate) fn from_xml(xml: &str) -> Result<Self> {
+        let mut reader = quick_xml::Reader::from_str(xml);
+        let mut tag = String::new();
+        let mut acl_string = String::new();
+        let mut owner = Owner::default();
+
+        loop {
+            match reader.read_event()? {
+                Event::Eof => break,
+                Event::Start(t) => match t.local_name().as_ref() {
+                    b"Owner" => owner = Owner::from_xml_reader(&mut reader)?,
+                    _ => tag = String::from_utf8_lossy(t.local_name().as_ref()).to_string(),
+                },
+                Event::Text(text) if tag.as_str() == "Grant" => {
+                    acl_string = text.unescape()?.trim().to_string();
+                }
+                Event::End(_) => tag.clear(),
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            acl: ObjectAcl::try_from(acl_string)?,
+            owner,
+        })
+    }
+}
+
+pub(crate) fn build_get_bucket_acl_request(bucket_name: &str) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+    }
+
+    Ok(OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("acl", ""))
 }
 
 pub(crate) fn build_get_object_acl_request(bucket_name: &str, object_key: &str, options: &Option<GetObjectAclOptions>) -> Result<OssRequest> {
@@ -36,7 +208,7 @@ pub(crate) fn build_get_object_acl_request(bucket_name: &str, object_key: &str,
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
     }
 
     let mut request = OssRequest::new()
@@ -54,13 +226,13 @@ pub(crate) fn build_get_object_acl_request(bucket_name: &str, object_key: &str,
     Ok(request)
 }
 
-pub(crate) fn build_put_object_acl_request(bucket_name: &str, object_key: &str, acl: ObjectAcl, options: &Option<GetObjectAclOptions>) -> Result<OssRequest> {
+pub(crate) fn build_put_object_acl_request(bucket_name: &str, object_key: &str, acl: ObjectAcl, options: &Option<PutObjectAclOptions>) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
         return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
     }
 
     let mut request = OssRequest::new()
@@ -78,3 +250,98 @@ pub(crate) fn build_put_object_acl_request(bucket_name: &str, object_key: &str,
 
     Ok(request)
 }
+
+#[cfg(test)]
+mod test_acl_common {
+    use super::{
+        build_get_object_acl_request, build_put_object_acl_request, AccessAudit, BucketAccess, BucketAclResult, CachedBucketAccess, ObjectAclResult,
+        BUCKET_ACCESS_CACHE_TTL,
+    };
+    use crate::{acl::PutObjectAclOptions, bucket_common::BucketAcl, common::WithVersionId, object_common::ObjectAcl, request::RequestMethod};
+
+    #[test]
+    fn test_build_object_acl_requests() {
+        let request = build_get_object_acl_request("yuanyq", "a.txt", &None).unwrap();
+        assert_eq!(RequestMethod::Get, request.method);
+        assert_eq!(Some(&"".to_string()), request.query.get("acl"));
+
+        let options = Some(PutObjectAclOptions::with_version_id("v1"));
+        let request = build_put_object_acl_request("yuanyq", "a.txt", ObjectAcl::PublicRead, &options).unwrap();
+        assert_eq!(RequestMethod::Put, request.method);
+        assert_eq!(Some(&"".to_string()), request.query.get("acl"));
+        assert_eq!(Some(&"public-read".to_string()), request.headers.get("x-oss-object-acl"));
+        assert_eq!(Some(&"v1".to_string()), request.query.get("versionId"));
+
+        assert!(build_put_object_acl_request("yuanyq", "", ObjectAcl::PublicRead, &None).is_err());
+    }
+
+    #[test]
+    fn test_parse_object_acl_result() {
+        let xml = r#"<?xml version="1.0" ?>
+<AccessControlPolicy>
+    <Owner>
+        <ID>1234513715092****</ID>
+        <DisplayName>1234-****</DisplayName>
+    </Owner>
+    <AccessControlList>
+        <Grant>public-read</Grant>
+    </AccessControlList>
+</AccessControlPolicy>"#;
+
+        let ret = ObjectAclResult::from_xml(xml).unwrap();
+        assert_eq!(ObjectAcl::PublicRead, ret.acl);
+        assert_eq!("1234513715092****", ret.owner.id);
+        assert_eq!("1234-****", ret.owner.display_name);
+    }
+
+    #[test]
+    fn test_access_audit_is_public() {
+        let access = |block_public_access, acl| BucketAccess { block_public_access, acl };
+
+        assert!(AccessAudit::new(access(false, BucketAcl::PublicRead), ObjectAcl::Default).is_public());
+        assert!(!AccessAudit::new(access(false, BucketAcl::Private), ObjectAcl::Default).is_public());
+        assert!(AccessAudit::new(access(false, BucketAcl::Private), ObjectAcl::PublicRead).is_public());
+        assert!(!AccessAudit::new(access(false, BucketAcl::PublicReadWrite), ObjectAcl::Private).is_public());
+
+        // 阻止公共访问优先于任何公共读写权限
+        assert!(!AccessAudit::new(access(true, BucketAcl::PublicRead), ObjectAcl::PublicReadWrite).is_public());
+    }
+
+    #[test]
+    fn test_cached_bucket_access_ttl() {
+        let access = BucketAccess {
+            block_public_access: false,
+            acl: BucketAcl::PublicRead,
+        };
+        let cached = CachedBucketAccess::new(access);
+
+        assert_eq!(Some(access), cached.fresh_at(cached.cached_at));
+        assert_eq!(
+            Some(access),
+            cached.fresh_at(cached.cached_at + BUCKET_ACCESS_CACHE_TTL - std::time::Duration::from_secs(1))
+        );
+        assert!(cached.fresh_at(cached.cached_at + BUCKET_ACCESS_CACHE_TTL).is_none());
+    }
+
+    #[test]
+    fn test_parse_bucket_acl_result() {
+        let xml = r#"<?xml version="1.0" ?>
+<AccessControlPolicy>
+    <Owner>
+        <ID>0022012****</ID>
+        <DisplayName>user_example</DisplayName>
+    </Owner>
+    <AccessControlList>
+        <Grant>private</Grant>
+        <Grant>public-read</Grant>
+    </AccessControlList>
+</AccessControlPolicy>"#;
+
+        let ret = BucketAclResult::from_xml(xml).unwrap();
+        assert_eq!(vec![BucketAcl::Private, BucketAcl::PublicRead], ret.access_control_list);
+        assert_eq!(BucketAcl::PublicRead, ret.effective_acl());
+        assert_eq!("0022012****", ret.owner.id);
+
+        assert_eq!(BucketAcl::Private, BucketAclResult::default().effective_acl());
+    }
+}
@@ -1,22 +1,50 @@
-//! Object acl module
+//! Bucket and object acl module
+
+use std::time::Instant;
 
 use crate::{
-    acl_common::{build_get_object_acl_request, build_put_object_acl_request, parse_object_acl_from_xml},
+    acl_common::{
+        build_get_bucket_acl_request, build_get_object_acl_request, build_put_object_acl_request, AccessAudit, BucketAccess, BucketAclResult,
+        CachedBucketAccess, ObjectAclResult,
+    },
     common::VersionIdOnlyOptions,
     object_common::ObjectAcl,
     Result,
 };
 
-use super::Client;
+use super::{bucket::BucketOperations, Client};
 
 pub type PutObjectAclOptions = VersionIdOnlyOptions;
 pub type GetObjectAclOptions = VersionIdOnlyOptions;
 
+pub trait BucketAclOperations {
+    /// Get bucket acl and owner. All grants are collected, use `BucketAclResult::effective_acl` to get the most permissive one
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketacl>
+    fn get_bucket_acl<S>(&self, bucket_name: S) -> Result<BucketAclResult>
+    where
+        S: AsRef<str>;
+}
+
+impl BucketAclOperations for Client {
+    /// Get bucket acl and owner. All grants are collected, use `BucketAclResult::effective_acl` to get the most permissive one
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketacl>
+    fn get_bucket_acl<S>(&self, bucket_name: S) -> Result<BucketAclResult>
+    where
+        S: AsRef<str>,
+    {
+        let request = build_get_bucket_acl_request(bucket_name.as_ref())?;
+        let (_, content) = self.do_request::<String>(request)?;
+        BucketAclResult::from_xml(&content)
+    }
+}
+
 pub trait ObjectAclOperations {
-    /// Get an object's acl.
+    /// Get an object's acl and owner.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobjectacl>
-    fn get_object_acl<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectAclOptions>) -> Result<ObjectAcl>
+    fn get_object_acl<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectAclOptions>) -> Result<ObjectAclResult>
     where
         S1: AsRef<str>,
         S2: AsRef<str>;
@@ -24,30 +52,44 @@ pub trait ObjectAclOperations {
     /// Put an object's acl. If you want to restore the object's acl to follow bucket acl settings, pass acl as `ObjectAcl::Default`
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobjectacl>
-    fn put_object_acl<S1, S2>(&self, bucket_name: S1, object_key: S2, acl: ObjectAcl, options: Option<GetObjectAclOptions>) -> Result<()>
+    fn put_object_acl<S1, S2>(&self, bucket_name: S1, object_key: S2, acl: ObjectAcl, options: Option<PutObjectAclOptions>) -> Result<()>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>;
+
+    /// Audit the effective access of an object by combining bucket info (block public access and bucket acl) and object acl.
+    /// Bucket level settings are cached in the client for 5 minutes, so auditing many objects in the same bucket calls GetBucketInfo only once.
+    /// Call `clear_access_audit_cache` if bucket settings may have changed within that time
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketinfo>,
+    /// <https://help.aliyun.com/zh/oss/developer-reference/getobjectacl>
+    fn audit_object_access<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<AccessAudit>
     where
         S1: AsRef<str>,
         S2: AsRef<str>;
+
+    /// Clear bucket level settings cached by `audit_object_access`
+    fn clear_access_audit_cache(&self);
 }
 
 impl ObjectAclOperations for Client {
-    /// Get an object's acl.
+    /// Get an object's acl and owner.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobjectacl>
-    fn get_object_acl<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectAclOptions>) -> Result<ObjectAcl>
+    fn get_object_acl<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectAclOptions>) -> Result<ObjectAclResult>
     where
         S1: AsRef<str>,
         S2: AsRef<str>,
     {
         let request = build_get_object_acl_request(bucket_name.as_ref(), object_key.as_ref(), &options)?;
         let (_, content) = self.do_request::<String>(request)?;
-        parse_object_acl_from_xml(&content)
+        ObjectAclResult::from_xml(&content)
     }
 
     /// Put an object's acl. If you want to restore the object's acl to follow bucket acl settings, pass acl as `ObjectAcl::Default`
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobjectacl>
-    fn put_object_acl<S1, S2>(&self, bucket_name: S1, object_key: S2, acl: ObjectAcl, options: Option<GetObjectAclOptions>) -> Result<()>
+    fn put_object_acl<S1, S2>(&self, bucket_name: S1, object_key: S2, acl: ObjectAcl, options: Option<PutObjectAclOptions>) -> Result<()>
     where
         S1: AsRef<str>,
         S2: AsRef<str>,
@@ -56,6 +98,38 @@ impl ObjectAclOperations for Client {
         let _ = self.do_request::<()>(request)?;
         Ok(())
     }
+
+    /// Audit the effective access of an object. Bucket level settings are cached per bucket name
+    fn audit_object_access<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<AccessAudit>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let bucket_name = bucket_name.as_ref();
+
+        // 请求期间不持有锁，避免阻塞其他线程读取缓存，所以先取出缓存，没有或者已经过期的话请求之后再写回
+        let cached = self.bucket_access_cache.lock().ok().and_then(|cache| cache.get(bucket_name).copied());
+        let bucket_access = match cached.and_then(|c| c.fresh_at(Instant::now())) {
+            Some(access) => access,
+            None => {
+                let access = BucketAccess::from(&self.get_bucket_info(bucket_name)?);
+                if let Ok(mut cache) = self.bucket_access_cache.lock() {
+                    cache.insert(bucket_name.to_string(), CachedBucketAccess::new(access));
+                }
+                access
+            }
+        };
+
+        let object_acl = self.get_object_acl(bucket_name, object_key, None)?.acl;
+
+        Ok(AccessAudit::new(bucket_access, object_acl))
+    }
+
+    fn clear_access_audit_cache(&self) {
+        if let Ok(mut cache) = self.bucket_access_cache.lock() {
+            cache.clear();
+        }
+    }
 }
 
 #[cfg(all(test, feature = "blocking"))]
@@ -101,7 +175,8 @@ mod test_object_acl_blocking {
         let response = client.get_object_acl(bucket_name, &object_key, None);
         assert!(response.is_ok());
         let ret = response.unwrap();
-        assert_eq!(ObjectAcl::PublicRead, ret);
+        assert_eq!(ObjectAcl::PublicRead, ret.acl);
+        assert!(!ret.owner.id.is_empty());
 
         let url = format!("https://{}.oss-cn-beijing.aliyuncs.com/{}", bucket_name, object_key);
         let status = reqwest::blocking::get(&url).unwrap().status();
@@ -113,7 +188,7 @@ mod test_object_acl_blocking {
         let response = client.get_object_acl(bucket_name, &object_key, None);
         assert!(response.is_ok());
         let ret = response.unwrap();
-        assert_eq!(ObjectAcl::Default, ret);
+        assert_eq!(ObjectAcl::Default, ret.acl);
 
         let url = format!("https://{}.oss-cn-beijing.aliyuncs.com/{}", bucket_name, object_key);
         let status = reqwest::blocking::get(&url).unwrap().status();
@@ -1,11 +1,18 @@
+use std::{collections::VecDeque, sync::Mutex};
+
 use crate::{
     bucket_common::{
-        build_list_buckets_request, build_list_objects_request, build_put_bucket_request, extract_bucket_location, BucketDetail, BucketStat,
-        ListBucketsOptions, ListBucketsResult, ListObjectsOptions, ListObjectsResult, PutBucketConfiguration, PutBucketOptions,
+        build_get_bucket_lifecycle_request, build_list_buckets_request, build_list_object_versions_request, build_list_objects_request,
+        build_put_bucket_cors_request, build_put_bucket_encryption_request, build_put_bucket_lifecycle_request, build_put_bucket_policy_request,
+        build_put_bucket_referer_request, build_put_bucket_request, build_put_bucket_tagging_request, build_put_bucket_website_request,
+        extract_bucket_location, is_no_such_lifecycle_error, parse_bucket_tags_from_xml, BucketDetail, BucketStat, CorsConfiguration, LifecycleConfiguration,
+        LifecycleRule, ListBucketsOptions, ListBucketsResult, ListObjectVersionsOptions, ListObjectVersionsResult, ListObjectsOptions, ListObjectsResult,
+        ObjectSummary, PutBucketConfiguration, PutBucketOptions, RefererConfiguration, WebsiteConfiguration,
     },
+    common::ServerSideEncryptionRule,
     error::Error,
     request::{OssRequest, RequestMethod},
-    util::validate_bucket_name,
+    util::{human_size, validate_bucket_name},
     Result,
 };
 
@@ -15,10 +22,149 @@ pub trait BucketOperations {
     fn put_bucket<S: AsRef<str>>(&self, bucket_name: S, config: PutBucketConfiguration, options: Option<PutBucketOptions>) -> Result<()>;
     fn list_buckets(&self, options: Option<ListBucketsOptions>) -> Result<ListBucketsResult>;
     fn get_bucket_info<S: AsRef<str>>(&self, bucket_name: S) -> Result<BucketDetail>;
+
+    /// List buckets and get the detail (versioning, redundancy type, etc.) of each bucket.
+    /// Pages are followed automatically until all buckets matching `options` are listed.
+    ///
+    /// **CAUTION** One extra GetBucketInfo request is sent for each bucket, at most `concurrency` of them at the same time.
+    /// Keep `concurrency` small if you have many buckets, OSS limits the request rate of the account.
+    /// Details are returned in the same order as listed. Fails on the first error
+    fn list_buckets_detailed(&self, options: Option<ListBucketsOptions>, concurrency: usize) -> Result<Vec<BucketDetail>>;
+
     fn get_bucket_location<S: AsRef<str>>(&self, bucket_name: S) -> Result<String>;
     fn get_bucket_stat<S: AsRef<str>>(&self, bucket_name: S) -> Result<BucketStat>;
     fn list_objects<S: AsRef<str>>(&self, bucket_name: S, options: Option<ListObjectsOptions>) -> Result<ListObjectsResult>;
+    fn list_object_versions<S: AsRef<str>>(&self, bucket_name: S, options: Option<ListObjectVersionsOptions>) -> Result<ListObjectVersionsResult>;
     fn delete_bucket<S: AsRef<str>>(&self, bucket_name: S) -> Result<()>;
+
+    /// List all objects in a bucket (v2), yielding objects one by one.
+    /// Continuation tokens are followed automatically until `is_truncated` is `false`,
+    /// `max_keys` in `options` is the page size of each request. The iterator ends after yielding an error.
+    fn list_objects_iter<S: AsRef<str>>(&self, bucket_name: S, options: Option<ListObjectsOptions>) -> impl Iterator<Item = Result<ObjectSummary>> + '_;
+
+    /// List all object keys with the given prefix in a bucket.
+    /// Continuation tokens are followed automatically until all pages are fetched.
+    /// Pass an empty string as `prefix` to list all keys in the bucket.
+    fn list_keys<S1: AsRef<str>, S2: AsRef<str>>(&self, bucket_name: S1, prefix: S2) -> impl Iterator<Item = Result<String>> + '_;
+
+    /// Get bucket lifecycle configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketlifecycle>
+    fn get_bucket_lifecycle<S: AsRef<str>>(&self, bucket_name: S) -> Result<LifecycleConfiguration>;
+
+    /// Put bucket lifecycle configuration. All existing rules are replaced
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketlifecycle>
+    fn put_bucket_lifecycle<S: AsRef<str>>(&self, bucket_name: S, config: LifecycleConfiguration) -> Result<()>;
+
+    /// Delete bucket lifecycle configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebucketlifecycle>
+    fn delete_bucket_lifecycle<S: AsRef<str>>(&self, bucket_name: S) -> Result<()>;
+
+    /// Add a lifecycle rule to the bucket, keeping all existing rules.
+    /// Fails if a rule with the same id already exists.
+    ///
+    /// This is a read-modify-write: the current configuration is fetched, modified and put back.
+    /// It is **not** atomic. If the lifecycle configuration is modified concurrently by others
+    /// between the get and the put, their changes are overwritten.
+    ///
+    /// Fails without modifying anything if the current configuration contains elements
+    /// which are not modeled by [`LifecycleRule`], because they would be lost when put back.
+    fn add_lifecycle_rule<S: AsRef<str>>(&self, bucket_name: S, rule: LifecycleRule) -> Result<()>;
+
+    /// Remove the lifecycle rule with the given id from the bucket, keeping all other rules.
+    /// Returns `false` if no such rule exists. The lifecycle configuration is deleted if no rules left.
+    ///
+    /// This is a read-modify-write and has the same race as [`BucketOperations::add_lifecycle_rule`].
+    fn remove_lifecycle_rule<S1: AsRef<str>, S2: AsRef<str>>(&self, bucket_name: S1, rule_id: S2) -> Result<bool>;
+
+    /// Get bucket CORS configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketcors>
+    fn get_bucket_cors<S: AsRef<str>>(&self, bucket_name: S) -> Result<CorsConfiguration>;
+
+    /// Put bucket CORS configuration. All existing rules are replaced
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketcors>
+    fn put_bucket_cors<S: AsRef<str>>(&self, bucket_name: S, config: CorsConfiguration) -> Result<()>;
+
+    /// Delete bucket CORS configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebucketcors>
+    fn delete_bucket_cors<S: AsRef<str>>(&self, bucket_name: S) -> Result<()>;
+
+    /// Get bucket default server side encryption rule
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketencryption>
+    fn get_bucket_encryption<S: AsRef<str>>(&self, bucket_name: S) -> Result<ServerSideEncryptionRule>;
+
+    /// Put bucket default server side encryption rule
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketencryption>
+    fn put_bucket_encryption<S: AsRef<str>>(&self, bucket_name: S, rule: ServerSideEncryptionRule) -> Result<()>;
+
+    /// Delete bucket default server side encryption rule
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebucketencryption>
+    fn delete_bucket_encryption<S: AsRef<str>>(&self, bucket_name: S) -> Result<()>;
+
+    /// Get bucket static website configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketwebsite>
+    fn get_bucket_website<S: AsRef<str>>(&self, bucket_name: S) -> Result<WebsiteConfiguration>;
+
+    /// Put bucket static website configuration: index document, error document and routing rules
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketwebsite>
+    fn put_bucket_website<S: AsRef<str>>(&self, bucket_name: S, config: WebsiteConfiguration) -> Result<()>;
+
+    /// Delete bucket static website configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebucketwebsite>
+    fn delete_bucket_website<S: AsRef<str>>(&self, bucket_name: S) -> Result<()>;
+
+    /// Get bucket referer (anti-leech) configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketreferer>
+    fn get_bucket_referer<S: AsRef<str>>(&self, bucket_name: S) -> Result<RefererConfiguration>;
+
+    /// Put bucket referer (anti-leech) configuration. There is no delete operation,
+    /// put a configuration with empty referer list and `allow_empty_referer = true` to disable it
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketreferer>
+    fn put_bucket_referer<S: AsRef<str>>(&self, bucket_name: S, config: RefererConfiguration) -> Result<()>;
+
+    /// Get bucket tags. The tags are returned in the order of the response
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbuckettags>
+    fn get_bucket_tagging<S: AsRef<str>>(&self, bucket_name: S) -> Result<Vec<(String, String)>>;
+
+    /// Put bucket tags. The existing tags of the bucket will be replaced.
+    /// At most 20 tags are allowed for a bucket
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbuckettags>
+    fn put_bucket_tagging<S: AsRef<str>>(&self, bucket_name: S, tags: Vec<(String, String)>) -> Result<()>;
+
+    /// Delete all tags of the bucket
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebuckettags>
+    fn delete_bucket_tagging<S: AsRef<str>>(&self, bucket_name: S) -> Result<()>;
+
+    /// Get bucket policy. The policy is returned as the raw JSON string
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketpolicy>
+    fn get_bucket_policy<S: AsRef<str>>(&self, bucket_name: S) -> Result<String>;
+
+    /// Put bucket policy. The existing policy of the bucket will be replaced
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketpolicy>
+    fn put_bucket_policy<S1: AsRef<str>, S2: AsRef<str>>(&self, bucket_name: S1, policy: S2) -> Result<()>;
+
+    /// Delete bucket policy
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebucketpolicy>
+    fn delete_bucket_policy<S: AsRef<str>>(&self, bucket_name: S) -> Result<()>;
 }
 
 impl BucketOperations for Client {
@@ -45,6 +191,54 @@ impl BucketOperations for Client {
         ListBucketsResult::from_xml(&content)
     }
 
+    fn list_buckets_detailed(&self, options: Option<ListBucketsOptions>, concurrency: usize) -> Result<Vec<BucketDetail>> {
+        let mut options = options.unwrap_or_default();
+        let mut buckets = vec![];
+
+        loop {
+            let page = self.list_buckets(Some(options.clone()))?;
+            buckets.extend(page.buckets);
+
+            match (page.next_marker, page.is_truncated) {
+                (Some(marker), true) => options.marker = Some(marker),
+                _ => break,
+            }
+        }
+
+        let total = buckets.len();
+        let buckets = Mutex::new(buckets.into_iter().enumerate());
+        let details = Mutex::new(Vec::with_capacity(total));
+
+        std::thread::scope(|scope| {
+            for _ in 0..concurrency.max(1) {
+                scope.spawn(|| loop {
+                    let Some((i, bucket)) = buckets.lock().unwrap().next() else {
+                        break;
+                    };
+
+                    // 其他地域的 Bucket 需要请求其所在地域的 Endpoint
+                    let r = if bucket.region == self.region {
+                        self.get_bucket_info(&bucket.name)
+                    } else {
+                        self.clone_to(&bucket.region, &bucket.extranet_endpoint).get_bucket_info(&bucket.name)
+                    };
+
+                    let failed = r.is_err();
+                    details.lock().unwrap().push((i, r));
+
+                    // 出错之后不再发送新的请求
+                    if failed {
+                        buckets.lock().unwrap().by_ref().for_each(drop);
+                    }
+                });
+            }
+        });
+
+        let mut details = details.into_inner().unwrap();
+        details.sort_by_key(|(i, _)| *i);
+        details.into_iter().map(|(_, r)| r).collect()
+    }
+
     fn get_bucket_info<S: AsRef<str>>(&self, bucket_name: S) -> Result<BucketDetail> {
         let bucket_name = bucket_name.as_ref();
 
@@ -84,7 +278,18 @@ impl BucketOperations for Client {
 
         let (_, content) = self.do_request::<String>(request_builder)?;
 
-        BucketStat::from_xml(&content)
+        let stat = BucketStat::from_xml(&content)?;
+
+        oss_log!(
+            debug,
+            "bucket {} stat: {} objects, storage {}, standard storage {}",
+            bucket_name,
+            stat.object_count,
+            human_size(stat.storage),
+            human_size(stat.standard_storage)
+        );
+
+        Ok(stat)
     }
 
     fn list_objects<S: AsRef<str>>(&self, bucket_name: S, options: Option<ListObjectsOptions>) -> Result<ListObjectsResult> {
@@ -98,7 +303,25 @@ impl BucketOperations for Client {
 
         let (_, content) = self.do_request::<String>(request)?;
 
-        ListObjectsResult::from_xml(&content)
+        let ret = ListObjectsResult::from_xml(&content)?;
+
+        oss_log!(
+            debug,
+            "listed {} objects of {} in bucket {}",
+            ret.contents.len(),
+            human_size(ret.contents.iter().map(|obj| obj.size).sum()),
+            bucket_name
+        );
+
+        Ok(ret)
+    }
+
+    fn list_object_versions<S: AsRef<str>>(&self, bucket_name: S, options: Option<ListObjectVersionsOptions>) -> Result<ListObjectVersionsResult> {
+        let request = build_list_object_versions_request(bucket_name.as_ref(), &options)?;
+
+        let (_, content) = self.do_request::<String>(request)?;
+
+        ListObjectVersionsResult::from_xml(&content)
     }
 
     fn delete_bucket<S: AsRef<str>>(&self, bucket_name: S) -> Result<()> {
@@ -114,6 +337,321 @@ impl BucketOperations for Client {
 
         Ok(())
     }
+
+    fn list_objects_iter<S: AsRef<str>>(&self, bucket_name: S, options: Option<ListObjectsOptions>) -> impl Iterator<Item = Result<ObjectSummary>> + '_ {
+        let bucket_name = bucket_name.as_ref().to_string();
+
+        let mut next_options = Some(options.unwrap_or_default());
+        let mut objects = VecDeque::new();
+
+        std::iter::from_fn(move || loop {
+            if let Some(obj) = objects.pop_front() {
+                return Some(Ok(obj));
+            }
+
+            let mut options = next_options.take()?;
+
+            match self.list_objects(bucket_name.as_str(), Some(options.clone())) {
+                Ok(page) => {
+                    if let (Some(token), true) = (page.next_continuation_token, page.is_truncated) {
+                        options.continuation_token = Some(token);
+                        next_options = Some(options);
+                    }
+
+                    objects.extend(page.contents);
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        })
+    }
+
+    fn list_keys<S1: AsRef<str>, S2: AsRef<str>>(&self, bucket_name: S1, prefix: S2) -> impl Iterator<Item = Result<String>> + '_ {
+        let options = ListObjectsOptions {
+            prefix: Some(prefix.as_ref().to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+
+        self.list_objects_iter(bucket_name, Some(options)).map(|r| r.map(|obj| obj.key))
+    }
+
+    fn get_bucket_lifecycle<S: AsRef<str>>(&self, bucket_name: S) -> Result<LifecycleConfiguration> {
+        let request_builder = build_get_bucket_lifecycle_request(bucket_name.as_ref())?;
+
+        let (_, content) = self.do_request::<String>(request_builder)?;
+
+        LifecycleConfiguration::from_xml(&content)
+    }
+
+    fn put_bucket_lifecycle<S: AsRef<str>>(&self, bucket_name: S, config: LifecycleConfiguration) -> Result<()> {
+        let request_builder = build_put_bucket_lifecycle_request(bucket_name.as_ref(), &config)?;
+
+        self.do_request::<()>(request_builder)?;
+
+        Ok(())
+    }
+
+    fn delete_bucket_lifecycle<S: AsRef<str>>(&self, bucket_name: S) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).add_query("lifecycle", "");
+
+        self.do_request::<()>(request_builder)?;
+
+        Ok(())
+    }
+
+    fn add_lifecycle_rule<S: AsRef<str>>(&self, bucket_name: S, rule: LifecycleRule) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        // 严格解析，遇到未建模的元素时报错，避免写回时丢失这些配置
+        let ret = self
+            .do_request::<String>(build_get_bucket_lifecycle_request(bucket_name)?)
+            .and_then(|(_, content)| LifecycleConfiguration::from_xml_ext(&content, true));
+
+        let mut config = match ret {
+            Ok(c) => c,
+            Err(e) if is_no_such_lifecycle_error(&e) => LifecycleConfiguration::default(),
+            Err(e) => return Err(e),
+        };
+
+        config.add_rule(rule)?;
+
+        self.put_bucket_lifecycle(bucket_name, config)
+    }
+
+    fn remove_lifecycle_rule<S1: AsRef<str>, S2: AsRef<str>>(&self, bucket_name: S1, rule_id: S2) -> Result<bool> {
+        let bucket_name = bucket_name.as_ref();
+
+        // 严格解析，遇到未建模的元素时报错，避免写回时丢失这些配置
+        let ret = self
+            .do_request::<String>(build_get_bucket_lifecycle_request(bucket_name)?)
+            .and_then(|(_, content)| LifecycleConfiguration::from_xml_ext(&content, true));
+
+        let mut config = match ret {
+            Ok(c) => c,
+            Err(e) if is_no_such_lifecycle_error(&e) => return Ok(false),
+            Err(e) => return Err(e),
+        };
+
+        if !config.remove_rule(rule_id.as_ref()) {
+            return Ok(false);
+        }
+
+        // 不允许提交空的规则列表，没有规则时直接删除生命周期配置
+        if config.rules.is_empty() {
+            self.delete_bucket_lifecycle(bucket_name)?;
+        } else {
+            self.put_bucket_lifecycle(bucket_name, config)?;
+        }
+
+        Ok(true)
+    }
+
+    fn get_bucket_cors<S: AsRef<str>>(&self, bucket_name: S) -> Result<CorsConfiguration> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("cors", "");
+
+        let (_, content) = self.do_request::<String>(request_builder)?;
+
+        CorsConfiguration::from_xml(&content)
+    }
+
+    fn put_bucket_cors<S: AsRef<str>>(&self, bucket_name: S, config: CorsConfiguration) -> Result<()> {
+        let request_builder = build_put_bucket_cors_request(bucket_name.as_ref(), &config)?;
+
+        self.do_request::<()>(request_builder)?;
+
+        Ok(())
+    }
+
+    fn delete_bucket_cors<S: AsRef<str>>(&self, bucket_name: S) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).add_query("cors", "");
+
+        self.do_request::<()>(request_builder)?;
+
+        Ok(())
+    }
+
+    fn get_bucket_encryption<S: AsRef<str>>(&self, bucket_name: S) -> Result<ServerSideEncryptionRule> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("encryption", "");
+
+        let (_, content) = self.do_request::<String>(request_builder)?;
+
+        ServerSideEncryptionRule::from_xml(&content)
+    }
+
+    fn put_bucket_encryption<S: AsRef<str>>(&self, bucket_name: S, rule: ServerSideEncryptionRule) -> Result<()> {
+        let request_builder = build_put_bucket_encryption_request(bucket_name.as_ref(), &rule)?;
+
+        self.do_request::<()>(request_builder)?;
+
+        Ok(())
+    }
+
+    fn delete_bucket_encryption<S: AsRef<str>>(&self, bucket_name: S) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).add_query("encryption", "");
+
+        self.do_request::<()>(request_builder)?;
+
+        Ok(())
+    }
+
+    fn get_bucket_website<S: AsRef<str>>(&self, bucket_name: S) -> Result<WebsiteConfiguration> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("website", "");
+
+        let (_, content) = self.do_request::<String>(request_builder)?;
+
+        WebsiteConfiguration::from_xml(&content)
+    }
+
+    fn put_bucket_website<S: AsRef<str>>(&self, bucket_name: S, config: WebsiteConfiguration) -> Result<()> {
+        let request_builder = build_put_bucket_website_request(bucket_name.as_ref(), &config)?;
+
+        self.do_request::<()>(request_builder)?;
+
+        Ok(())
+    }
+
+    fn delete_bucket_website<S: AsRef<str>>(&self, bucket_name: S) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).add_query("website", "");
+
+        self.do_request::<()>(request_builder)?;
+
+        Ok(())
+    }
+
+    fn get_bucket_referer<S: AsRef<str>>(&self, bucket_name: S) -> Result<RefererConfiguration> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("referer", "");
+
+        let (_, content) = self.do_request::<String>(request_builder)?;
+
+        RefererConfiguration::from_xml(&content)
+    }
+
+    fn put_bucket_referer<S: AsRef<str>>(&self, bucket_name: S, config: RefererConfiguration) -> Result<()> {
+        let request_builder = build_put_bucket_referer_request(bucket_name.as_ref(), &config)?;
+
+        self.do_request::<()>(request_builder)?;
+
+        Ok(())
+    }
+
+    fn get_bucket_tagging<S: AsRef<str>>(&self, bucket_name: S) -> Result<Vec<(String, String)>> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("tagging", "");
+
+        let (_, content) = self.do_request::<String>(request_builder)?;
+
+        parse_bucket_tags_from_xml(&content)
+    }
+
+    fn put_bucket_tagging<S: AsRef<str>>(&self, bucket_name: S, tags: Vec<(String, String)>) -> Result<()> {
+        let request_builder = build_put_bucket_tagging_request(bucket_name.as_ref(), &tags)?;
+
+        self.do_request::<()>(request_builder)?;
+
+        Ok(())
+    }
+
+    fn delete_bucket_tagging<S: AsRef<str>>(&self, bucket_name: S) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).add_query("tagging", "");
+
+        self.do_request::<()>(request_builder)?;
+
+        Ok(())
+    }
+
+    fn get_bucket_policy<S: AsRef<str>>(&self, bucket_name: S) -> Result<String> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("policy", "");
+
+        let (_, content) = self.do_request::<String>(request_builder)?;
+
+        Ok(content)
+    }
+
+    fn put_bucket_policy<S1: AsRef<str>, S2: AsRef<str>>(&self, bucket_name: S1, policy: S2) -> Result<()> {
+        let request_builder = build_put_bucket_policy_request(bucket_name.as_ref(), policy.as_ref())?;
+
+        self.do_request::<()>(request_builder)?;
+
+        Ok(())
+    }
+
+    fn delete_bucket_policy<S: AsRef<str>>(&self, bucket_name: S) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).add_query("policy", "");
+
+        self.do_request::<()>(request_builder)?;
+
+        Ok(())
+    }
 }
 
 #[cfg(all(test, feature = "blocking"))]
@@ -185,6 +723,20 @@ mod test_bucket_blocking {
         log::debug!("{:?}", result);
     }
 
+    #[test]
+    fn test_list_buckets_detailed_blocking() {
+        setup();
+        let client = Client::from_env();
+
+        let buckets = client.list_buckets(None).unwrap().buckets;
+        let details = client.list_buckets_detailed(None, 4).unwrap();
+
+        assert_eq!(buckets.len(), details.len());
+        for (summary, detail) in buckets.iter().zip(details.iter()) {
+            assert_eq!(summary.name, detail.name);
+        }
+    }
+
     #[test]
     fn test_list_buckets_with_options_blocking() {
         setup_comp();
@@ -234,4 +786,36 @@ mod test_bucket_blocking {
         assert!(result.key_count > 0);
         assert_eq!(result.key_count, (result.common_prefixes.len() + result.contents.len()) as u64);
     }
+
+    #[test]
+    fn test_list_keys_blocking() {
+        setup_comp();
+
+        let client = Client::from_env();
+
+        let keys = client.list_keys("mi-dev-public", "").collect::<crate::Result<Vec<_>>>();
+        assert!(keys.is_ok());
+
+        let keys = keys.unwrap();
+        assert!(!keys.is_empty());
+    }
+
+    #[test]
+    fn test_list_objects_iter_blocking() {
+        setup_comp();
+
+        let client = Client::from_env();
+
+        // 每页 2 个，验证自动翻页
+        let options = ListObjectsOptionsBuilder::new().max_keys(2).build();
+        let objects = client.list_objects_iter("mi-dev-public", Some(options)).collect::<crate::Result<Vec<_>>>();
+        assert!(objects.is_ok());
+
+        let keys = client.list_keys("mi-dev-public", "").collect::<crate::Result<Vec<_>>>().unwrap();
+        assert_eq!(keys, objects.unwrap().into_iter().map(|obj| obj.key).collect::<Vec<_>>());
+
+        let mut iter = client.list_objects_iter("-invalid-bucket", None);
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+    }
 }
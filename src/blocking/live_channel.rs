@@ -0,0 +1,31 @@
+use crate::{
+    live_channel_common::{build_list_live_channels_request, ListLiveChannelsOptions, ListLiveChannelsResult},
+    Result,
+};
+
+use super::Client;
+
+pub trait LiveChannelOperations {
+    /// List live channels of a bucket
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/listlivechannel>
+    fn list_live_channels<S>(&self, bucket_name: S, options: Option<ListLiveChannelsOptions>) -> Result<ListLiveChannelsResult>
+    where
+        S: AsRef<str>;
+}
+
+impl LiveChannelOperations for Client {
+    /// List live channels of a bucket
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/listlivechannel>
+    fn list_live_channels<S>(&self, bucket_name: S, options: Option<ListLiveChannelsOptions>) -> Result<ListLiveChannelsResult>
+    where
+        S: AsRef<str>,
+    {
+        let request = build_list_live_channels_request(bucket_name.as_ref(), &options)?;
+
+        let (_, xml) = self.do_request::<String>(request)?;
+
+        ListLiveChannelsResult::from_xml(&xml)
+    }
+}
@@ -1,19 +1,20 @@
 use std::ops::Range;
 use std::path::Path;
+use std::sync::Mutex;
 
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
 
 use crate::error::Error;
 use crate::multipart_common::{
-    build_complete_multipart_uploads_request, build_initiate_multipart_uploads_request, build_list_multipart_uploads_request, build_list_parts_request,
-    build_upload_part_copy_request, build_upload_part_request, CompleteMultipartUploadApiResponse, CompleteMultipartUploadOptions,
-    CompleteMultipartUploadRequest, CompleteMultipartUploadResult, InitiateMultipartUploadOptions, InitiateMultipartUploadResult, ListMultipartUploadsOptions,
-    ListMultipartUploadsResult, ListPartsOptions, ListPartsResult, UploadPartCopyOptions, UploadPartCopyRequest, UploadPartCopyResult, UploadPartRequest,
+    build_abort_multipart_upload_request, build_complete_multipart_uploads_request, build_initiate_multipart_uploads_request,
+    build_list_multipart_uploads_request, build_list_parts_request, build_upload_file_parts, build_upload_part_copy_request, build_upload_part_request,
+    is_no_such_upload, upload_checkpoint_path, CompleteMultipartUploadOptions, CompleteMultipartUploadRequest, CompleteMultipartUploadResult,
+    InitiateMultipartUploadOptions, InitiateMultipartUploadResult, ListMultipartUploadsOptions, ListMultipartUploadsResult, ListPartsOptions, ListPartsResult,
+    ListPartsResultItem, UploadCheckpoint, UploadConfig, UploadPartCopyOptions, UploadPartCopyRequest, UploadPartCopyResult, UploadPartRequest,
     UploadPartResult,
 };
-use crate::request::{OssRequest, RequestMethod};
-use crate::util::{validate_bucket_name, validate_object_key};
+use crate::util::validate_bucket_name;
 use crate::{RequestBody, Result};
 
 use super::Client;
@@ -120,6 +121,65 @@ pub trait MultipartUploadsOperations {
         S1: AsRef<str>,
         S2: AsRef<str>,
         S3: AsRef<str>;
+
+    /// Upload a large file with multipart uploads: initiate, upload parts of `config.part_size` bytes,
+    /// with at most `config.concurrency` threads, and complete. The multipart upload is aborted if any part fails.
+    ///
+    /// The callback in `options`, if any, is sent with complete multipart upload request.
+    /// The `timeout` in `options`, if any, applies to initiating the upload and to each part separately, not to the whole upload.
+    fn upload_large_file<S1, S2, P>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        file_path: P,
+        config: UploadConfig,
+        options: Option<InitiateMultipartUploadOptions>,
+    ) -> Result<CompleteMultipartUploadResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        P: AsRef<Path>;
+
+    /// Upload a large file like `upload_large_file`, but resumable. A checkpoint file `<file name>.oss-checkpoint.json`
+    /// is saved next to the source file after each part is uploaded. Calling it again after an interruption
+    /// reloads the checkpoint, reconciles it with the uploaded parts by ListParts, and uploads the missing parts only.
+    ///
+    /// Each part is recorded with its crc64, parts whose crc64 does not match the local file are uploaded again when resuming.
+    /// The checkpoint is invalid if the source file size or modification time changed, the multipart upload of it is aborted
+    /// and the upload starts over. The multipart upload is not aborted on failure so it can be resumed.
+    /// The checkpoint file is deleted after completion.
+    fn upload_large_file_resumable<S1, S2, P>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        file_path: P,
+        config: UploadConfig,
+        options: Option<InitiateMultipartUploadOptions>,
+    ) -> Result<CompleteMultipartUploadResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        P: AsRef<Path>;
+}
+
+impl Client {
+    /// List all parts of the multipart upload, page by page
+    fn list_all_parts(&self, bucket_name: &str, object_key: &str, upload_id: &str) -> Result<Vec<ListPartsResultItem>> {
+        let mut options = ListPartsOptions::default();
+        let mut parts = vec![];
+
+        loop {
+            let ret = self.list_parts(bucket_name, object_key, upload_id, Some(options.clone()))?;
+            parts.extend(ret.parts);
+
+            match (ret.next_part_number_marker, ret.is_truncated) {
+                (Some(marker), true) => options.part_number_marker = Some(marker),
+                _ => break,
+            }
+        }
+
+        Ok(parts)
+    }
 }
 
 impl MultipartUploadsOperations for Client {
@@ -208,7 +268,12 @@ impl MultipartUploadsOperations for Client {
         S2: AsRef<str>,
         B: Into<Vec<u8>>,
     {
-        let request = build_upload_part_request(bucket_name.as_ref(), object_key.as_ref(), RequestBody::Bytes(buffer.into()), params)?;
+        let request = build_upload_part_request(
+            bucket_name.as_ref(),
+            object_key.as_ref(),
+            RequestBody::Bytes(bytes::Bytes::from(buffer.into())),
+            params,
+        )?;
 
         let (headers, _) = self.do_request::<()>(request)?;
 
@@ -267,16 +332,13 @@ impl MultipartUploadsOperations for Client {
     {
         let with_callback = if let Some(opt) = &options { opt.callback.is_some() } else { false };
 
-        let request = build_complete_multipart_uploads_request(bucket_name.as_ref(), object_key.as_ref(), data, &options)?;
-        let (_, content) = self.do_request::<String>(request)?;
+        let bucket_name = bucket_name.as_ref();
+        let object_key = object_key.as_ref();
 
-        if with_callback {
-            Ok(CompleteMultipartUploadResult::CallbackResponse(content))
-        } else {
-            Ok(CompleteMultipartUploadResult::ApiResponse(CompleteMultipartUploadApiResponse::from_xml(
-                &content,
-            )?))
-        }
+        let request = build_complete_multipart_uploads_request(bucket_name, object_key, data, &options)?;
+        let (headers, content) = self.do_request::<String>(request)?;
+
+        CompleteMultipartUploadResult::from_response(bucket_name, object_key, with_callback, headers, content)
     }
 
     /// About multipart uploads
@@ -287,31 +349,212 @@ impl MultipartUploadsOperations for Client {
         S1: AsRef<str>,
         S2: AsRef<str>,
         S3: AsRef<str>,
+    {
+        let request = build_abort_multipart_upload_request(bucket_name.as_ref(), object_key.as_ref(), upload_id.as_ref())?;
+
+        self.do_request::<()>(request)?;
+
+        Ok(())
+    }
+
+    /// Upload a large file with multipart uploads: initiate, upload parts of `config.part_size` bytes,
+    /// with at most `config.concurrency` threads, and complete. The multipart upload is aborted if any part fails.
+    ///
+    /// The callback in `options`, if any, is sent with complete multipart upload request.
+    /// The `timeout` in `options`, if any, applies to initiating the upload and to each part separately, not to the whole upload.
+    fn upload_large_file<S1, S2, P>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        file_path: P,
+        config: UploadConfig,
+        options: Option<InitiateMultipartUploadOptions>,
+    ) -> Result<CompleteMultipartUploadResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        P: AsRef<Path>,
     {
         let bucket_name = bucket_name.as_ref();
         let object_key = object_key.as_ref();
+        let file_path = file_path.as_ref();
+
+        let file_size = std::fs::metadata(file_path)?.len();
+        let parts = build_upload_file_parts(file_size, &config)?;
+
+        let callback = options.as_ref().and_then(|opt| opt.callback.clone());
+        let timeout = options.as_ref().and_then(|opt| opt.timeout);
+
+        let upload_id = self.initiate_multipart_uploads(bucket_name, object_key, options)?.upload_id;
+
+        let pending = Mutex::new(parts.into_iter());
+        let uploaded = Mutex::new(vec![]);
+        let error = Mutex::new(None);
+
+        std::thread::scope(|scope| {
+            for _ in 0..config.concurrency.max(1) {
+                scope.spawn(|| loop {
+                    if error.lock().unwrap().is_some() {
+                        break;
+                    }
+
+                    let Some((part_number, range)) = pending.lock().unwrap().next() else {
+                        break;
+                    };
+
+                    let params = UploadPartRequest {
+                        timeout,
+                        ..UploadPartRequest::new(part_number, &upload_id)
+                    };
+
+                    match self.upload_part_from_file(bucket_name, object_key, file_path, range, params) {
+                        Ok(ret) => uploaded.lock().unwrap().push((part_number, ret.etag)),
+                        Err(e) => {
+                            error.lock().unwrap().get_or_insert(e);
+                            break;
+                        }
+                    }
+                });
+            }
+        });
 
-        if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        if let Some(e) = error.into_inner().unwrap() {
+            // 尽力清理已经上传的分片，清理失败时返回原始的错误
+            let _ = self.abort_multipart_uploads(bucket_name, object_key, &upload_id);
+            return Err(e);
         }
 
-        if !validate_object_key(object_key) {
-            return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        let mut parts = uploaded.into_inner().unwrap();
+        parts.sort_by_key(|(part_number, _)| *part_number);
+
+        let data = CompleteMultipartUploadRequest {
+            upload_id: upload_id.clone(),
+            parts,
+        };
+
+        let options = callback.map(|callback| CompleteMultipartUploadOptions { callback: Some(callback) });
+
+        match self.complete_multipart_uploads(bucket_name, object_key, data, options) {
+            Ok(ret) => Ok(ret),
+            Err(e) => {
+                let _ = self.abort_multipart_uploads(bucket_name, object_key, &upload_id);
+                Err(e)
+            }
         }
+    }
 
-        if upload_id.as_ref().is_empty() {
-            return Err(Error::Other("invalid upload id: [empty]".to_string()));
+    /// Upload a large file like `upload_large_file`, but resumable. A checkpoint file `<file name>.oss-checkpoint.json`
+    /// is saved next to the source file after each part is uploaded. Calling it again after an interruption
+    /// reloads the checkpoint, reconciles it with the uploaded parts by ListParts, and uploads the missing parts only.
+    ///
+    /// Each part is recorded with its crc64, parts whose crc64 does not match the local file are uploaded again when resuming.
+    /// The checkpoint is invalid if the source file size or modification time changed, the multipart upload of it is aborted
+    /// and the upload starts over. The multipart upload is not aborted on failure so it can be resumed.
+    /// The checkpoint file is deleted after completion.
+    fn upload_large_file_resumable<S1, S2, P>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        file_path: P,
+        config: UploadConfig,
+        options: Option<InitiateMultipartUploadOptions>,
+    ) -> Result<CompleteMultipartUploadResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        P: AsRef<Path>,
+    {
+        let bucket_name = bucket_name.as_ref();
+        let object_key = object_key.as_ref();
+        let file_path = file_path.as_ref();
+
+        let meta = std::fs::metadata(file_path)?;
+        let all_parts = build_upload_file_parts(meta.len(), &config)?;
+        let checkpoint_path = upload_checkpoint_path(file_path);
+
+        let callback = options.as_ref().and_then(|opt| opt.callback.clone());
+        let timeout = options.as_ref().and_then(|opt| opt.timeout);
+
+        let mut checkpoint = None;
+
+        if let Some(mut cp) = UploadCheckpoint::load(&checkpoint_path) {
+            if cp.matches(bucket_name, object_key, config.part_size, &meta) {
+                match self.list_all_parts(bucket_name, object_key, &cp.upload_id) {
+                    Ok(uploaded) => {
+                        cp.reconcile(&uploaded, &all_parts);
+                        cp.verify_parts(file_path, &all_parts)?;
+                        checkpoint = Some(cp);
+                    }
+                    // 分片上传已经完成或者被取消，重新开始
+                    Err(e) if is_no_such_upload(&e) => {}
+                    Err(e) => return Err(e),
+                }
+            } else {
+                // 检查点失效，取消旧的分片上传，否则已经上传的分片会一直占用存储空间
+                match self.abort_multipart_uploads(&cp.bucket, &cp.object_key, &cp.upload_id) {
+                    Ok(_) => {}
+                    Err(e) if is_no_such_upload(&e) => {}
+                    Err(e) => return Err(e),
+                }
+            }
         }
 
-        let request = OssRequest::new()
-            .method(RequestMethod::Delete)
-            .bucket(bucket_name)
-            .object(object_key)
-            .add_query("uploadId", upload_id);
+        let checkpoint = match checkpoint {
+            Some(cp) => cp,
+            None => {
+                let upload_id = self.initiate_multipart_uploads(bucket_name, object_key, options)?.upload_id;
+                UploadCheckpoint::new(bucket_name, object_key, &upload_id, config.part_size, &meta)?
+            }
+        };
 
-        self.do_request::<()>(request)?;
+        checkpoint.save(&checkpoint_path)?;
+
+        let upload_id = checkpoint.upload_id.clone();
+        let pending = Mutex::new(checkpoint.pending_parts(all_parts).into_iter());
+        let checkpoint = Mutex::new(checkpoint);
+        let error = Mutex::new(None);
+
+        std::thread::scope(|scope| {
+            for _ in 0..config.concurrency.max(1) {
+                scope.spawn(|| loop {
+                    if error.lock().unwrap().is_some() {
+                        break;
+                    }
+
+                    let Some((part_number, range)) = pending.lock().unwrap().next() else {
+                        break;
+                    };
+
+                    let params = UploadPartRequest {
+                        timeout,
+                        ..UploadPartRequest::new(part_number, &upload_id)
+                    };
+
+                    let ret = self.upload_part_from_file(bucket_name, object_key, file_path, range, params).and_then(|ret| {
+                        let mut checkpoint = checkpoint.lock().unwrap();
+                        checkpoint.add_part(part_number, ret);
+                        checkpoint.save(&checkpoint_path)
+                    });
+
+                    if let Err(e) = ret {
+                        error.lock().unwrap().get_or_insert(e);
+                        break;
+                    }
+                });
+            }
+        });
 
-        Ok(())
+        if let Some(e) = error.into_inner().unwrap() {
+            return Err(e);
+        }
+
+        let checkpoint = checkpoint.into_inner().unwrap();
+        let options = callback.map(|callback| CompleteMultipartUploadOptions { callback: Some(callback) });
+        let ret = self.complete_multipart_uploads(bucket_name, object_key, checkpoint.complete_request(), options)?;
+
+        let _ = std::fs::remove_file(&checkpoint_path);
+
+        Ok(ret)
     }
 }
 
@@ -328,10 +571,11 @@ mod test_multipart_blocking {
     use crate::{
         blocking::{multipart::MultipartUploadsOperations, object::ObjectOperations, Client},
         multipart_common::{
-            CompleteMultipartUploadOptions, CompleteMultipartUploadRequest, CompleteMultipartUploadResult, UploadPartCopyOptionsBuilder, UploadPartCopyRequest,
-            UploadPartRequest,
+            upload_checkpoint_path, CompleteMultipartUploadOptions, CompleteMultipartUploadRequest, CompleteMultipartUploadResult, UploadCheckpoint,
+            UploadConfig, UploadPartCopyOptionsBuilder, UploadPartCopyRequest, UploadPartRequest, MIN_PART_SIZE,
         },
         object_common::{CallbackBodyParameter, CallbackBuilder},
+        util,
     };
 
     static INIT: Once = Once::new();
@@ -428,10 +672,7 @@ mod test_multipart_blocking {
         let mut upload_results = vec![];
 
         for (i, rng) in ranges.iter().enumerate() {
-            let upload_data = UploadPartRequest {
-                part_number: (i + 1) as u32,
-                upload_id: upload_id.clone(),
-            };
+            let upload_data = UploadPartRequest::new((i + 1) as u32, &upload_id).compute_content_md5(true);
 
             log::debug!("begin to upload part {}", i);
 
@@ -524,10 +765,7 @@ mod test_multipart_blocking {
             let mut partial = stream.take(rng.end - rng.start);
             partial.read_to_end(&mut buf).unwrap();
 
-            let upload_data = UploadPartRequest {
-                part_number: part_no,
-                upload_id: upload_id.clone(),
-            };
+            let upload_data = UploadPartRequest::new(part_no, &upload_id);
 
             let upload_response = client.upload_part_from_buffer(bucket, &object, buf, upload_data);
 
@@ -651,10 +889,7 @@ mod test_multipart_blocking {
         let mut upload_results = vec![];
 
         for (i, rng) in ranges.iter().enumerate() {
-            let upload_data = UploadPartRequest {
-                part_number: (i + 1) as u32,
-                upload_id: upload_id.clone(),
-            };
+            let upload_data = UploadPartRequest::new((i + 1) as u32, &upload_id);
 
             log::debug!("begin to upload part {}", i);
 
@@ -713,7 +948,7 @@ mod test_multipart_blocking {
 
         let ret = comp_response.unwrap();
 
-        if let CompleteMultipartUploadResult::CallbackResponse(s) = ret {
+        if let CompleteMultipartUploadResult::CallbackResponse(s, _) = ret {
             assert!(s.contains(&serde_json::to_string(&object).unwrap()));
         } else {
             panic!("no callback json content returned");
@@ -721,4 +956,64 @@ mod test_multipart_blocking {
 
         client.delete_object(bucket, &object, None).unwrap();
     }
+
+    #[test]
+    fn test_upload_large_file_resumable() {
+        setup();
+
+        let client = Client::from_env();
+
+        let bucket = "yuanyq";
+        let object = format!("rust-sdk-test/large-file-{}.bin", Uuid::new_v4());
+
+        let file = std::env::temp_dir().join(format!("ali-oss-rs-large-file-{}.bin", Uuid::new_v4()));
+        let content = (0..MIN_PART_SIZE * 3 + 1234).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+        std::fs::write(&file, &content).unwrap();
+
+        // 模拟上传中断：先上传第一个分片并保存检查点
+        let meta = std::fs::metadata(&file).unwrap();
+        let upload_id = client.initiate_multipart_uploads(bucket, &object, None).unwrap().upload_id;
+        let ret = client
+            .upload_part_from_file(bucket, &object, &file, 0..MIN_PART_SIZE, UploadPartRequest::new(1, &upload_id))
+            .unwrap();
+        let mut checkpoint = UploadCheckpoint::new(bucket, &object, &upload_id, MIN_PART_SIZE, &meta).unwrap();
+        checkpoint.add_part(1, ret);
+        checkpoint.save(&upload_checkpoint_path(&file)).unwrap();
+
+        let config = UploadConfig::new(MIN_PART_SIZE, 2);
+        let ret = client.upload_large_file_resumable(bucket, &object, &file, config, None);
+        assert!(ret.is_ok());
+        assert!(!upload_checkpoint_path(&file).exists());
+        std::fs::remove_file(&file).unwrap();
+
+        let data = client.get_object_to_buffer(bucket, &object, None).unwrap();
+        assert_eq!(content, data);
+
+        client.delete_object(bucket, &object, None).unwrap();
+    }
+
+    #[test]
+    fn test_upload_large_file() {
+        setup();
+
+        let client = Client::from_env();
+
+        let bucket = "yuanyq";
+        let object = format!("rust-sdk-test/large-file-{}.bin", Uuid::new_v4());
+
+        let file = std::env::temp_dir().join(format!("ali-oss-rs-large-file-{}.bin", Uuid::new_v4()));
+        let content = (0..MIN_PART_SIZE * 3 + 1234).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+        std::fs::write(&file, &content).unwrap();
+
+        let config = UploadConfig::new(MIN_PART_SIZE, 2);
+        let ret = client.upload_large_file(bucket, &object, &file, config, None);
+        std::fs::remove_file(&file).unwrap();
+        assert!(ret.is_ok());
+        assert!(util::is_multipart_etag(&ret.unwrap().api_response().etag));
+
+        let data = client.get_object_to_buffer(bucket, &object, None).unwrap();
+        assert_eq!(content, data);
+
+        client.delete_object(bucket, &object, None).unwrap();
+    }
 }
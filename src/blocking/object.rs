@@ -1,24 +1,76 @@
-use std::path::Path;
-
-use base64::{prelude::BASE64_STANDARD, Engine};
-use reqwest::StatusCode;
+use std::{collections::HashMap, io::Write, path::Path, sync::Mutex};
 
 use crate::{
+    bucket_common::{ListObjectVersionsOptions, ListObjectsOptions},
+    common::{ObjectType, ResponseMeta, TagDirective, WithVersionId, MAX_COPY_OBJECT_SIZE},
     error::Error,
+    multipart_common::{CompleteMultipartUploadRequest, UploadPartCopyOptions, UploadPartCopyRequest},
     object_common::{
-        build_copy_object_request, build_delete_multiple_objects_request, build_get_object_request, build_head_object_request, build_put_object_request,
-        build_restore_object_request, AppendObjectOptions, AppendObjectResult, CopyObjectOptions, CopyObjectResult, DeleteMultipleObjectsConfig,
-        DeleteMultipleObjectsResult, DeleteObjectOptions, DeleteObjectResult, GetObjectMetadataOptions, GetObjectOptions, GetObjectResult, HeadObjectOptions,
-        ObjectMetadata, PutObjectOptions, PutObjectResult, RestoreObjectRequest, RestoreObjectResult,
+        build_copy_multipart_options, build_copy_object_request, build_copy_part_ranges, build_create_folder_request, build_delete_folder_request,
+        build_delete_multiple_objects_request, build_delete_versions_requests, build_folder_exists_options, build_get_object_request,
+        build_head_object_request, build_put_object_request, build_rename_delete_options, build_restore_object_request, build_resume_get_object_options,
+        build_select_object_request, check_copy_region, check_rename_object_keys, check_resilient_download_options, check_verified_download_options,
+        is_restore_already_in_progress, need_restore, request_body_crc64, response_crc64, verify_crc64, AppendObjectOptions, AppendObjectResult,
+        CopyObjectOptions, CopyObjectResult, CreateFolderResult, DeleteMultipleObjectsConfig, DeleteMultipleObjectsResult, DeleteObjectOptions,
+        DeleteObjectResult, GetObjectMetadataOptions, GetObjectOptions, GetObjectResult, HeadObjectOptions, ObjectAcl, ObjectMetadata, PutObjectOptions,
+        PutObjectResult, PutSource, RestoreObjectRequest, RestoreObjectResult, RestorePrefixResult, SelectRequest,
     },
     request::{OssRequest, RequestMethod},
-    util::{validate_bucket_name, validate_object_key, validate_path},
-    RequestBody, Result,
+    tagging_common::GetObjectTagOptions,
+    util::{explain_invalid_object_key, validate_bucket_name, validate_object_key, validate_path, Crc64, Crc64Writer},
+    Result,
 };
 
-use super::{BytesBody, Client};
+use super::{
+    acl::{BucketAclOperations, GetObjectAclOptions, ObjectAclOperations},
+    bucket::BucketOperations,
+    multipart::MultipartUploadsOperations,
+    tagging::ObjectTagOperations,
+    BytesBody, Client, SelectObjectReader,
+};
 
 pub trait ObjectOperations {
+    /// Put object from any supported source: local file, buffer, bytes or base64 string.
+    /// The `put_object_from_*` methods are shortcuts of this method.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
+    fn put_object<S1, S2, T>(&self, bucket_name: S1, object_key: S2, source: T, options: Option<PutObjectOptions>) -> Result<PutObjectResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        T: Into<PutSource>;
+
+    /// Same as [`Self::put_object`], and returns the request id, status and elapsed time of the response
+    fn put_object_with_meta<S1, S2, T>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        source: T,
+        options: Option<PutObjectOptions>,
+    ) -> Result<(PutObjectResult, ResponseMeta)>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        T: Into<PutSource>;
+
+    /// Append object from any supported source: local file, buffer, bytes or base64 string.
+    /// When `position` is `0`, the appendable object is created with `mime_type`, `traffic_limit` etc. in `options`.
+    /// The `append_object_from_*` methods are shortcuts of this method.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/appendobject>
+    fn append_object<S1, S2, T>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        source: T,
+        position: u64,
+        options: Option<AppendObjectOptions>,
+    ) -> Result<AppendObjectResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        T: Into<PutSource>;
+
     /// Uploads a file to a specified bucket and object key.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
@@ -38,6 +90,48 @@ pub trait ObjectOperations {
         S2: AsRef<str>,
         B: Into<Vec<u8>>;
 
+    /// Same as [`Self::put_object_from_file`], and returns the request id, status and elapsed time of the response
+    fn put_object_from_file_with_meta<S1, S2, P>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        file_path: P,
+        options: Option<PutObjectOptions>,
+    ) -> Result<(PutObjectResult, ResponseMeta)>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        P: AsRef<Path>;
+
+    /// Same as [`Self::put_object_from_buffer`], and returns the request id, status and elapsed time of the response
+    fn put_object_from_buffer_with_meta<S1, S2, B>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        buffer: B,
+        options: Option<PutObjectOptions>,
+    ) -> Result<(PutObjectResult, ResponseMeta)>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        B: Into<Vec<u8>>;
+
+    /// Create an object from several in-memory fragments. The fragments are sent in order without being concatenated,
+    /// so no extra allocation is needed when the data is already split.
+    /// And, it is recommended to set `mime_type` in `options`
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
+    fn put_object_from_chunks<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        chunks: Vec<bytes::Bytes>,
+        options: Option<PutObjectOptions>,
+    ) -> Result<PutObjectResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>;
+
     /// Create an object from base64 string.
     /// And, it is recommended to set `mime_type` in `options`
     ///
@@ -55,6 +149,7 @@ pub trait ObjectOperations {
         S3: AsRef<str>;
 
     /// Append object.
+    /// When `position` is `0`, the appendable object is created with `mime_type`, `traffic_limit` etc. in `options`
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/appendobject>
     fn append_object_from_file<S1, S2, P>(
@@ -104,7 +199,8 @@ pub trait ObjectOperations {
         S2: AsRef<str>,
         S3: AsRef<str>;
 
-    /// Uploads a file to a specified bucket and object key.
+    /// Download object to local file.
+    /// The metadata parsed from the response headers is returned, e.g. to verify the server side encryption.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobject>
     fn get_object_to_file<S1, S2, P>(&self, bucket_name: S1, object_key: S2, file_path: P, options: Option<GetObjectOptions>) -> Result<GetObjectResult>
@@ -113,6 +209,39 @@ pub trait ObjectOperations {
         S2: AsRef<str>,
         P: AsRef<Path>;
 
+    /// Download object to local file. If the connection is broken while downloading,
+    /// continue from the received bytes with a range request, at most `max_retries` times.
+    /// The etag of the first response is sent as `If-Match` on each retry,
+    /// so `412 Precondition Failed` is returned if the object is changed in the middle.
+    ///
+    /// `range` and `accept_encoding` of `options` are not supported.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobject>
+    fn get_object_to_file_resilient<S1, S2, P>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        file_path: P,
+        options: Option<GetObjectOptions>,
+        max_retries: u32,
+    ) -> Result<GetObjectResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        P: AsRef<Path>;
+
+    /// Download object and write the content to `writer` chunk by chunk, e.g. an in-memory buffer,
+    /// a hashing writer or a compression encoder, without landing on disk.
+    /// The response body is fully drained and the writer is flushed before returning.
+    /// Errors while writing are returned as `Error::IoError`.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobject>
+    fn get_object_to_writer<S1, S2, W>(&self, bucket_name: S1, object_key: S2, writer: &mut W, options: Option<GetObjectOptions>) -> Result<GetObjectResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        W: Write;
+
     /// Get object content into memory (bytes array).
     ///
     /// Large files can consume significant memory, exercise caution when using this function.
@@ -121,10 +250,34 @@ pub trait ObjectOperations {
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send;
 
-    /// Create a "folder"
+    /// Get object content into memory and verify it with the `x-oss-hash-crc64ecma` response header.
+    /// Returns `Error::CrcMismatch` if the data is corrupted. Range request is not supported.
+    ///
+    /// Large files can consume significant memory, exercise caution when using this function.
+    fn get_object_to_verified_buffer<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectOptions>) -> Result<(ObjectMetadata, Vec<u8>)>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+
+    /// Create a "folder". `a/b` and `/a/b/` both create the `a/b/` marker object.
+    /// Keys containing `//` are rejected.
+    ///
+    /// If `fail_if_exists` is `true`, an error is returned when the folder already exists
+    /// (the marker object or any object under the folder exists, see `folder_exists`),
+    /// and the marker object is put with `x-oss-forbid-overwrite: true`.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
-    fn create_folder<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<()>
+    fn create_folder<S1, S2>(&self, bucket_name: S1, object_key: S2, fail_if_exists: bool) -> Result<CreateFolderResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>;
+
+    /// Check if any object exists under the "folder", including the `a/b/` marker object itself.
+    /// `a/b` and `/a/b/` are both treated as `a/b/`.
+    /// Unlike `exists`, it returns `true` for a folder without marker object if any object is under it
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/listobjectsv2>
+    fn folder_exists<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<bool>
     where
         S1: AsRef<str>,
         S2: AsRef<str>;
@@ -153,6 +306,16 @@ pub trait ObjectOperations {
         S1: AsRef<str>,
         S2: AsRef<str>;
 
+    /// Get the object type (`Normal`, `Appendable`, `Multipart` or `Symlink`) using head object.
+    /// Returns `None` if the object does not exist.
+    /// Useful to decide whether to append to the object or overwrite it
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/headobject>
+    fn object_type<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<Option<ObjectType>>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>;
+
     /// Head object
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/headobject>
@@ -162,6 +325,7 @@ pub trait ObjectOperations {
         S2: AsRef<str>;
 
     /// Copy files (Objects) between the same or different Buckets within the same region.
+    /// If the copy fails because the buckets are in different regions, `Error::CrossRegionCopyUnsupported` is returned.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/copyobject>
     fn copy_object<S1, S2, S3, S4>(
@@ -178,6 +342,42 @@ pub trait ObjectOperations {
         S3: AsRef<str>,
         S4: AsRef<str>;
 
+    /// Copy object no matter how large it is.
+    /// Objects smaller than 1GB are copied by `copy_object`, larger objects are copied by multipart upload with UploadPartCopy,
+    /// and the multipart upload is aborted if any part fails.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/uploadpartcopy>
+    fn copy_object_auto<S1, S2, S3, S4>(
+        &self,
+        source_bucket_name: S1,
+        source_object_key: S2,
+        dest_bucket_name: S3,
+        dest_object_key: S4,
+        options: Option<CopyObjectOptions>,
+    ) -> Result<CopyObjectResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        S3: AsRef<str>,
+        S4: AsRef<str>;
+
+    /// Rename an object by copying it to `to_object_key` (multipart copy for large objects, see `copy_object_auto`)
+    /// and deleting `from_object_key` only after the copy succeeds. It is not atomic:
+    /// both objects exist for a short while, and the new object is kept if deleting the source fails.
+    ///
+    /// If `source_version_id` is set in `options`, that version of the source object is permanently deleted
+    /// instead of adding a delete marker to the current version.
+    ///
+    /// With `forbid_overwrite` set in `options`, an error is returned if `to_object_key` already exists.
+    /// Returns the metadata of the new object.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/copyobject>
+    fn rename_object<S1, S2, S3>(&self, bucket_name: S1, from_object_key: S2, to_object_key: S3, options: Option<CopyObjectOptions>) -> Result<ObjectMetadata>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        S3: AsRef<str>;
+
     /// Delete an object
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deleteobject>
@@ -186,6 +386,17 @@ pub trait ObjectOperations {
         S1: AsRef<str>,
         S2: AsRef<str>;
 
+    /// Same as [`Self::delete_object`], and returns the request id, status and elapsed time of the response
+    fn delete_object_with_meta<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        options: Option<DeleteObjectOptions>,
+    ) -> Result<(DeleteObjectResult, ResponseMeta)>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>;
+
     /// Delete multiple objects
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletemultipleobjects>
@@ -194,6 +405,13 @@ pub trait ObjectOperations {
         S1: AsRef<str>,
         S2: AsRef<str>;
 
+    /// Permanently delete all versions and delete markers of an object in a versioning enabled bucket.
+    /// Returns the number of deleted versions (including delete markers)
+    fn delete_all_versions<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<usize>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>;
+
     /// Restore object
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/restoreobject>
@@ -202,6 +420,14 @@ pub trait ObjectOperations {
         S1: AsRef<str>,
         S2: AsRef<str>;
 
+    /// Query CSV or JSON object with SQL and read the matching records, without downloading the whole object
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/selectobject>
+    fn select_object<S1, S2>(&self, bucket_name: S1, object_key: S2, select_request: SelectRequest) -> Result<SelectObjectReader>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>;
+
     /// Clean retored object
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/cleanrestoredobject>
@@ -209,17 +435,76 @@ pub trait ObjectOperations {
     where
         S1: AsRef<str>,
         S2: AsRef<str>;
+
+    /// Restore all `Archive`, `ColdArchive` and `DeepColdArchive` objects under the prefix.
+    /// At most `concurrency` threads are used to send restore requests.
+    /// Objects which are already being restored are counted in `already_in_progress` rather than `failed`.
+    /// `version_id` in `config` is ignored, the current version of each object is restored.
+    fn restore_prefix<S1, S2>(&self, bucket_name: S1, prefix: S2, config: RestoreObjectRequest, concurrency: usize) -> Result<RestorePrefixResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>;
+}
+
+impl Client {
+    /// Read storage class and acl of the source object which are not set explicitly in `options`,
+    /// so the destination object matches the source object regardless of the destination bucket defaults
+    fn resolve_preserved_copy_source(
+        &self,
+        source_bucket_name: &str,
+        source_object_key: &str,
+        dest_bucket_name: &str,
+        mut options: CopyObjectOptions,
+    ) -> Result<CopyObjectOptions> {
+        let source_version_id = options.source_version_id.clone();
+
+        if options.storage_class.is_none() {
+            let head_options = source_version_id.clone().map(HeadObjectOptions::with_version_id);
+            options.storage_class = self.head_object(source_bucket_name, source_object_key, head_options)?.storage_class;
+        }
+
+        if options.object_acl.is_none() {
+            let acl_options = source_version_id.map(GetObjectAclOptions::with_version_id);
+            let mut acl = self.get_object_acl(source_bucket_name, source_object_key, acl_options)?.acl;
+
+            // `Default` 表示继承 Bucket 的权限，跨 Bucket 拷贝时需要显式设置为源 Bucket 的权限
+            if acl == ObjectAcl::Default && source_bucket_name != dest_bucket_name {
+                acl = self.get_bucket_acl(source_bucket_name)?.effective_acl().into();
+            }
+
+            options.object_acl = Some(acl);
+        }
+
+        Ok(options)
+    }
 }
 
 impl ObjectOperations for Client {
-    /// Uploads a file to a specified bucket and object key.
+    /// Put object from any supported source: local file, buffer, bytes or base64 string.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
-    fn put_object_from_file<S1, S2, P>(&self, bucket_name: S1, object_key: S2, file_path: P, options: Option<PutObjectOptions>) -> Result<PutObjectResult>
+    fn put_object<S1, S2, T>(&self, bucket_name: S1, object_key: S2, source: T, options: Option<PutObjectOptions>) -> Result<PutObjectResult>
     where
         S1: AsRef<str>,
         S2: AsRef<str>,
-        P: AsRef<Path>,
+        T: Into<PutSource>,
+    {
+        let (result, _) = self.put_object_with_meta(bucket_name, object_key, source, options)?;
+        Ok(result)
+    }
+
+    /// Same as [`Self::put_object`], and returns the request id, status and elapsed time of the response
+    fn put_object_with_meta<S1, S2, T>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        source: T,
+        options: Option<PutObjectOptions>,
+    ) -> Result<(PutObjectResult, ResponseMeta)>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        T: Into<PutSource>,
     {
         let bucket_name = bucket_name.as_ref();
         let object_key = object_key.as_ref();
@@ -227,21 +512,38 @@ impl ObjectOperations for Client {
         let object_key = object_key.strip_prefix("/").unwrap_or(object_key);
         let object_key = object_key.strip_suffix("/").unwrap_or(object_key);
 
-        let file_path = file_path.as_ref();
-
         let with_callback = if let Some(opt) = &options { opt.callback.is_some() } else { false };
+        let check_crc64 = options.as_ref().is_some_and(|o| o.verify_crc64);
 
-        let request = build_put_object_request(bucket_name, object_key, RequestBody::File(file_path.to_path_buf(), None), &options)?;
+        let request = build_put_object_request(bucket_name, object_key, source.into().into_request_body()?, &options)?;
 
-        let (headers, content) = self.do_request::<String>(request)?;
+        let local_crc64 = if check_crc64 { Some(request_body_crc64(&request.body)?) } else { None };
+
+        let (headers, content, meta) = self.do_request_with_meta::<String>(request)?;
+
+        if let Some(crc) = local_crc64 {
+            verify_crc64(response_crc64(&headers), crc)?;
+        }
 
         if with_callback {
-            Ok(PutObjectResult::CallbackResponse(content))
+            Ok((PutObjectResult::CallbackResponse(content, headers.into()), meta))
         } else {
-            Ok(PutObjectResult::ApiResponse(headers.into()))
+            Ok((PutObjectResult::ApiResponse(headers.into()), meta))
         }
     }
 
+    /// Uploads a file to a specified bucket and object key.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
+    fn put_object_from_file<S1, S2, P>(&self, bucket_name: S1, object_key: S2, file_path: P, options: Option<PutObjectOptions>) -> Result<PutObjectResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        P: AsRef<Path>,
+    {
+        self.put_object(bucket_name, object_key, PutSource::File(file_path.as_ref().to_path_buf()), options)
+    }
+
     /// Create an object from buffer. If you are going to upload a large file, it is recommended to use `upload_file` instead.
     /// And, it is recommended to set `mime_type` in `options`
     ///
@@ -252,23 +554,58 @@ impl ObjectOperations for Client {
         S2: AsRef<str>,
         B: Into<Vec<u8>>,
     {
-        let bucket_name = bucket_name.as_ref();
-        let object_key = object_key.as_ref();
-
-        let object_key = object_key.strip_prefix("/").unwrap_or(object_key);
-        let object_key = object_key.strip_suffix("/").unwrap_or(object_key);
-
-        let with_callback = if let Some(opt) = &options { opt.callback.is_some() } else { false };
+        self.put_object(bucket_name, object_key, PutSource::Buffer(buffer.into()), options)
+    }
 
-        let request = build_put_object_request(bucket_name, object_key, RequestBody::Bytes(buffer.into()), &options)?;
+    /// Same as [`Self::put_object_from_file`], and returns the request id, status and elapsed time of the response
+    fn put_object_from_file_with_meta<S1, S2, P>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        file_path: P,
+        options: Option<PutObjectOptions>,
+    ) -> Result<(PutObjectResult, ResponseMeta)>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        P: AsRef<Path>,
+    {
+        self.put_object_with_meta(bucket_name, object_key, PutSource::File(file_path.as_ref().to_path_buf()), options)
+    }
 
-        let (headers, content) = self.do_request::<String>(request)?;
+    /// Same as [`Self::put_object_from_buffer`], and returns the request id, status and elapsed time of the response
+    fn put_object_from_buffer_with_meta<S1, S2, B>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        buffer: B,
+        options: Option<PutObjectOptions>,
+    ) -> Result<(PutObjectResult, ResponseMeta)>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        B: Into<Vec<u8>>,
+    {
+        self.put_object_with_meta(bucket_name, object_key, PutSource::Buffer(buffer.into()), options)
+    }
 
-        if with_callback {
-            Ok(PutObjectResult::CallbackResponse(content))
-        } else {
-            Ok(PutObjectResult::ApiResponse(headers.into()))
-        }
+    /// Create an object from several in-memory fragments. The fragments are sent in order without being concatenated,
+    /// so no extra allocation is needed when the data is already split.
+    /// And, it is recommended to set `mime_type` in `options`
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
+    fn put_object_from_chunks<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        chunks: Vec<bytes::Bytes>,
+        options: Option<PutObjectOptions>,
+    ) -> Result<PutObjectResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        self.put_object(bucket_name, object_key, PutSource::Chunks(chunks), options)
     }
 
     /// Create an object from base64 string.
@@ -287,30 +624,25 @@ impl ObjectOperations for Client {
         S2: AsRef<str>,
         S3: AsRef<str>,
     {
-        let data = if let Ok(d) = BASE64_STANDARD.decode(base64_string.as_ref()) {
-            d
-        } else {
-            return Err(Error::Other("Decoding base64 string failed".to_string()));
-        };
-
-        self.put_object_from_buffer(bucket_name, object_key, data, options)
+        self.put_object(bucket_name, object_key, PutSource::Base64(base64_string.as_ref().to_string()), options)
     }
 
-    /// Append object.
+    /// Append object from any supported source: local file, buffer, bytes or base64 string.
+    /// When `position` is `0`, the appendable object is created with `mime_type`, `traffic_limit` etc. in `options`
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/appendobject>
-    fn append_object_from_file<S1, S2, P>(
+    fn append_object<S1, S2, T>(
         &self,
         bucket_name: S1,
         object_key: S2,
-        file_path: P,
+        source: T,
         position: u64,
         options: Option<AppendObjectOptions>,
     ) -> Result<AppendObjectResult>
     where
         S1: AsRef<str>,
         S2: AsRef<str>,
-        P: AsRef<Path>,
+        T: Into<PutSource>,
     {
         let bucket_name = bucket_name.as_ref();
         let object_key = object_key.as_ref();
@@ -318,9 +650,7 @@ impl ObjectOperations for Client {
         let object_key = object_key.strip_prefix("/").unwrap_or(object_key);
         let object_key = object_key.strip_suffix("/").unwrap_or(object_key);
 
-        let file_path = file_path.as_ref();
-
-        let mut request = build_put_object_request(bucket_name, object_key, RequestBody::File(file_path.to_path_buf(), None), &options)?;
+        let mut request = build_put_object_request(bucket_name, object_key, source.into().into_request_body()?, &options)?;
 
         // alter the request method and add append object query parameters
         request = request
@@ -333,6 +663,26 @@ impl ObjectOperations for Client {
         Ok(headers.into())
     }
 
+    /// Append object.
+    /// When `position` is `0`, the appendable object is created with `mime_type`, `traffic_limit` etc. in `options`
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/appendobject>
+    fn append_object_from_file<S1, S2, P>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        file_path: P,
+        position: u64,
+        options: Option<AppendObjectOptions>,
+    ) -> Result<AppendObjectResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        P: AsRef<Path>,
+    {
+        self.append_object(bucket_name, object_key, PutSource::File(file_path.as_ref().to_path_buf()), position, options)
+    }
+
     /// Append object from buffer. suitable for small size content
     /// And, it is recommended to set `mime_type` in `options`
     ///
@@ -349,61 +699,112 @@ impl ObjectOperations for Client {
         S1: AsRef<str>,
         S2: AsRef<str>,
         B: Into<Vec<u8>>,
+    {
+        self.append_object(bucket_name, object_key, PutSource::Buffer(buffer.into()), position, options)
+    }
+
+    /// Append object from base64 string. suitable for small size content
+    /// And, it is recommended to set `mime_type` in `options`
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
+    fn append_object_from_base64<S1, S2, S3>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        base64_string: S3,
+        position: u64,
+        options: Option<AppendObjectOptions>,
+    ) -> Result<AppendObjectResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        S3: AsRef<str>,
+    {
+        self.append_object(
+            bucket_name,
+            object_key,
+            PutSource::Base64(base64_string.as_ref().to_string()),
+            position,
+            options,
+        )
+    }
+
+    /// Download object to local file.
+    /// The metadata parsed from the response headers is returned, e.g. to verify the server side encryption.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobject>
+    fn get_object_to_file<S1, S2, P>(&self, bucket_name: S1, object_key: S2, file_path: P, options: Option<GetObjectOptions>) -> Result<GetObjectResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        P: AsRef<Path>,
     {
         let bucket_name = bucket_name.as_ref();
         let object_key = object_key.as_ref();
+        let file_path = file_path.as_ref();
 
-        let object_key = object_key.strip_prefix("/").unwrap_or(object_key);
-        let object_key = object_key.strip_suffix("/").unwrap_or(object_key);
+        let check_crc64 = options.as_ref().is_some_and(|o| o.verify_crc64);
+        if check_crc64 {
+            check_verified_download_options(&options)?;
+        }
 
-        let mut request = build_put_object_request(bucket_name, object_key, RequestBody::Bytes(buffer.into()), &options)?;
+        let file_path = if file_path.is_relative() {
+            file_path.canonicalize()?
+        } else {
+            file_path.to_path_buf()
+        };
 
-        // alter the request method and add append object query parameters
-        request = request
-            .method(RequestMethod::Post)
-            .add_query("append", "")
-            .add_query("position", position.to_string());
+        if !validate_path(&file_path) {
+            return Err(Error::Other(format!("invalid file path: {:?}", file_path.as_os_str().to_str())));
+        }
 
-        let (headers, _) = self.do_request::<()>(request)?;
+        // check parent path
+        if let Some(parent_path) = file_path.parent() {
+            if !parent_path.exists() {
+                std::fs::create_dir_all(parent_path)?;
+            }
+        }
 
-        Ok(headers.into())
-    }
+        let request = build_get_object_request(bucket_name, object_key, &options)?;
+
+        let (headers, mut stream) = self.do_request::<BytesBody>(request)?;
+
+        let metadata = ObjectMetadata::from(headers);
+
+        if check_crc64 {
+            let mut file = Crc64Writer::new(std::fs::File::create(&file_path)?, true);
+            let mut written = 0u64;
+            stream.write_counted(&mut file, &mut written)?;
+            file.flush()?;
 
-    /// Append object from base64 string. suitable for small size content
-    /// And, it is recommended to set `mime_type` in `options`
-    ///
-    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
-    fn append_object_from_base64<S1, S2, S3>(
-        &self,
-        bucket_name: S1,
-        object_key: S2,
-        base64_string: S3,
-        position: u64,
-        options: Option<AppendObjectOptions>,
-    ) -> Result<AppendObjectResult>
-    where
-        S1: AsRef<str>,
-        S2: AsRef<str>,
-        S3: AsRef<str>,
-    {
-        let data = if let Ok(d) = BASE64_STANDARD.decode(base64_string.as_ref()) {
-            d
+            verify_crc64(metadata.hash_crc64ecma, file.crc64())?;
         } else {
-            return Err(Error::Other("Decoding base64 string failed".to_string()));
-        };
+            stream.save_to_file(file_path)?;
+        }
 
-        self.append_object_from_buffer(bucket_name, object_key, data, position, options)
+        Ok(GetObjectResult { metadata })
     }
 
-    /// Uploads a file to a specified bucket and object key.
+    /// Download oss object to local file and resume from the received bytes if the connection is broken.
+    /// `file_path` is the full file path to save.
+    /// If the `file_path` parent path does not exist, it will be created
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobject>
-    fn get_object_to_file<S1, S2, P>(&self, bucket_name: S1, object_key: S2, file_path: P, options: Option<GetObjectOptions>) -> Result<GetObjectResult>
+    fn get_object_to_file_resilient<S1, S2, P>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        file_path: P,
+        options: Option<GetObjectOptions>,
+        max_retries: u32,
+    ) -> Result<GetObjectResult>
     where
         S1: AsRef<str>,
         S2: AsRef<str>,
         P: AsRef<Path>,
     {
+        check_resilient_download_options(&options)?;
+
         let bucket_name = bucket_name.as_ref();
         let object_key = object_key.as_ref();
         let file_path = file_path.as_ref();
@@ -425,13 +826,79 @@ impl ObjectOperations for Client {
             }
         }
 
-        let request = build_get_object_request(bucket_name, object_key, &options)?;
+        let check_crc64 = options.as_ref().is_some_and(|o| o.verify_crc64);
+        let mut file = Crc64Writer::new(std::fs::File::create(&file_path)?, check_crc64);
+        let mut written = 0u64;
+        let mut etag: Option<String> = None;
+        let mut metadata: Option<ObjectMetadata> = None;
+        let mut retries = 0u32;
+
+        loop {
+            let ret = (|| {
+                let opts = build_resume_get_object_options(&options, written, etag.as_deref())?;
+                let request = build_get_object_request(bucket_name, object_key, &opts)?;
+                let (headers, mut stream) = self.do_request::<BytesBody>(request)?;
+
+                if metadata.is_none() {
+                    etag = headers.get("etag").cloned();
+                    metadata = Some(ObjectMetadata::from(headers));
+                }
+
+                stream.write_counted(&mut file, &mut written)
+            })();
+
+            match ret {
+                Ok(_) => break,
+                // only network errors are worth retrying, api errors such as `412` are returned directly
+                Err(Error::ReqwestError(_)) if retries < max_retries => retries += 1,
+                Err(e) => return Err(e),
+            }
+        }
+
+        file.flush()?;
+
+        let metadata = metadata.unwrap_or_default();
+
+        if check_crc64 {
+            verify_crc64(metadata.hash_crc64ecma, file.crc64())?;
+        }
+
+        Ok(GetObjectResult { metadata })
+    }
+
+    /// Download object and write the content to `writer` chunk by chunk, e.g. an in-memory buffer,
+    /// a hashing writer or a compression encoder, without landing on disk.
+    /// The response body is fully drained and the writer is flushed before returning.
+    /// Errors while writing are returned as `Error::IoError`.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobject>
+    fn get_object_to_writer<S1, S2, W>(&self, bucket_name: S1, object_key: S2, writer: &mut W, options: Option<GetObjectOptions>) -> Result<GetObjectResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        W: Write,
+    {
+        let check_crc64 = options.as_ref().is_some_and(|o| o.verify_crc64);
+        if check_crc64 {
+            check_verified_download_options(&options)?;
+        }
+
+        let request = build_get_object_request(bucket_name.as_ref(), object_key.as_ref(), &options)?;
 
-        let (_, mut stream) = self.do_request::<BytesBody>(request)?;
+        let (headers, mut body) = self.do_request::<BytesBody>(request)?;
 
-        stream.save_to_file(file_path)?;
+        let mut writer = Crc64Writer::new(writer, check_crc64);
+        let mut written = 0u64;
+        body.write_counted(&mut writer, &mut written)?;
+        writer.flush()?;
 
-        Ok(GetObjectResult)
+        let metadata = ObjectMetadata::from(headers);
+
+        if check_crc64 {
+            verify_crc64(metadata.hash_crc64ecma, writer.crc64())?;
+        }
+
+        Ok(GetObjectResult { metadata })
     }
 
     /// Get object content into memory (bytes array).
@@ -452,37 +919,67 @@ impl ObjectOperations for Client {
         stream.save_to_buffer()
     }
 
-    /// Create a "folder"
+    /// Get object content into memory and verify it with the `x-oss-hash-crc64ecma` response header.
+    /// Returns `Error::CrcMismatch` if the data is corrupted. Range request is not supported.
+    ///
+    /// Large files can consume significant memory, exercise caution when using this function.
+    fn get_object_to_verified_buffer<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectOptions>) -> Result<(ObjectMetadata, Vec<u8>)>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        check_verified_download_options(&options)?;
+
+        let request = build_get_object_request(bucket_name.as_ref(), object_key.as_ref(), &options)?;
+
+        let (headers, stream) = self.do_request::<BytesBody>(request)?;
+
+        let buf = stream.save_to_buffer()?;
+
+        let mut crc = Crc64::new();
+        crc.update(&buf);
+
+        let metadata = ObjectMetadata::from(headers);
+        verify_crc64(metadata.hash_crc64ecma, crc.finish())?;
+
+        Ok((metadata, buf))
+    }
+
+    /// Create a "folder". `a/b` and `/a/b/` both create the `a/b/` marker object.
+    /// Keys containing `//` are rejected
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
-    fn create_folder<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<()>
+    fn create_folder<S1, S2>(&self, bucket_name: S1, object_key: S2, fail_if_exists: bool) -> Result<CreateFolderResult>
     where
         S1: AsRef<str>,
         S2: AsRef<str>,
     {
         let bucket_name = bucket_name.as_ref();
         let object_key = object_key.as_ref();
-        let object_key = object_key.strip_prefix("/").unwrap_or(object_key);
-        let object_key = if object_key.ends_with("/") {
-            object_key.to_string()
-        } else {
-            format!("{}/", object_key)
-        };
 
-        if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        let request = build_create_folder_request(bucket_name, object_key, fail_if_exists)?;
+
+        if fail_if_exists && self.folder_exists(bucket_name, object_key)? {
+            return Err(Error::Other(format!("folder already exists: {}", object_key)));
         }
 
-        let request = OssRequest::new()
-            .method(RequestMethod::Put)
-            .bucket(bucket_name)
-            .object(object_key)
-            .body(RequestBody::Empty)
-            .content_length(0);
+        let (headers, _) = self.do_request::<()>(request)?;
 
-        let _ = self.do_request::<()>(request)?;
+        Ok(headers.into())
+    }
 
-        Ok(())
+    /// Check if any object exists under the "folder" by listing at most one object with the folder prefix
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/listobjectsv2>
+    fn folder_exists<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<bool>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let options = build_folder_exists_options(object_key.as_ref())?;
+        let ret = self.list_objects(bucket_name, Some(options))?;
+
+        Ok(!ret.contents.is_empty())
     }
 
     /// Delete a "folder". if the folder contains any object, it will not be deleted
@@ -493,20 +990,7 @@ impl ObjectOperations for Client {
         S1: AsRef<str>,
         S2: AsRef<str>,
     {
-        let bucket_name = bucket_name.as_ref();
-        let object_key = object_key.as_ref();
-        let object_key = object_key.strip_prefix("/").unwrap_or(object_key);
-        let object_key = if object_key.ends_with("/") {
-            object_key.to_string()
-        } else {
-            format!("{}/", object_key)
-        };
-
-        if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
-        }
-
-        let request = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).object(object_key);
+        let request = build_delete_folder_request(bucket_name.as_ref(), object_key.as_ref())?;
 
         let _ = self.do_request::<()>(request)?;
 
@@ -529,7 +1013,7 @@ impl ObjectOperations for Client {
         }
 
         if !validate_object_key(object_key) {
-            return Err(Error::Other(format!("invalid object key: {}", object_key)));
+            return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
         }
 
         let mut request = OssRequest::new()
@@ -558,10 +1042,23 @@ impl ObjectOperations for Client {
     {
         match self.get_object_metadata(bucket_name, object_key, options) {
             Ok(_) => Ok(true),
-            Err(e) => match e {
-                Error::StatusError(status) if status == StatusCode::NOT_FOUND => Ok(false),
-                _ => Err(e),
-            },
+            Err(e) if e.is_not_found() => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get the object type using head object. Returns `None` if the object does not exist.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/headobject>
+    fn object_type<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<Option<ObjectType>>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        match self.head_object(bucket_name, object_key, None) {
+            Ok(meta) => Ok(meta.object_type),
+            Err(e) if e.is_not_found() => Ok(None),
+            Err(e) => Err(e),
         }
     }
 
@@ -583,6 +1080,7 @@ impl ObjectOperations for Client {
     }
 
     /// Copy files (Objects) between the same or different Buckets within the same region.
+    /// If the copy fails because the buckets are in different regions, `Error::CrossRegionCopyUnsupported` is returned.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/copyobject>
     fn copy_object<S1, S2, S3, S4>(
@@ -599,6 +1097,13 @@ impl ObjectOperations for Client {
         S3: AsRef<str>,
         S4: AsRef<str>,
     {
+        let options = match options {
+            Some(opt) if opt.preserve_source => {
+                Some(self.resolve_preserved_copy_source(source_bucket_name.as_ref(), source_object_key.as_ref(), dest_bucket_name.as_ref(), opt)?)
+            }
+            _ => options,
+        };
+
         let request = build_copy_object_request(
             source_bucket_name.as_ref(),
             source_object_key.as_ref(),
@@ -607,15 +1112,183 @@ impl ObjectOperations for Client {
             &options,
         )?;
 
-        let (_, _) = self.do_request::<()>(request)?;
+        let (headers, content) = match self.do_request::<String>(request) {
+            Ok(v) => v,
+            Err(e) => {
+                // CopyObject fails with a confusing error if the buckets are in different regions.
+                // Only check bucket locations after the request failed, so the successful path costs nothing extra
+                // A copy within the same bucket is never cross region
+                if matches!(e, Error::ApiError(_)) && source_bucket_name.as_ref() != dest_bucket_name.as_ref() {
+                    if let (Ok(source_location), Ok(dest_location)) = (
+                        self.get_bucket_location(source_bucket_name.as_ref()),
+                        self.get_bucket_location(dest_bucket_name.as_ref()),
+                    ) {
+                        check_copy_region(&source_location, &dest_location)?;
+                    }
+                }
+
+                return Err(e);
+            }
+        };
+
+        CopyObjectResult::from_response(headers, &content)
+    }
+
+    /// Copy object with CopyObject or UploadPartCopy according to the size of the source object
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/uploadpartcopy>
+    fn copy_object_auto<S1, S2, S3, S4>(
+        &self,
+        source_bucket_name: S1,
+        source_object_key: S2,
+        dest_bucket_name: S3,
+        dest_object_key: S4,
+        options: Option<CopyObjectOptions>,
+    ) -> Result<CopyObjectResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        S3: AsRef<str>,
+        S4: AsRef<str>,
+    {
+        let source_bucket_name = source_bucket_name.as_ref();
+        let source_object_key = source_object_key.as_ref();
+        let dest_bucket_name = dest_bucket_name.as_ref();
+        let dest_object_key = dest_object_key.as_ref();
+
+        let source_version_id = options.as_ref().and_then(|opt| opt.source_version_id.clone());
+        let head_options = source_version_id.clone().map(HeadObjectOptions::with_version_id);
+        let source_metadata = self.head_object(source_bucket_name, source_object_key, head_options)?;
+
+        // 已经读取了源 Object 的元数据，保持存储类型时不需要再次 HeadObject
+        let mut options = options;
+        if let Some(opt) = options.as_mut().filter(|opt| opt.preserve_source && opt.storage_class.is_none()) {
+            opt.storage_class = source_metadata.storage_class;
+        }
+
+        if source_metadata.content_length < MAX_COPY_OBJECT_SIZE {
+            return self.copy_object(source_bucket_name, source_object_key, dest_bucket_name, dest_object_key, options);
+        }
+
+        let need_source_tags = !matches!(options.as_ref().and_then(|opt| opt.tag_directive), Some(TagDirective::Replace));
+        let source_tags = if need_source_tags && source_metadata.tag_count.unwrap_or(0) > 0 {
+            self.get_object_tags(
+                source_bucket_name,
+                source_object_key,
+                source_version_id.clone().map(GetObjectTagOptions::with_version_id),
+            )?
+        } else {
+            HashMap::new()
+        };
+
+        let options = match options {
+            Some(opt) if opt.preserve_source => Some(self.resolve_preserved_copy_source(source_bucket_name, source_object_key, dest_bucket_name, opt)?),
+            _ => options,
+        };
+
+        let (initiate_options, part_copy_options) = build_copy_multipart_options(&source_metadata, source_tags, &options);
+
+        let upload_id = self
+            .initiate_multipart_uploads(dest_bucket_name, dest_object_key, Some(initiate_options))?
+            .upload_id;
+
+        let mut parts = vec![];
+        for (part_number, range) in build_copy_part_ranges(source_metadata.content_length) {
+            let data = UploadPartCopyRequest::new(part_number, &upload_id, source_object_key).source_bucket(source_bucket_name);
+            let part_options = UploadPartCopyOptions {
+                copy_source_range: Some(range),
+                ..part_copy_options.clone()
+            };
+
+            match self.upload_part_copy(dest_bucket_name, dest_object_key, data, Some(part_options)) {
+                Ok(ret) => parts.push((part_number, ret.etag)),
+                Err(e) => {
+                    // 尽力清理已经拷贝的分片，清理失败时返回原始的错误
+                    let _ = self.abort_multipart_uploads(dest_bucket_name, dest_object_key, &upload_id);
+                    return Err(e);
+                }
+            }
+        }
+
+        let data = CompleteMultipartUploadRequest {
+            upload_id: upload_id.clone(),
+            parts,
+        };
+        let complete_result = match self.complete_multipart_uploads(dest_bucket_name, dest_object_key, data, None) {
+            Ok(ret) => ret,
+            Err(e) => {
+                let _ = self.abort_multipart_uploads(dest_bucket_name, dest_object_key, &upload_id);
+                return Err(e);
+            }
+        };
+
+        let mut result = CopyObjectResult {
+            source_version_id,
+            ..Default::default()
+        };
+
+        let ret = complete_result.api_response();
+        result.etag = ret.etag.clone();
+        result.version_id = ret.version_id.clone();
+        result.request_id = ret.request_id.clone();
+
+        Ok(result)
+    }
+
+    /// Rename an object by copying it to `to_object_key` (multipart copy for large objects, see `copy_object_auto`)
+    /// and deleting `from_object_key` only after the copy succeeds. It is not atomic:
+    /// both objects exist for a short while, and the new object is kept if deleting the source fails.
+    ///
+    /// If `source_version_id` is set in `options`, that version of the source object is permanently deleted
+    /// instead of adding a delete marker to the current version.
+    ///
+    /// With `forbid_overwrite` set in `options`, an error is returned if `to_object_key` already exists.
+    /// Returns the metadata of the new object.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/copyobject>
+    fn rename_object<S1, S2, S3>(&self, bucket_name: S1, from_object_key: S2, to_object_key: S3, options: Option<CopyObjectOptions>) -> Result<ObjectMetadata>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        S3: AsRef<str>,
+    {
+        let bucket_name = bucket_name.as_ref();
+        let from_object_key = from_object_key.as_ref();
+        let to_object_key = to_object_key.as_ref();
+
+        check_rename_object_keys(from_object_key, to_object_key)?;
+
+        // 服务端也会校验 `x-oss-forbid-overwrite`，这里提前检查，避免大文件在分片拷贝完成之后才失败
+        if options.as_ref().and_then(|opt| opt.forbid_overwrite).unwrap_or(false) && self.exists(bucket_name, to_object_key, None)? {
+            return Err(Error::Other(format!("object already exists: {}", to_object_key)));
+        }
 
-        Ok(CopyObjectResult)
+        let delete_options = build_rename_delete_options(&options);
+        self.copy_object_auto(bucket_name, from_object_key, bucket_name, to_object_key, options)?;
+        self.delete_object(bucket_name, from_object_key, delete_options)?;
+
+        self.head_object(bucket_name, to_object_key, None)
     }
 
     /// Delete an object
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deleteobject>
     fn delete_object<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<DeleteObjectOptions>) -> Result<DeleteObjectResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let (result, _) = self.delete_object_with_meta(bucket_name, object_key, options)?;
+        Ok(result)
+    }
+
+    /// Same as [`Self::delete_object`], and returns the request id, status and elapsed time of the response
+    fn delete_object_with_meta<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        options: Option<DeleteObjectOptions>,
+    ) -> Result<(DeleteObjectResult, ResponseMeta)>
     where
         S1: AsRef<str>,
         S2: AsRef<str>,
@@ -628,7 +1301,7 @@ impl ObjectOperations for Client {
         }
 
         if !validate_object_key(object_key) {
-            return Err(Error::Other(format!("invalid object key: {}", object_key)));
+            return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
         }
 
         let mut request = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).object(object_key);
@@ -639,9 +1312,9 @@ impl ObjectOperations for Client {
             }
         }
 
-        let _ = self.do_request::<()>(request)?;
+        let (_, _, meta) = self.do_request_with_meta::<()>(request)?;
 
-        Ok(DeleteObjectResult)
+        Ok((DeleteObjectResult, meta))
     }
 
     /// Delete multiple objects
@@ -659,6 +1332,55 @@ impl ObjectOperations for Client {
         DeleteMultipleObjectsResult::from_xml(&content)
     }
 
+    /// Permanently delete all versions and delete markers of an object in a versioning enabled bucket.
+    /// Returns the number of deleted versions (including delete markers)
+    fn delete_all_versions<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<usize>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let bucket_name = bucket_name.as_ref();
+        let object_key = object_key.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        if !validate_object_key(object_key) {
+            return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
+        }
+
+        let mut version_ids = vec![];
+        let mut options = ListObjectVersionsOptions {
+            prefix: Some(object_key.to_string()),
+            ..Default::default()
+        };
+
+        loop {
+            let page = self.list_object_versions(bucket_name, Some(options.clone()))?;
+
+            version_ids.extend(page.versions.into_iter().filter(|v| v.key == object_key).map(|v| v.version_id));
+            version_ids.extend(page.delete_markers.into_iter().filter(|m| m.key == object_key).map(|m| m.version_id));
+
+            // 结果按 key 排序，下一页的起点已经越过了目标 key，不会再有它的版本
+            if !page.is_truncated || page.next_key_marker.as_deref() != Some(object_key) {
+                break;
+            }
+
+            options.key_marker = page.next_key_marker;
+            options.version_id_marker = page.next_version_id_marker;
+        }
+
+        let mut deleted = 0;
+
+        for request in build_delete_versions_requests(object_key, version_ids) {
+            let result = self.delete_multiple_objects::<_, &str>(bucket_name, DeleteMultipleObjectsConfig::FullRequest(request))?;
+            deleted += result.items.len();
+        }
+
+        Ok(deleted)
+    }
+
     /// Restore object
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/restoreobject>
@@ -672,6 +1394,20 @@ impl ObjectOperations for Client {
         Ok(headers.into())
     }
 
+    /// Query CSV or JSON object with SQL and read the matching records, without downloading the whole object
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/selectobject>
+    fn select_object<S1, S2>(&self, bucket_name: S1, object_key: S2, select_request: SelectRequest) -> Result<SelectObjectReader>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let request = build_select_object_request(bucket_name.as_ref(), object_key.as_ref(), &select_request)?;
+        let (_, body) = self.do_request::<BytesBody>(request)?;
+
+        Ok(SelectObjectReader::new(body, select_request.output_serialization.enable_payload_crc))
+    }
+
     /// Clean retored object
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/cleanrestoredobject>
@@ -688,7 +1424,7 @@ impl ObjectOperations for Client {
         }
 
         if !validate_object_key(object_key) {
-            return Err(Error::Other(format!("invalid object key: {}", object_key)));
+            return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
         }
         let request = OssRequest::new()
             .method(RequestMethod::Post)
@@ -700,6 +1436,55 @@ impl ObjectOperations for Client {
 
         Ok(())
     }
+
+    fn restore_prefix<S1, S2>(&self, bucket_name: S1, prefix: S2, config: RestoreObjectRequest, concurrency: usize) -> Result<RestorePrefixResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let bucket_name = bucket_name.as_ref();
+
+        let options = ListObjectsOptions {
+            prefix: Some(prefix.as_ref().to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+
+        let keys = self
+            .list_objects_iter(bucket_name, Some(options))
+            .filter(|r| r.as_ref().map_or(true, |obj| need_restore(&obj.storage_class)))
+            .map(|r| r.map(|obj| obj.key))
+            .collect::<Result<Vec<_>>>()?;
+
+        let config = RestoreObjectRequest { version_id: None, ..config };
+
+        let keys = Mutex::new(keys.into_iter());
+        let ret = Mutex::new(RestorePrefixResult::default());
+
+        std::thread::scope(|scope| {
+            for _ in 0..concurrency.max(1) {
+                scope.spawn(|| loop {
+                    let Some(key) = keys.lock().unwrap().next() else {
+                        break;
+                    };
+
+                    let r = self.restore_object(bucket_name, key.as_str(), config.clone());
+
+                    let mut ret = ret.lock().unwrap();
+                    match r {
+                        Ok(_) => ret.submitted += 1,
+                        Err(e) if is_restore_already_in_progress(&e) => ret.already_in_progress += 1,
+                        Err(e) => {
+                            oss_log!(error, "restore object {} failed: {}", key, e);
+                            ret.failed += 1;
+                            ret.failed_keys.push(key);
+                        }
+                    }
+                });
+            }
+        });
+
+        Ok(ret.into_inner().unwrap())
+    }
 }
 
 #[cfg(all(test, feature = "blocking"))]
@@ -713,8 +1498,8 @@ mod test_object_blocking {
         blocking::{object::ObjectOperations, Client},
         common::{ObjectType, StorageClass},
         object_common::{
-            CallbackBodyParameter, CallbackBuilder, DeleteMultipleObjectsConfig, GetObjectOptionsBuilder, PutObjectApiResponse, PutObjectOptions,
-            PutObjectOptionsBuilder, PutObjectResult, RestoreObjectRequest,
+            CallbackBodyParameter, CallbackBuilder, CopyObjectOptionsBuilder, DeleteMultipleObjectsConfig, GetObjectOptionsBuilder, PutObjectApiResponse,
+            PutObjectOptions, PutObjectOptionsBuilder, PutObjectResult, RestoreObjectRequest,
         },
         util,
     };
@@ -860,7 +1645,7 @@ mod test_object_blocking {
 
         let client = Client::from_env();
 
-        let result = client.create_folder("yuanyq", "rust-sdk-test/test-folder/");
+        let result = client.create_folder("yuanyq", "rust-sdk-test/test-folder/", false);
 
         log::debug!("{:?}", result);
 
@@ -877,7 +1662,7 @@ mod test_object_blocking {
         let bucket = "yuanyq";
         let object = format!("rust-sdk-test/{}/", Uuid::new_v4());
 
-        client.create_folder(bucket, &object).unwrap();
+        client.create_folder(bucket, &object, false).unwrap();
 
         let response = client.delete_folder(bucket, &object);
         assert!(response.is_ok());
@@ -898,6 +1683,10 @@ mod test_object_blocking {
         let md5_hash = util::file_md5(&output_file);
         assert_eq!("pIPky6/KtraaoNqF76ia8Q==", md5_hash);
 
+        let metadata = result.unwrap().metadata;
+        assert_eq!(std::fs::metadata(&output_file).unwrap().len(), metadata.content_length);
+        log::debug!("server side encryption: {:?}", metadata.server_side_encryption);
+
         std::fs::remove_file(&output_file).unwrap();
         log::debug!("local file {} is deleted", output_file);
     }
@@ -923,6 +1712,36 @@ mod test_object_blocking {
         std::fs::remove_file(&output_file).unwrap();
     }
 
+    #[test]
+    fn test_get_object_to_writer_blocking() {
+        setup();
+        let client = Client::from_env();
+
+        let mut buf: Vec<u8> = vec![];
+        let result = client.get_object_to_writer("yuanyq", "rust-sdk-test/katex.zip", &mut buf, None);
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().metadata.content_length, buf.len() as u64);
+        assert_eq!("pIPky6/KtraaoNqF76ia8Q==", util::content_md5_base64(&buf));
+    }
+
+    #[test]
+    fn test_get_object_to_file_resilient_blocking() {
+        setup();
+        let client = Client::from_env();
+
+        let output_file = "/home/yuanyq/Downloads/ali-oss-rs-test/katex.resilient.zip";
+
+        let result = client.get_object_to_file_resilient("yuanyq", "rust-sdk-test/katex.zip", output_file, None, 3);
+
+        assert!(result.is_ok());
+
+        let meta = client.head_object("yuanyq", "rust-sdk-test/katex.zip", None).unwrap();
+        assert_eq!(meta.content_length, std::fs::metadata(output_file).unwrap().len());
+
+        std::fs::remove_file(output_file).unwrap();
+    }
+
     #[test]
     fn test_download_file_3_blocking() {
         setup();
@@ -993,6 +1812,18 @@ mod test_object_blocking {
         assert_eq!(Some(StorageClass::Standard), meta.storage_class);
     }
 
+    #[test]
+    fn test_object_type_blocking() {
+        setup();
+        let client = Client::from_env();
+
+        let ret = client.object_type("yuanyq", "rust-sdk-test/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack");
+        assert_eq!(Some(ObjectType::Normal), ret.unwrap());
+
+        let ret = client.object_type("yuanyq", format!("rust-sdk-test/{}.txt", Uuid::new_v4()));
+        assert_eq!(None, ret.unwrap());
+    }
+
     /// Copy object in same bucket
     #[test]
     fn test_copy_object_1_blocking() {
@@ -1018,6 +1849,35 @@ mod test_object_blocking {
         client.delete_object("yuanyq", &dest_object, None).unwrap();
     }
 
+    #[test]
+    fn test_rename_object_blocking() {
+        setup();
+        let client = Client::from_env();
+
+        let bucket = "yuanyq";
+        let from_object = format!("rust-sdk-test/katex-{}.zip", Uuid::new_v4());
+        let to_object = format!("rust-sdk-test/katex-{}.zip", Uuid::new_v4());
+
+        client.copy_object(bucket, "rust-sdk-test/katex.zip", bucket, &from_object, None).unwrap();
+
+        let ret = client.rename_object(bucket, &from_object, &from_object, None);
+        assert!(ret.is_err());
+
+        let meta = client.rename_object(bucket, &from_object, &to_object, None).unwrap();
+        assert!(!client.exists(bucket, &from_object, None).unwrap());
+        assert!(meta.content_length > 0);
+
+        // 目标已经存在，禁止覆盖
+        client.copy_object(bucket, "rust-sdk-test/katex.zip", bucket, &from_object, None).unwrap();
+        let options = CopyObjectOptionsBuilder::new().forbid_overwrite(true).build();
+        let ret = client.rename_object(bucket, &from_object, &to_object, Some(options));
+        assert!(ret.is_err());
+        assert!(client.exists(bucket, &from_object, None).unwrap());
+
+        client.delete_object(bucket, &from_object, None).unwrap();
+        client.delete_object(bucket, &to_object, None).unwrap();
+    }
+
     /// Copy object across buckets
     #[test]
     fn test_copy_object_2_blocking() {
@@ -1,20 +1,156 @@
+use chrono::Utc;
+
 use crate::{
-    presign::SignedOssRequest,
-    presign_common::{build_presign_get_request, PresignGetOptions},
+    presign::{SignedOssRequest, SignedRequest},
+    presign_common::{
+        build_presign_get_request, build_presign_process_request, build_presign_put_request, build_presign_upload_part_request, sign_post_policy,
+        PostPolicyConditions, PresignGetOptions, PresignPutOptions, PresignedPostPolicy,
+    },
     request::OssRequest,
     util::{self, get_iso8601_date_time_string},
+    Result,
 };
 
 use super::Client;
 
 impl Client {
-    /// Presign URL for GET request without any additional headers supported, for brower mostly
-    pub fn presign_url<S1, S2>(&self, bucket_name: S1, object_key: S2, options: PresignGetOptions) -> String
+    /// Presign URL for GET request, for browser mostly.
+    /// If `options.headers` is not empty, use [`Client::presign_get`] instead to get the headers which must be sent
+    pub fn presign_url<S1, S2>(&self, bucket_name: S1, object_key: S2, options: PresignGetOptions) -> Result<String>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let request = build_presign_get_request(bucket_name.as_ref(), object_key.as_ref(), &options);
+        self.presign_request_url(request)
+    }
+
+    /// Presign URL for GET request, with headers signed into the url.
+    /// e.g. pin the object version by `versionId`, override `response-content-disposition`,
+    /// and require the `range` header:
+    ///
+    /// ```ignore
+    /// let options = PresignGetOptionsBuilder::new(3600)
+    ///     .version_id("CAEQNhiBgMDJgZCA0BYiIDc4MGZjZGI2OTBjOTRmNTE5NmU5NmFhZjhjYmY0****")
+    ///     .response_content_disposition("attachment; filename=test.webp")
+    ///     .header("range", "bytes=0-1023")
+    ///     .build();
+    /// let SignedOssRequest { url, headers } = client.presign_get("yuanyq", "rust-sdk-test/test.webp", options)?;
+    /// // headers: {"range": "bytes=0-1023"}
+    /// ```
+    ///
+    /// Query parameters are part of the url, but the visitor **MUST** send all headers in the returned `SignedOssRequest::headers`
+    /// with exactly the same values, otherwise OSS responds `SignatureDoesNotMatch`.
+    pub fn presign_get<S1, S2>(&self, bucket_name: S1, object_key: S2, options: PresignGetOptions) -> Result<SignedOssRequest>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let request = build_presign_get_request(bucket_name.as_ref(), object_key.as_ref(), &options);
+        let headers = request.headers.clone();
+
+        Ok(SignedOssRequest {
+            url: self.presign_request_url(request)?,
+            headers,
+        })
+    }
+
+    /// Presign URL for PUT request, so others can upload the object without access key.
+    ///
+    /// `content_type`, `content_md5` and other headers in `options` are signed into the url.
+    /// The uploader **MUST** send all headers in the returned `SignedOssRequest::headers` with exactly the same values,
+    /// otherwise OSS responds `SignatureDoesNotMatch`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let options = PresignPutOptionsBuilder::new(3600).content_type("image/webp").build();
+    /// let SignedOssRequest { url, headers } = client.presign_put("yuanyq", "rust-sdk-test/test.webp", options)?;
+    /// // headers: {"content-type": "image/webp"}
+    /// ```
+    ///
+    /// Then upload the file with `curl`, `-T` sends a PUT request with file content as body:
+    ///
+    /// ```bash
+    /// curl -T ./test.webp -H "Content-Type: image/webp" "https://yuanyq.oss-cn-beijing.aliyuncs.com/rust-sdk-test/test.webp?x-oss-signature-version=..."
+    /// ```
+    pub fn presign_put<S1, S2>(&self, bucket_name: S1, object_key: S2, options: PresignPutOptions) -> Result<SignedOssRequest>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let request = build_presign_put_request(bucket_name.as_ref(), object_key.as_ref(), &options);
+        let headers = request.headers.clone();
+
+        Ok(SignedOssRequest {
+            url: self.presign_request_url(request)?,
+            headers,
+        })
+    }
+
+    /// Presign URL for uploading a part of a multipart upload, e.g. for uploading parts from browser directly.
+    /// The backend initiates the multipart upload, hands out the url of each part, and completes the upload after all parts are uploaded.
+    /// Upload the part by sending PUT request with part content as body to the url.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/uploadpart>
+    pub fn presign_upload_part<S1, S2, S3>(&self, bucket_name: S1, object_key: S2, upload_id: S3, part_number: u32, expire_seconds: u32) -> Result<String>
     where
         S1: AsRef<str>,
         S2: AsRef<str>,
+        S3: AsRef<str>,
     {
-        let mut request = build_presign_get_request(bucket_name.as_ref(), object_key.as_ref(), &options);
+        let request = build_presign_upload_part_request(bucket_name.as_ref(), object_key.as_ref(), upload_id.as_ref(), part_number, expire_seconds)?;
+        self.presign_request_url(request)
+    }
+
+    /// Presign URL for GET request with `x-oss-process`, e.g. share a resized thumbnail of a private image:
+    /// `image/resize,w_100` or a style `style/test-img-process`.
+    /// The process parameter is signed in the url, so it can not be modified by the visitor.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/user-guide/img-parameters>
+    pub fn signed_process_url<S1, S2, S3>(&self, bucket_name: S1, object_key: S2, process: S3, expire_seconds: u32) -> Result<String>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        S3: AsRef<str>,
+    {
+        let request = build_presign_process_request(bucket_name.as_ref(), object_key.as_ref(), process.as_ref(), expire_seconds)?;
+        self.presign_request_url(request)
+    }
+
+    /// Sign a POST policy for uploading object from browser directly by PostObject (form upload),
+    /// so the access key is never exposed to the browser.
+    /// The policy JSON is base64 encoded and signed with V4 signature.
+    ///
+    /// Post a `multipart/form-data` form to `PresignedPostPolicy::url` with all fields in `PresignedPostPolicy::form_fields()`,
+    /// the `key` field and the `file` field. The `file` field **MUST** be the last one.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/postobject>
+    pub fn presign_post_policy<S: AsRef<str>>(&self, bucket_name: S, conditions: PostPolicyConditions) -> Result<PresignedPostPolicy> {
+        self.check_signing_client()?;
+
+        let bucket_name = bucket_name.as_ref();
+
+        let signed = sign_post_policy(
+            bucket_name,
+            conditions,
+            &self.access_key_id,
+            &self.region,
+            self.sts_token.as_deref(),
+            Utc::now(),
+            |policy, date_string| self.calculate_signature(policy, date_string),
+        )?;
+
+        Ok(PresignedPostPolicy {
+            url: self.bucket_url(bucket_name, ""),
+            ..signed
+        })
+    }
+
+    /// Calculate signature and put it in query string of the url.
+    /// The headers of request are included in signature calculation
+    fn presign_request_url(&self, mut request: OssRequest) -> Result<String> {
+        self.check_signing_client()?;
 
         let date_time_string = request.query.get("x-oss-date").unwrap().clone();
         let date_string = &date_time_string[..8];
@@ -46,20 +182,18 @@ impl Client {
         let uri = request.build_request_uri();
         let query_string = request.build_canonical_query_string();
 
-        let domain_name = if request.bucket_name.is_empty() {
-            format!("{}://{}{}", self.scheme, self.endpoint, uri)
-        } else {
-            format!("{}://{}.{}{}", self.scheme, request.bucket_name, self.endpoint, uri)
-        };
+        let domain_name = self.bucket_url(&request.bucket_name, &uri);
 
         if query_string.is_empty() {
-            domain_name
+            Ok(domain_name)
         } else {
-            format!("{}?{}", domain_name, query_string)
+            Ok(format!("{}?{}", domain_name, query_string))
         }
     }
 
-    pub fn presign_raw_request(&self, mut oss_request: OssRequest) -> SignedOssRequest {
+    pub fn presign_raw_request(&self, mut oss_request: OssRequest) -> Result<SignedOssRequest> {
+        self.check_signing_client()?;
+
         let date_header = "x-oss-date".to_string();
         {
             oss_request.headers_mut().entry(date_header.clone()).or_insert(get_iso8601_date_time_string());
@@ -100,17 +234,43 @@ impl Client {
         let uri = oss_request.build_request_uri();
         let query_string = oss_request.build_canonical_query_string();
 
-        let url = if oss_request.bucket_name.is_empty() {
-            format!("{}://{}{}", self.scheme, self.endpoint, uri)
-        } else {
-            format!("{}://{}.{}{}", self.scheme, oss_request.bucket_name, self.endpoint, uri)
-        };
+        let url = self.bucket_url(&oss_request.bucket_name, &uri);
 
         let url = if query_string.is_empty() { url } else { format!("{}?{}", url, query_string) };
 
-        SignedOssRequest {
+        Ok(SignedOssRequest {
             url,
             headers: oss_request.headers,
+        })
+    }
+
+    /// Sign a request without sending it, so the signing logic can be used with any HTTP client.
+    /// Security token, `x-oss-date`, `date` and `authorization` headers are set as what this client sends.
+    /// Send the request to the returned url with all the returned headers before `x-oss-date` expires (15 minutes).
+    /// Use [`SignedOssRequest::into_parts`] to get the parsed `Url` and `HeaderMap` for `reqwest`
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let client = ali_oss_rs::blocking::Client::from_env();
+    ///
+    /// let request = OssRequest::new()
+    ///     .method(RequestMethod::Get)
+    ///     .bucket("yuanyq")
+    ///     .object("rust-sdk-test/test.txt");
+    ///
+    /// let SignedRequest { url, headers } = client.sign_request(request)?;
+    /// ```
+    pub fn sign_request(&self, mut oss_request: OssRequest) -> Result<SignedRequest> {
+        // check if sign `host` header
+        if oss_request.additional_headers.contains("host") && !oss_request.headers.contains_key("host") {
+            let host = self.bucket_host(&oss_request.bucket_name);
+
+            oss_request.headers_mut().insert("host".to_string(), host);
         }
+
+        oss_request.headers_mut().insert("date".to_string(), util::get_http_date());
+
+        self.presign_raw_request(oss_request)
     }
 }
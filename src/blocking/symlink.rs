@@ -1,7 +1,7 @@
 //! Object symlink module
 
 use super::Client;
-use crate::symlink_common::{build_get_symlink_request, build_put_symlink_request, GetSymlinkOptions, PutSymlinkOptions, PutSymlinkResult};
+use crate::symlink_common::{build_get_symlink_request, build_put_symlink_request, GetSymlinkOptions, GetSymlinkResult, PutSymlinkOptions, PutSymlinkResult};
 use crate::Result;
 
 pub trait ObjectSymlinkOperations {
@@ -22,10 +22,10 @@ pub trait ObjectSymlinkOperations {
         S2: AsRef<str>,
         S3: AsRef<str>;
 
-    /// Get a symlink object. The returned string is the target object key
+    /// Get a symlink object. The result contains the target object key, the etag and user defined metadata of the symlink
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getsymlink>
-    fn get_symlink<S1, S2>(&self, bucket_name: S1, symlink_object_key: S2, options: Option<GetSymlinkOptions>) -> Result<String>
+    fn get_symlink<S1, S2>(&self, bucket_name: S1, symlink_object_key: S2, options: Option<GetSymlinkOptions>) -> Result<GetSymlinkResult>
     where
         S1: AsRef<str>,
         S2: AsRef<str>;
@@ -54,19 +54,17 @@ impl ObjectSymlinkOperations for Client {
         Ok(headers.into())
     }
 
-    /// Get a symlink object. The returned string is the target object key
+    /// Get a symlink object. The result contains the target object key, the etag and user defined metadata of the symlink
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getsymlink>
-    fn get_symlink<S1, S2>(&self, bucket_name: S1, symlink_object_key: S2, options: Option<GetSymlinkOptions>) -> Result<String>
+    fn get_symlink<S1, S2>(&self, bucket_name: S1, symlink_object_key: S2, options: Option<GetSymlinkOptions>) -> Result<GetSymlinkResult>
     where
         S1: AsRef<str>,
         S2: AsRef<str>,
     {
         let request = build_get_symlink_request(bucket_name.as_ref(), symlink_object_key.as_ref(), &options)?;
         let (headers, _) = self.do_request::<()>(request)?;
-        let object_key = headers.get("x-oss-symlink-target").unwrap_or(&String::new()).to_string();
-
-        Ok(urlencoding::decode(&object_key)?.to_string())
+        GetSymlinkResult::from_headers(headers)
     }
 }
 
@@ -136,7 +134,8 @@ mod test_symlink_blocking {
         let response = client.get_symlink(bucket_name, &link_name, None);
         assert!(response.is_ok());
         let ret = response.unwrap();
-        assert_eq!(ret, object_key);
+        assert_eq!(ret.target_object_key, object_key);
+        assert_eq!(Some(&"meta value b".to_string()), ret.metadata.get("x-oss-meta-a"));
 
         let response = client.delete_object(bucket_name, &link_name, None);
         assert!(response.is_ok());
@@ -1,15 +1,21 @@
 //! Basic bucket operations
 use async_trait::async_trait;
+use futures::{Stream, StreamExt, TryStreamExt};
 
 use crate::{
     bucket_common::{
-        build_list_buckets_request, build_list_objects_request, build_put_bucket_request, extract_bucket_location, BucketDetail, BucketStat,
-        ListBucketsOptions, ListBucketsResult, ListObjectsOptions, ListObjectsResult, PutBucketConfiguration, PutBucketOptions,
+        build_get_bucket_lifecycle_request, build_list_buckets_request, build_list_object_versions_request, build_list_objects_request,
+        build_put_bucket_cors_request, build_put_bucket_encryption_request, build_put_bucket_lifecycle_request, build_put_bucket_policy_request,
+        build_put_bucket_referer_request, build_put_bucket_request, build_put_bucket_tagging_request, build_put_bucket_website_request,
+        extract_bucket_location, is_no_such_lifecycle_error, parse_bucket_tags_from_xml, BucketDetail, BucketStat, CorsConfiguration, LifecycleConfiguration,
+        LifecycleRule, ListBucketsOptions, ListBucketsResult, ListObjectVersionsOptions, ListObjectVersionsResult, ListObjectsOptions, ListObjectsResult,
+        ObjectSummary, PutBucketConfiguration, PutBucketOptions, RefererConfiguration, WebsiteConfiguration,
     },
+    common::ServerSideEncryptionRule,
     error::Error,
     request::{OssRequest, RequestMethod},
-    util::validate_bucket_name,
-    Result,
+    util::{human_size, validate_bucket_name},
+    Client, Result,
 };
 
 #[async_trait]
@@ -31,6 +37,14 @@ pub trait BucketOperations {
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketinfo>
     async fn get_bucket_info<S: AsRef<str> + Send>(&self, bucket_name: S) -> Result<BucketDetail>;
 
+    /// List buckets and get the detail (versioning, redundancy type, etc.) of each bucket.
+    /// Pages are followed automatically until all buckets matching `options` are listed.
+    ///
+    /// **CAUTION** One extra GetBucketInfo request is sent for each bucket, at most `concurrency` of them at the same time.
+    /// Keep `concurrency` small if you have many buckets, OSS limits the request rate of the account.
+    /// Details are returned in the same order as listed. Fails on the first error
+    async fn list_buckets_detailed(&self, options: Option<ListBucketsOptions>, concurrency: usize) -> Result<Vec<BucketDetail>>;
+
     /// Get bucket location
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketlocation>
@@ -52,12 +66,224 @@ pub trait BucketOperations {
     where
         S: AsRef<str> + Send;
 
+    /// List all versions of objects in a bucket, including delete markers
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/listobjectversions>
+    async fn list_object_versions<S>(&self, bucket_name: S, options: Option<ListObjectVersionsOptions>) -> Result<ListObjectVersionsResult>
+    where
+        S: AsRef<str> + Send;
+
     /// Delete a bucket
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebucket>
     async fn delete_bucket<S>(&self, bucket_name: S) -> Result<()>
     where
         S: AsRef<str> + Send;
+
+    /// List all objects in a bucket (v2), yielding objects one by one.
+    /// Continuation tokens are followed automatically until `is_truncated` is `false`,
+    /// `max_keys` in `options` is the page size of each request. The stream ends after yielding an error.
+    fn list_objects_stream<S>(&self, bucket_name: S, options: Option<ListObjectsOptions>) -> impl Stream<Item = Result<ObjectSummary>> + Send + '_
+    where
+        S: AsRef<str>;
+
+    /// List all object keys with the given prefix in a bucket.
+    /// Continuation tokens are followed automatically until all pages are fetched.
+    /// Pass an empty string as `prefix` to list all keys in the bucket.
+    fn list_keys<S1, S2>(&self, bucket_name: S1, prefix: S2) -> impl Stream<Item = Result<String>> + Send + '_
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>;
+
+    /// Get bucket lifecycle configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketlifecycle>
+    async fn get_bucket_lifecycle<S>(&self, bucket_name: S) -> Result<LifecycleConfiguration>
+    where
+        S: AsRef<str> + Send;
+
+    /// Put bucket lifecycle configuration. All existing rules are replaced
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketlifecycle>
+    async fn put_bucket_lifecycle<S>(&self, bucket_name: S, config: LifecycleConfiguration) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+
+    /// Delete bucket lifecycle configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebucketlifecycle>
+    async fn delete_bucket_lifecycle<S>(&self, bucket_name: S) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+
+    /// Add a lifecycle rule to the bucket, keeping all existing rules.
+    /// Fails if a rule with the same id already exists.
+    ///
+    /// This is a read-modify-write: the current configuration is fetched, modified and put back.
+    /// It is **not** atomic. If the lifecycle configuration is modified concurrently by others
+    /// between the get and the put, their changes are overwritten.
+    ///
+    /// Fails without modifying anything if the current configuration contains elements
+    /// which are not modeled by [`LifecycleRule`], because they would be lost when put back.
+    async fn add_lifecycle_rule<S>(&self, bucket_name: S, rule: LifecycleRule) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+
+    /// Remove the lifecycle rule with the given id from the bucket, keeping all other rules.
+    /// Returns `false` if no such rule exists. The lifecycle configuration is deleted if no rules left.
+    ///
+    /// This is a read-modify-write and has the same race as [`BucketOperations::add_lifecycle_rule`].
+    async fn remove_lifecycle_rule<S1, S2>(&self, bucket_name: S1, rule_id: S2) -> Result<bool>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+
+    /// Get bucket CORS configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketcors>
+    async fn get_bucket_cors<S>(&self, bucket_name: S) -> Result<CorsConfiguration>
+    where
+        S: AsRef<str> + Send;
+
+    /// Put bucket CORS configuration. All existing rules are replaced
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketcors>
+    async fn put_bucket_cors<S>(&self, bucket_name: S, config: CorsConfiguration) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+
+    /// Delete bucket CORS configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebucketcors>
+    async fn delete_bucket_cors<S>(&self, bucket_name: S) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+
+    /// Get bucket default server side encryption rule
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketencryption>
+    async fn get_bucket_encryption<S>(&self, bucket_name: S) -> Result<ServerSideEncryptionRule>
+    where
+        S: AsRef<str> + Send;
+
+    /// Put bucket default server side encryption rule
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketencryption>
+    async fn put_bucket_encryption<S>(&self, bucket_name: S, rule: ServerSideEncryptionRule) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+
+    /// Delete bucket default server side encryption rule
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebucketencryption>
+    async fn delete_bucket_encryption<S>(&self, bucket_name: S) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+
+    /// Get bucket static website configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketwebsite>
+    async fn get_bucket_website<S>(&self, bucket_name: S) -> Result<WebsiteConfiguration>
+    where
+        S: AsRef<str> + Send;
+
+    /// Put bucket static website configuration: index document, error document and routing rules
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketwebsite>
+    async fn put_bucket_website<S>(&self, bucket_name: S, config: WebsiteConfiguration) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+
+    /// Delete bucket static website configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebucketwebsite>
+    async fn delete_bucket_website<S>(&self, bucket_name: S) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+
+    /// Get bucket referer (anti-leech) configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketreferer>
+    async fn get_bucket_referer<S>(&self, bucket_name: S) -> Result<RefererConfiguration>
+    where
+        S: AsRef<str> + Send;
+
+    /// Put bucket referer (anti-leech) configuration. There is no delete operation,
+    /// put a configuration with empty referer list and `allow_empty_referer = true` to disable it
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketreferer>
+    async fn put_bucket_referer<S>(&self, bucket_name: S, config: RefererConfiguration) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+
+    /// Get bucket tags. The tags are returned in the order of the response
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbuckettags>
+    async fn get_bucket_tagging<S>(&self, bucket_name: S) -> Result<Vec<(String, String)>>
+    where
+        S: AsRef<str> + Send;
+
+    /// Put bucket tags. The existing tags of the bucket will be replaced.
+    /// At most 20 tags are allowed for a bucket
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbuckettags>
+    async fn put_bucket_tagging<S>(&self, bucket_name: S, tags: Vec<(String, String)>) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+
+    /// Delete all tags of the bucket
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebuckettags>
+    async fn delete_bucket_tagging<S>(&self, bucket_name: S) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+
+    /// Get bucket policy. The policy is returned as the raw JSON string
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketpolicy>
+    async fn get_bucket_policy<S>(&self, bucket_name: S) -> Result<String>
+    where
+        S: AsRef<str> + Send;
+
+    /// Put bucket policy. The existing policy of the bucket will be replaced
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketpolicy>
+    async fn put_bucket_policy<S1, S2>(&self, bucket_name: S1, policy: S2) -> Result<()>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+
+    /// Delete bucket policy
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebucketpolicy>
+    async fn delete_bucket_policy<S>(&self, bucket_name: S) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+}
+
+/// Fetch pages of list objects (V2) one by one by following `next_continuation_token`.
+pub(crate) fn list_objects_pages(
+    client: &Client,
+    bucket_name: String,
+    options: ListObjectsOptions,
+) -> impl Stream<Item = Result<ListObjectsResult>> + Send + '_ {
+    futures::stream::try_unfold(Some((bucket_name, options)), move |state| async move {
+        let Some((bucket_name, mut options)) = state else {
+            return Ok(None);
+        };
+
+        let page = client.list_objects(bucket_name.as_str(), Some(options.clone())).await?;
+
+        let next_state = match (&page.next_continuation_token, page.is_truncated) {
+            (Some(token), true) => {
+                options.continuation_token = Some(token.clone());
+                Some((bucket_name, options))
+            }
+            _ => None,
+        };
+
+        Ok(Some((page, next_state)))
+    })
 }
 
 #[async_trait]
@@ -95,6 +321,40 @@ impl BucketOperations for crate::Client {
         ListBucketsResult::from_xml(&content)
     }
 
+    /// List buckets and get the detail (versioning, redundancy type, etc.) of each bucket.
+    /// Pages are followed automatically until all buckets matching `options` are listed.
+    ///
+    /// **CAUTION** One extra GetBucketInfo request is sent for each bucket, at most `concurrency` of them at the same time.
+    /// Keep `concurrency` small if you have many buckets, OSS limits the request rate of the account.
+    /// Details are returned in the same order as listed. Fails on the first error
+    async fn list_buckets_detailed(&self, options: Option<ListBucketsOptions>, concurrency: usize) -> Result<Vec<BucketDetail>> {
+        let mut options = options.unwrap_or_default();
+        let mut buckets = vec![];
+
+        loop {
+            let page = self.list_buckets(Some(options.clone())).await?;
+            buckets.extend(page.buckets);
+
+            match (page.next_marker, page.is_truncated) {
+                (Some(marker), true) => options.marker = Some(marker),
+                _ => break,
+            }
+        }
+
+        futures::stream::iter(buckets)
+            .map(|bucket| async move {
+                // 其他地域的 Bucket 需要请求其所在地域的 Endpoint
+                if bucket.region == self.region {
+                    self.get_bucket_info(&bucket.name).await
+                } else {
+                    self.clone_to(&bucket.region, &bucket.extranet_endpoint).get_bucket_info(&bucket.name).await
+                }
+            })
+            .buffered(concurrency.max(1))
+            .try_collect()
+            .await
+    }
+
     /// Delete a bucket. Only non-empty bucket can be deleted
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebucket>
@@ -159,7 +419,18 @@ impl BucketOperations for crate::Client {
 
         let (_, content) = self.do_request::<String>(request_builder).await?;
 
-        BucketStat::from_xml(&content)
+        let stat = BucketStat::from_xml(&content)?;
+
+        oss_log!(
+            debug,
+            "bucket {} stat: {} objects, storage {}, standard storage {}",
+            bucket_name,
+            stat.object_count,
+            human_size(stat.storage),
+            human_size(stat.standard_storage)
+        );
+
+        Ok(stat)
     }
 
     /// List objects in a bucket (V2)
@@ -176,7 +447,421 @@ impl BucketOperations for crate::Client {
 
         let (_, content) = self.do_request::<String>(request).await?;
 
-        ListObjectsResult::from_xml(&content)
+        let ret = ListObjectsResult::from_xml(&content)?;
+
+        oss_log!(
+            debug,
+            "listed {} objects of {} in bucket {}",
+            ret.contents.len(),
+            human_size(ret.contents.iter().map(|obj| obj.size).sum()),
+            bucket_name
+        );
+
+        Ok(ret)
+    }
+
+    /// List all versions of objects in a bucket, including delete markers
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/listobjectversions>
+    async fn list_object_versions<S>(&self, bucket_name: S, options: Option<ListObjectVersionsOptions>) -> Result<ListObjectVersionsResult>
+    where
+        S: AsRef<str> + Send,
+    {
+        let request = build_list_object_versions_request(bucket_name.as_ref(), &options)?;
+
+        let (_, content) = self.do_request::<String>(request).await?;
+
+        ListObjectVersionsResult::from_xml(&content)
+    }
+
+    /// List all objects in a bucket (v2), yielding objects one by one.
+    /// Continuation tokens are followed automatically until `is_truncated` is `false`.
+    fn list_objects_stream<S>(&self, bucket_name: S, options: Option<ListObjectsOptions>) -> impl Stream<Item = Result<ObjectSummary>> + Send + '_
+    where
+        S: AsRef<str>,
+    {
+        list_objects_pages(self, bucket_name.as_ref().to_string(), options.unwrap_or_default())
+            .map_ok(|page| futures::stream::iter(page.contents.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    /// List all object keys with the given prefix in a bucket.
+    /// Continuation tokens are followed automatically until all pages are fetched.
+    fn list_keys<S1, S2>(&self, bucket_name: S1, prefix: S2) -> impl Stream<Item = Result<String>> + Send + '_
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let options = ListObjectsOptions {
+            prefix: Some(prefix.as_ref().to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+
+        self.list_objects_stream(bucket_name, Some(options)).map_ok(|obj| obj.key)
+    }
+
+    /// Get bucket lifecycle configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketlifecycle>
+    async fn get_bucket_lifecycle<S: AsRef<str> + Send>(&self, bucket_name: S) -> Result<LifecycleConfiguration> {
+        let request_builder = build_get_bucket_lifecycle_request(bucket_name.as_ref())?;
+
+        let (_, content) = self.do_request::<String>(request_builder).await?;
+
+        LifecycleConfiguration::from_xml(&content)
+    }
+
+    /// Put bucket lifecycle configuration. All existing rules are replaced
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketlifecycle>
+    async fn put_bucket_lifecycle<S: AsRef<str> + Send>(&self, bucket_name: S, config: LifecycleConfiguration) -> Result<()> {
+        let request_builder = build_put_bucket_lifecycle_request(bucket_name.as_ref(), &config)?;
+
+        self.do_request::<()>(request_builder).await?;
+
+        Ok(())
+    }
+
+    /// Delete bucket lifecycle configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebucketlifecycle>
+    async fn delete_bucket_lifecycle<S: AsRef<str> + Send>(&self, bucket_name: S) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).add_query("lifecycle", "");
+
+        self.do_request::<()>(request_builder).await?;
+
+        Ok(())
+    }
+
+    /// Add a lifecycle rule to the bucket, keeping all existing rules.
+    /// Fails if a rule with the same id already exists.
+    ///
+    /// This is a read-modify-write: the current configuration is fetched, modified and put back.
+    /// It is **not** atomic. If the lifecycle configuration is modified concurrently by others
+    /// between the get and the put, their changes are overwritten.
+    ///
+    /// Fails without modifying anything if the current configuration contains elements
+    /// which are not modeled by [`LifecycleRule`], because they would be lost when put back.
+    async fn add_lifecycle_rule<S: AsRef<str> + Send>(&self, bucket_name: S, rule: LifecycleRule) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        // 严格解析，遇到未建模的元素时报错，避免写回时丢失这些配置
+        let ret = self
+            .do_request::<String>(build_get_bucket_lifecycle_request(bucket_name)?)
+            .await
+            .and_then(|(_, content)| LifecycleConfiguration::from_xml_ext(&content, true));
+
+        let mut config = match ret {
+            Ok(c) => c,
+            Err(e) if is_no_such_lifecycle_error(&e) => LifecycleConfiguration::default(),
+            Err(e) => return Err(e),
+        };
+
+        config.add_rule(rule)?;
+
+        self.put_bucket_lifecycle(bucket_name, config).await
+    }
+
+    /// Remove the lifecycle rule with the given id from the bucket, keeping all other rules.
+    /// Returns `false` if no such rule exists. The lifecycle configuration is deleted if no rules left.
+    ///
+    /// This is a read-modify-write and has the same race as [`BucketOperations::add_lifecycle_rule`].
+    async fn remove_lifecycle_rule<S1, S2>(&self, bucket_name: S1, rule_id: S2) -> Result<bool>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let bucket_name = bucket_name.as_ref();
+
+        // 严格解析，遇到未建模的元素时报错，避免写回时丢失这些配置
+        let ret = self
+            .do_request::<String>(build_get_bucket_lifecycle_request(bucket_name)?)
+            .await
+            .and_then(|(_, content)| LifecycleConfiguration::from_xml_ext(&content, true));
+
+        let mut config = match ret {
+            Ok(c) => c,
+            Err(e) if is_no_such_lifecycle_error(&e) => return Ok(false),
+            Err(e) => return Err(e),
+        };
+
+        if !config.remove_rule(rule_id.as_ref()) {
+            return Ok(false);
+        }
+
+        // 不允许提交空的规则列表，没有规则时直接删除生命周期配置
+        if config.rules.is_empty() {
+            self.delete_bucket_lifecycle(bucket_name).await?;
+        } else {
+            self.put_bucket_lifecycle(bucket_name, config).await?;
+        }
+
+        Ok(true)
+    }
+
+    /// Get bucket CORS configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketcors>
+    async fn get_bucket_cors<S: AsRef<str> + Send>(&self, bucket_name: S) -> Result<CorsConfiguration> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("cors", "");
+
+        let (_, content) = self.do_request::<String>(request_builder).await?;
+
+        CorsConfiguration::from_xml(&content)
+    }
+
+    /// Put bucket CORS configuration. All existing rules are replaced
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketcors>
+    async fn put_bucket_cors<S: AsRef<str> + Send>(&self, bucket_name: S, config: CorsConfiguration) -> Result<()> {
+        let request_builder = build_put_bucket_cors_request(bucket_name.as_ref(), &config)?;
+
+        self.do_request::<()>(request_builder).await?;
+
+        Ok(())
+    }
+
+    /// Delete bucket CORS configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebucketcors>
+    async fn delete_bucket_cors<S: AsRef<str> + Send>(&self, bucket_name: S) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).add_query("cors", "");
+
+        self.do_request::<()>(request_builder).await?;
+
+        Ok(())
+    }
+
+    /// Get bucket default server side encryption rule
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketencryption>
+    async fn get_bucket_encryption<S: AsRef<str> + Send>(&self, bucket_name: S) -> Result<ServerSideEncryptionRule> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("encryption", "");
+
+        let (_, content) = self.do_request::<String>(request_builder).await?;
+
+        ServerSideEncryptionRule::from_xml(&content)
+    }
+
+    /// Put bucket default server side encryption rule
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketencryption>
+    async fn put_bucket_encryption<S: AsRef<str> + Send>(&self, bucket_name: S, rule: ServerSideEncryptionRule) -> Result<()> {
+        let request_builder = build_put_bucket_encryption_request(bucket_name.as_ref(), &rule)?;
+
+        self.do_request::<()>(request_builder).await?;
+
+        Ok(())
+    }
+
+    /// Delete bucket default server side encryption rule
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebucketencryption>
+    async fn delete_bucket_encryption<S: AsRef<str> + Send>(&self, bucket_name: S) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).add_query("encryption", "");
+
+        self.do_request::<()>(request_builder).await?;
+
+        Ok(())
+    }
+
+    /// Get bucket static website configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketwebsite>
+    async fn get_bucket_website<S: AsRef<str> + Send>(&self, bucket_name: S) -> Result<WebsiteConfiguration> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("website", "");
+
+        let (_, content) = self.do_request::<String>(request_builder).await?;
+
+        WebsiteConfiguration::from_xml(&content)
+    }
+
+    /// Put bucket static website configuration: index document, error document and routing rules
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketwebsite>
+    async fn put_bucket_website<S: AsRef<str> + Send>(&self, bucket_name: S, config: WebsiteConfiguration) -> Result<()> {
+        let request_builder = build_put_bucket_website_request(bucket_name.as_ref(), &config)?;
+
+        self.do_request::<()>(request_builder).await?;
+
+        Ok(())
+    }
+
+    /// Delete bucket static website configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebucketwebsite>
+    async fn delete_bucket_website<S: AsRef<str> + Send>(&self, bucket_name: S) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).add_query("website", "");
+
+        self.do_request::<()>(request_builder).await?;
+
+        Ok(())
+    }
+
+    /// Get bucket referer (anti-leech) configuration
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketreferer>
+    async fn get_bucket_referer<S: AsRef<str> + Send>(&self, bucket_name: S) -> Result<RefererConfiguration> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("referer", "");
+
+        let (_, content) = self.do_request::<String>(request_builder).await?;
+
+        RefererConfiguration::from_xml(&content)
+    }
+
+    /// Put bucket referer (anti-leech) configuration. There is no delete operation,
+    /// put a configuration with empty referer list and `allow_empty_referer = true` to disable it
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketreferer>
+    async fn put_bucket_referer<S: AsRef<str> + Send>(&self, bucket_name: S, config: RefererConfiguration) -> Result<()> {
+        let request_builder = build_put_bucket_referer_request(bucket_name.as_ref(), &config)?;
+
+        self.do_request::<()>(request_builder).await?;
+
+        Ok(())
+    }
+
+    /// Get bucket tags. The tags are returned in the order of the response
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbuckettags>
+    async fn get_bucket_tagging<S: AsRef<str> + Send>(&self, bucket_name: S) -> Result<Vec<(String, String)>> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("tagging", "");
+
+        let (_, content) = self.do_request::<String>(request_builder).await?;
+
+        parse_bucket_tags_from_xml(&content)
+    }
+
+    /// Put bucket tags. The existing tags of the bucket will be replaced.
+    /// At most 20 tags are allowed for a bucket
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbuckettags>
+    async fn put_bucket_tagging<S: AsRef<str> + Send>(&self, bucket_name: S, tags: Vec<(String, String)>) -> Result<()> {
+        let request_builder = build_put_bucket_tagging_request(bucket_name.as_ref(), &tags)?;
+
+        self.do_request::<()>(request_builder).await?;
+
+        Ok(())
+    }
+
+    /// Delete all tags of the bucket
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebuckettags>
+    async fn delete_bucket_tagging<S: AsRef<str> + Send>(&self, bucket_name: S) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).add_query("tagging", "");
+
+        self.do_request::<()>(request_builder).await?;
+
+        Ok(())
+    }
+
+    /// Get bucket policy. The policy is returned as the raw JSON string
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketpolicy>
+    async fn get_bucket_policy<S: AsRef<str> + Send>(&self, bucket_name: S) -> Result<String> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("policy", "");
+
+        let (_, content) = self.do_request::<String>(request_builder).await?;
+
+        Ok(content)
+    }
+
+    /// Put bucket policy. The existing policy of the bucket will be replaced
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketpolicy>
+    async fn put_bucket_policy<S1, S2>(&self, bucket_name: S1, policy: S2) -> Result<()>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let request_builder = build_put_bucket_policy_request(bucket_name.as_ref(), policy.as_ref())?;
+
+        self.do_request::<()>(request_builder).await?;
+
+        Ok(())
+    }
+
+    /// Delete bucket policy
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebucketpolicy>
+    async fn delete_bucket_policy<S: AsRef<str> + Send>(&self, bucket_name: S) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        let request_builder = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).add_query("policy", "");
+
+        self.do_request::<()>(request_builder).await?;
+
+        Ok(())
     }
 }
 
@@ -184,9 +869,12 @@ impl BucketOperations for crate::Client {
 pub mod test_bucket_async {
     use std::sync::Once;
 
+    use futures::{StreamExt, TryStreamExt};
+
     use crate::{
         bucket::BucketOperations,
         bucket_common::{BucketAcl, ListBucketsOptions, ListObjectsOptionsBuilder},
+        util::human_size,
     };
 
     static INIT: Once = Once::new();
@@ -223,6 +911,20 @@ pub mod test_bucket_async {
         log::debug!("{:?}", result);
     }
 
+    #[tokio::test]
+    async fn test_list_buckets_detailed_async() {
+        setup();
+        let client = crate::Client::from_env();
+
+        let buckets = client.list_buckets(None).await.unwrap().buckets;
+        let details = client.list_buckets_detailed(None, 4).await.unwrap();
+
+        assert_eq!(buckets.len(), details.len());
+        for (summary, detail) in buckets.iter().zip(details.iter()) {
+            assert_eq!(summary.name, detail.name);
+        }
+    }
+
     #[tokio::test]
     async fn test_list_buckets_with_options_async() {
         setup_comp();
@@ -273,6 +975,33 @@ pub mod test_bucket_async {
         assert_eq!(result.key_count, (result.common_prefixes.len() + result.contents.len()) as u64);
     }
 
+    /// List a page, checkpoint the last key, then resume right after it with `start_after`
+    #[tokio::test]
+    async fn test_list_objects_resume_with_start_after_async() {
+        setup_comp();
+        let client = crate::Client::from_env();
+
+        let options = ListObjectsOptionsBuilder::new().max_keys(5).build();
+        let page1 = client.list_objects("mi-dev-public", Some(options)).await.unwrap();
+        assert!(!page1.contents.is_empty());
+
+        let checkpoint = page1.contents.last().unwrap().key.clone();
+
+        let options = ListObjectsOptionsBuilder::new().max_keys(5).start_after(&checkpoint).build();
+        let page2 = client.list_objects("mi-dev-public", Some(options)).await.unwrap();
+
+        assert!(page2.contents.iter().all(|o| o.key > checkpoint));
+
+        if page1.is_truncated {
+            let options = ListObjectsOptionsBuilder::new()
+                .max_keys(5)
+                .continuation_token(page1.next_continuation_token.unwrap())
+                .build();
+            let page2_by_token = client.list_objects("mi-dev-public", Some(options)).await.unwrap();
+            assert_eq!(page2_by_token.contents.first().map(|o| &o.key), page2.contents.first().map(|o| &o.key));
+        }
+    }
+
     #[tokio::test]
     async fn test_get_bucket_detail_async() {
         setup_comp();
@@ -290,4 +1019,53 @@ pub mod test_bucket_async {
         let ret = response.unwrap();
         assert_eq!(BucketAcl::Private, ret.access_control_list[0]);
     }
+
+    #[tokio::test]
+    async fn test_get_bucket_stat_async() {
+        setup();
+        let client = crate::Client::from_env();
+
+        let response = client.get_bucket_stat("mi-dev-public").await;
+        assert!(response.is_ok());
+
+        let stat = response.unwrap();
+        log::debug!(
+            "storage: {}, standard: {}, deep cold archive: {}",
+            human_size(stat.storage),
+            human_size(stat.standard_storage),
+            human_size(stat.deep_cold_archive_storage)
+        );
+        assert!(stat.object_count > 0);
+    }
+
+    #[tokio::test]
+    async fn test_list_keys_async() {
+        setup_comp();
+        let client = crate::Client::from_env();
+
+        let keys = client.list_keys("mi-dev-public", "").try_collect::<Vec<_>>().await;
+        assert!(keys.is_ok());
+
+        let keys = keys.unwrap();
+        assert!(!keys.is_empty());
+        assert!(keys.iter().all(|k| !k.is_empty()));
+    }
+
+    #[tokio::test]
+    async fn test_list_objects_stream_async() {
+        setup_comp();
+        let client = crate::Client::from_env();
+
+        // 每页 2 个，验证自动翻页
+        let options = ListObjectsOptionsBuilder::new().max_keys(2).build();
+        let objects = client.list_objects_stream("mi-dev-public", Some(options)).try_collect::<Vec<_>>().await;
+        assert!(objects.is_ok());
+
+        let keys = client.list_keys("mi-dev-public", "").try_collect::<Vec<_>>().await.unwrap();
+        assert_eq!(keys, objects.unwrap().into_iter().map(|obj| obj.key).collect::<Vec<_>>());
+
+        let mut stream = Box::pin(client.list_objects_stream("-invalid-bucket", None));
+        assert!(stream.next().await.unwrap().is_err());
+        assert!(stream.next().await.is_none());
+    }
 }
@@ -7,9 +7,9 @@ use crate::{
         self, AccessMonitor, CrossRegionReplication, DataRedundancyType, ObjectType, Owner, ServerSideEncryptionAlgorithm, ServerSideEncryptionRule,
         StorageClass, TransferAcceleration, Versioning,
     },
-    error::Error,
+    error::{Error, OssErrorCode},
     request::{OssRequest, RequestMethod},
-    util::{sanitize_etag, validate_bucket_name, validate_tag_key, validate_tag_value},
+    util::{content_md5_base64, sanitize_etag, validate_bucket_name, validate_bucket_tag_value, validate_tag_key, validate_tag_value},
     Result,
 };
 
@@ -175,6 +175,9 @@ pub struct BucketDetail {
     pub resource_group_id: Option<String>,
     pub comment: Option<String>,
     pub versioning: Option<Versioning>,
+
+    /// 取自 `<AccessControlList>` 中的 `<Grant>`。
+    /// OSS 的 `Grant` 只包含预设的访问权限（`private`、`public-read` 或 `public-read-write`），不会返回被授权者的信息
     pub access_control_list: Vec<BucketAcl>,
     pub bucket_policy: BucketPolicy,
     pub server_side_encryption_rule: Option<ServerSideEncryptionRule>,
@@ -678,6 +681,9 @@ pub struct ListObjectsOptions {
     pub start_after: Option<String>,
 
     /// 指定 List 操作需要从此 `token` 开始。您可从请求结果中的 `next_continuation_token` 获取此 `token`。
+    /// 请原样传入 `next_continuation_token`，不要自行 URL 编码，发送请求时会对其中的 `+`、`/`、`=` 等字符进行编码。
+    /// 同时指定 `start_after` 和 `continuation_token` 时，OSS 以 `continuation_token` 为准。
+    /// 增量同步等场景可以记录最后处理的 Object 的 key，下次使用 `start_after` 从该 key 之后继续列举
     pub continuation_token: Option<String>,
 
     /// 指定返回 Object 的最大数。取值：大于 0 小于等于 1000
@@ -745,99 +751,2436 @@ impl ListObjectsOptionsBuilder {
     }
 }
 
-pub(crate) fn build_put_bucket_request(bucket_name: &str, config: &PutBucketConfiguration, options: &Option<PutBucketOptions>) -> Result<OssRequest> {
-    let xml = config.to_xml()?;
+/// Object version data for list object versions
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct ObjectVersion {
+    pub key: String,
+    pub version_id: String,
 
-    let mut request = crate::request::OssRequest::new()
-        .method(RequestMethod::Put)
-        .bucket(bucket_name)
-        .content_type(common::MIME_TYPE_XML)
-        .text_body(xml);
+    /// 是否为 Object 的当前版本
+    pub is_latest: bool,
 
-    if let Some(options) = options {
-        if let Some(acl) = &options.acl {
-            request = request.add_header("x-oss-acl", acl.as_str());
-        }
+    /// 最后修改时间。 e.g. `2012-02-24T08:42:32.000Z`
+    pub last_modified: String,
 
-        if let Some(resource_group_id) = &options.resource_group_id {
-            request = request.add_header("x-oss-resource-group-id", resource_group_id);
-        }
+    /// 清理了首尾的双引号之后的 ETag。
+    pub etag: String,
 
-        if !options.tags.is_empty() {
-            if !options.tags.iter().all(|(k, v)| validate_tag_key(k) && validate_tag_value(v)) {
-                return Err(Error::Other(format!("invalid tag name or tag key: {:#?}", options.tags)));
-            }
+    pub object_type: ObjectType,
 
-            let tags_string = options
-                .tags
-                .iter()
-                .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(v)))
-                .collect::<Vec<String>>()
-                .join("&");
+    /// 以字节为单位的文件大小
+    pub size: u64,
 
-            request = request.add_header("x-oss-bucket-tagging", &tags_string);
+    pub storage_class: StorageClass,
+    pub owner: Option<Owner>,
+}
+
+impl ObjectVersion {
+    pub(crate) fn from_xml_reader(reader: &mut quick_xml::Reader<&[u8]>) -> Result<Self> {
+        let mut tag = String::new();
+        let mut data = Self::default();
+
+        loop {
+            match reader.read_event()? {
+                Event::Eof => break,
+                Event::Start(t) => match t.local_name().as_ref() {
+                    b"Owner" => data.owner = Some(Owner::from_xml_reader(reader)?),
+                    _ => tag = String::from_utf8_lossy(t.local_name().as_ref()).to_string(),
+                },
+                Event::Text(text) => {
+                    let s = text.unescape()?.trim().to_string();
+                    match tag.as_str() {
+                        "Key" => data.key = s,
+                        "VersionId" => data.version_id = s,
+                        "IsLatest" => data.is_latest = s == "true",
+                        "LastModified" => data.last_modified = s,
+                        "ETag" => data.etag = sanitize_etag(s),
+                        "Type" => data.object_type = ObjectType::try_from(s)?,
+                        "Size" => data.size = s.parse()?,
+                        "StorageClass" => data.storage_class = StorageClass::try_from(s)?,
+                        _ => {}
+                    }
+                }
+                Event::End(t) => {
+                    if t.local_name().as_ref() == b"Version" {
+                        break;
+                    }
+                    tag.clear();
+                }
+                _ => {}
+            }
         }
+
+        Ok(data)
     }
+}
 
-    Ok(request)
+/// Delete marker data for list object versions
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct DeleteMarker {
+    pub key: String,
+    pub version_id: String,
+
+    /// 是否为 Object 的当前版本。当前版本是删除标记时，Object 表现为已删除
+    pub is_latest: bool,
+
+    /// 最后修改时间。 e.g. `2012-02-24T08:42:32.000Z`
+    pub last_modified: String,
+
+    pub owner: Option<Owner>,
 }
 
-pub(crate) fn build_list_buckets_request(options: &Option<ListBucketsOptions>) -> OssRequest {
-    let mut request = OssRequest::new();
+impl DeleteMarker {
+    pub(crate) fn from_xml_reader(reader: &mut quick_xml::Reader<&[u8]>) -> Result<Self> {
+        let mut tag = String::new();
+        let mut data = Self::default();
 
-    if let Some(opt) = options {
-        if let Some(prefix) = &opt.prefix {
-            request = request.add_query("prefix", prefix);
-        }
-        if let Some(marker) = &opt.marker {
-            request = request.add_query("marker", marker);
-        }
-        if let Some(max_keys) = opt.max_keys {
-            request = request.add_query("max-keys", max_keys.to_string());
+        loop {
+            match reader.read_event()? {
+                Event::Eof => break,
+                Event::Start(t) => match t.local_name().as_ref() {
+                    b"Owner" => data.owner = Some(Owner::from_xml_reader(reader)?),
+                    _ => tag = String::from_utf8_lossy(t.local_name().as_ref()).to_string(),
+                },
+                Event::Text(text) => {
+                    let s = text.unescape()?.trim().to_string();
+                    match tag.as_str() {
+                        "Key" => data.key = s,
+                        "VersionId" => data.version_id = s,
+                        "IsLatest" => data.is_latest = s == "true",
+                        "LastModified" => data.last_modified = s,
+                        _ => {}
+                    }
+                }
+                Event::End(t) => {
+                    if t.local_name().as_ref() == b"DeleteMarker" {
+                        break;
+                    }
+                    tag.clear();
+                }
+                _ => {}
+            }
         }
-        if let Some(oss_resource_group_id) = &opt.resource_group_id {
-            request = request.add_header("x-oss-resource-group-id", oss_resource_group_id)
+
+        Ok(data)
+    }
+}
+
+/// Result of list object versions
+///
+/// Official document: <https://help.aliyun.com/zh/oss/developer-reference/listobjectversions>
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct ListObjectVersionsResult {
+    pub name: String,
+    pub prefix: String,
+    pub key_marker: Option<String>,
+    pub version_id_marker: Option<String>,
+
+    /// 结果被截断时，作为下一次请求的 `key_marker`
+    pub next_key_marker: Option<String>,
+
+    /// 结果被截断时，作为下一次请求的 `version_id_marker`
+    pub next_version_id_marker: Option<String>,
+
+    pub max_keys: u32,
+    pub delimiter: Option<char>,
+    pub is_truncated: bool,
+    pub common_prefixes: Vec<String>,
+    pub versions: Vec<ObjectVersion>,
+    pub delete_markers: Vec<DeleteMarker>,
+}
+
+impl ListObjectVersionsResult {
+    pub(crate) fn from_xml(xml: &str) -> Result<Self> {
+        let mut reader = quick_xml::Reader::from_str(xml);
+        let mut tag = String::new();
+        let mut data = Self::default();
+
+        let mut tags = vec![];
+
+        loop {
+            match reader.read_event()? {
+                Event::Eof => break,
+                Event::Start(t) => match t.local_name().as_ref() {
+                    b"Version" => data.versions.push(ObjectVersion::from_xml_reader(&mut reader)?),
+                    b"DeleteMarker" => data.delete_markers.push(DeleteMarker::from_xml_reader(&mut reader)?),
+                    _ => {
+                        tag = String::from_utf8_lossy(t.local_name().as_ref()).to_string();
+                        tags.push(tag.clone());
+                    }
+                },
+                Event::Text(text) => {
+                    let s = text.unescape()?.trim().to_string();
+                    match tag.as_str() {
+                        "Name" => data.name = s,
+                        "KeyMarker" => data.key_marker = if s.is_empty() { None } else { Some(s) },
+                        "VersionIdMarker" => data.version_id_marker = if s.is_empty() { None } else { Some(s) },
+                        "NextKeyMarker" => data.next_key_marker = if s.is_empty() { None } else { Some(s) },
+                        "NextVersionIdMarker" => data.next_version_id_marker = if s.is_empty() { None } else { Some(s) },
+                        "MaxKeys" => data.max_keys = s.parse()?,
+                        "Delimiter" => data.delimiter = if s.is_empty() { None } else { s.chars().next() },
+                        "IsTruncated" => data.is_truncated = s == "true",
+                        "Prefix" => {
+                            // `Prefix` under root element, or under `root/CommonPrefixes`
+                            if tags.len() == 2 {
+                                data.prefix = s;
+                            } else if tags.len() == 3 {
+                                data.common_prefixes.push(s);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Event::End(_) => {
+                    tags.pop();
+                    tag.clear();
+                }
+                _ => {}
+            }
         }
+
+        Ok(data)
     }
+}
 
-    request
+/// Query options for listing object versions
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct ListObjectVersionsOptions {
+    pub delimiter: Option<char>,
+
+    /// 从 `key_marker` 之后按字母排序开始返回
+    pub key_marker: Option<String>,
+
+    /// 与 `key_marker` 配合使用，从 `key_marker` 的该版本之后开始返回
+    pub version_id_marker: Option<String>,
+
+    /// 指定返回的最大数。取值：大于 0 小于等于 1000
+    pub max_keys: Option<u32>,
+
+    pub prefix: Option<String>,
 }
 
-pub(crate) fn build_list_objects_request(bucket_name: &str, options: &Option<ListObjectsOptions>) -> Result<OssRequest> {
-    if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+#[derive(Debug, Default)]
+pub struct ListObjectVersionsOptionsBuilder {
+    options: ListObjectVersionsOptions,
+}
+
+impl ListObjectVersionsOptionsBuilder {
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    let mut request = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("list-type", "2");
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.options.delimiter = Some(delimiter);
+        self
+    }
 
-    if let Some(options) = options {
-        if let Some(c) = options.delimiter {
-            request = request.add_query("delimiter", c.to_string());
-        }
+    pub fn key_marker<T: Into<String>>(mut self, key_marker: T) -> Self {
+        self.options.key_marker = Some(key_marker.into());
+        self
+    }
 
-        if let Some(s) = &options.prefix {
-            request = request.add_query("prefix", s);
-        }
+    pub fn version_id_marker<T: Into<String>>(mut self, version_id_marker: T) -> Self {
+        self.options.version_id_marker = Some(version_id_marker.into());
+        self
+    }
 
-        if let Some(u) = options.max_keys {
-            if u == 0 || u > 1000 {
-                return Err(Error::Other(format!("invalid max-keys: {}. must between 1 and 1000", u)));
-            }
-            request = request.add_query("max-keys", u.to_string());
-        }
+    pub fn max_keys(mut self, max_keys: u32) -> Self {
+        self.options.max_keys = Some(max_keys);
+        self
+    }
 
-        if let Some(s) = &options.start_after {
-            request = request.add_query("start-after", s);
-        }
+    pub fn prefix<T: Into<String>>(mut self, prefix: T) -> Self {
+        self.options.prefix = Some(prefix.into());
+        self
+    }
 
-        if let Some(s) = &options.continuation_token {
-            request = request.add_query("continuation-token", s);
-        }
+    pub fn build(self) -> ListObjectVersionsOptions {
+        self.options
+    }
+}
 
-        if let Some(b) = &options.fetch_owner {
-            request = request.add_query("fetch-owner", b.to_string());
+/// Status of a lifecycle rule
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum LifecycleRuleStatus {
+    #[default]
+    #[cfg_attr(feature = "serde-support", serde(rename = "Enabled"))]
+    Enabled,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "Disabled"))]
+    Disabled,
+}
+
+impl LifecycleRuleStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            LifecycleRuleStatus::Enabled => "Enabled",
+            LifecycleRuleStatus::Disabled => "Disabled",
         }
     }
+}
 
-    Ok(request)
+impl AsRef<str> for LifecycleRuleStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl TryFrom<&str> for LifecycleRuleStatus {
+    type Error = crate::error::Error;
+
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        match s {
+            "Enabled" => Ok(LifecycleRuleStatus::Enabled),
+            "Disabled" => Ok(LifecycleRuleStatus::Disabled),
+            _ => Err(Error::Other(format!("Invalid lifecycle rule status value: {}", s))),
+        }
+    }
+}
+
+impl TryFrom<String> for LifecycleRuleStatus {
+    type Error = crate::error::Error;
+
+    fn try_from(s: String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+/// Filter of a lifecycle rule.
+/// If more than one condition is set, they are combined with `<And>` when serialized.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct LifecycleFilter {
+    pub prefix: Option<String>,
+
+    /// 对象标签，按照 `(key, value)` 的形式保存
+    pub tags: Vec<(String, String)>,
+
+    /// 仅匹配大于该大小的对象，单位为字节
+    pub object_size_greater_than: Option<u64>,
+
+    /// 仅匹配小于该大小的对象，单位为字节
+    pub object_size_less_than: Option<u64>,
+}
+
+impl LifecycleFilter {
+    fn condition_count(&self) -> usize {
+        self.prefix.iter().count() + self.tags.len() + self.object_size_greater_than.iter().count() + self.object_size_less_than.iter().count()
+    }
+
+    fn write_xml<W: std::io::Write>(&self, writer: &mut quick_xml::Writer<W>) -> Result<()> {
+        writer.write_event(Event::Start(BytesStart::new("Filter")))?;
+
+        let use_and = self.condition_count() > 1;
+        if use_and {
+            writer.write_event(Event::Start(BytesStart::new("And")))?;
+        }
+
+        if let Some(s) = &self.prefix {
+            write_text_element(writer, "Prefix", s)?;
+        }
+
+        for (k, v) in &self.tags {
+            writer.write_event(Event::Start(BytesStart::new("Tag")))?;
+            write_text_element(writer, "Key", k)?;
+            write_text_element(writer, "Value", v)?;
+            writer.write_event(Event::End(BytesEnd::new("Tag")))?;
+        }
+
+        if let Some(n) = self.object_size_greater_than {
+            write_text_element(writer, "ObjectSizeGreaterThan", &n.to_string())?;
+        }
+
+        if let Some(n) = self.object_size_less_than {
+            write_text_element(writer, "ObjectSizeLessThan", &n.to_string())?;
+        }
+
+        if use_and {
+            writer.write_event(Event::End(BytesEnd::new("And")))?;
+        }
+
+        writer.write_event(Event::End(BytesEnd::new("Filter")))?;
+
+        Ok(())
+    }
+}
+
+/// Expiration action of a lifecycle rule. Only one of `days` and `created_before_date` should be set
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct LifecycleExpiration {
+    /// 距离对象最后修改时间的天数
+    pub days: Option<u32>,
+
+    /// 在该日期之前最后修改的对象过期。e.g. `2002-10-11T00:00:00.000Z`
+    pub created_before_date: Option<String>,
+
+    /// 是否自动删除过期的删除标记，仅适用于开启了版本控制的 bucket
+    pub expired_object_delete_marker: Option<bool>,
+}
+
+/// Transition action of a lifecycle rule. Only one of `days` and `created_before_date` should be set
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct LifecycleTransition {
+    pub days: Option<u32>,
+    pub created_before_date: Option<String>,
+    pub storage_class: StorageClass,
+}
+
+/// Transition action of noncurrent versions of a lifecycle rule, used in versioning enabled buckets
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct LifecycleNoncurrentVersionTransition {
+    /// 对象成为非当前版本之后的天数
+    pub noncurrent_days: u32,
+    pub storage_class: StorageClass,
+}
+
+/// A rule of bucket lifecycle configuration
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct LifecycleRule {
+    pub id: String,
+
+    /// 规则所适用的对象前缀。为空字符串时表示适用于整个 bucket
+    pub prefix: String,
+    pub status: LifecycleRuleStatus,
+
+    /// 规则所适用的对象标签，按照 `(key, value)` 的形式保存。只有同时匹配前缀和所有标签的对象才适用该规则
+    pub tags: Vec<(String, String)>,
+
+    pub filter: Option<LifecycleFilter>,
+    pub expiration: Option<LifecycleExpiration>,
+    pub transitions: Vec<LifecycleTransition>,
+
+    /// 未完成的分片上传在初始化多少天之后被删除
+    pub abort_multipart_upload_days: Option<u32>,
+
+    /// 在该日期之前初始化的未完成的分片上传被删除。e.g. `2002-10-11T00:00:00.000Z`
+    pub abort_multipart_upload_created_before_date: Option<String>,
+
+    /// 非当前版本的对象在成为非当前版本多少天之后过期，仅适用于开启了版本控制的 bucket
+    pub noncurrent_version_expiration_days: Option<u32>,
+
+    /// 非当前版本对象的存储类型转换，仅适用于开启了版本控制的 bucket
+    pub noncurrent_version_transitions: Vec<LifecycleNoncurrentVersionTransition>,
+}
+
+/// 未配置生命周期规则时，GetBucketLifecycle 返回 404 `NoSuchLifecycle`
+pub(crate) fn is_no_such_lifecycle_error(e: &Error) -> bool {
+    e.oss_error_code() == Some(OssErrorCode::NoSuchLifecycle)
+}
+
+/// Bucket lifecycle configuration
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct LifecycleConfiguration {
+    pub rules: Vec<LifecycleRule>,
+}
+
+impl LifecycleConfiguration {
+    /// Append a rule. Fails if a rule with the same non-empty id already exists
+    pub fn add_rule(&mut self, rule: LifecycleRule) -> Result<()> {
+        if !rule.id.is_empty() && self.rules.iter().any(|r| r.id == rule.id) {
+            return Err(Error::Other(format!("lifecycle rule already exists: {}", rule.id)));
+        }
+
+        self.rules.push(rule);
+        Ok(())
+    }
+
+    /// Remove the rule with the given id. Returns `false` if no such rule exists
+    pub fn remove_rule(&mut self, rule_id: &str) -> bool {
+        let len = self.rules.len();
+        self.rules.retain(|r| r.id != rule_id);
+        self.rules.len() != len
+    }
+
+    pub(crate) fn to_xml(&self) -> Result<String> {
+        let mut writer = quick_xml::Writer::new(Vec::new());
+        writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+
+        writer.write_event(Event::Start(BytesStart::new("LifecycleConfiguration")))?;
+
+        for rule in &self.rules {
+            writer.write_event(Event::Start(BytesStart::new("Rule")))?;
+
+            if !rule.id.is_empty() {
+                write_text_element(&mut writer, "ID", &rule.id)?;
+            }
+
+            write_text_element(&mut writer, "Prefix", &rule.prefix)?;
+            write_text_element(&mut writer, "Status", rule.status.as_str())?;
+
+            for (k, v) in &rule.tags {
+                writer.write_event(Event::Start(BytesStart::new("Tag")))?;
+                write_text_element(&mut writer, "Key", k)?;
+                write_text_element(&mut writer, "Value", v)?;
+                writer.write_event(Event::End(BytesEnd::new("Tag")))?;
+            }
+
+            if let Some(filter) = &rule.filter {
+                filter.write_xml(&mut writer)?;
+            }
+
+            if let Some(expiration) = &rule.expiration {
+                writer.write_event(Event::Start(BytesStart::new("Expiration")))?;
+                if let Some(n) = expiration.days {
+                    write_text_element(&mut writer, "Days", &n.to_string())?;
+                }
+                if let Some(s) = &expiration.created_before_date {
+                    write_text_element(&mut writer, "CreatedBeforeDate", s)?;
+                }
+                if let Some(b) = expiration.expired_object_delete_marker {
+                    write_text_element(&mut writer, "ExpiredObjectDeleteMarker", &b.to_string())?;
+                }
+                writer.write_event(Event::End(BytesEnd::new("Expiration")))?;
+            }
+
+            for transition in &rule.transitions {
+                writer.write_event(Event::Start(BytesStart::new("Transition")))?;
+                if let Some(n) = transition.days {
+                    write_text_element(&mut writer, "Days", &n.to_string())?;
+                }
+                if let Some(s) = &transition.created_before_date {
+                    write_text_element(&mut writer, "CreatedBeforeDate", s)?;
+                }
+                write_text_element(&mut writer, "StorageClass", transition.storage_class.as_str())?;
+                writer.write_event(Event::End(BytesEnd::new("Transition")))?;
+            }
+
+            if rule.abort_multipart_upload_days.is_some() || rule.abort_multipart_upload_created_before_date.is_some() {
+                writer.write_event(Event::Start(BytesStart::new("AbortMultipartUpload")))?;
+                if let Some(n) = rule.abort_multipart_upload_days {
+                    write_text_element(&mut writer, "Days", &n.to_string())?;
+                }
+                if let Some(s) = &rule.abort_multipart_upload_created_before_date {
+                    write_text_element(&mut writer, "CreatedBeforeDate", s)?;
+                }
+                writer.write_event(Event::End(BytesEnd::new("AbortMultipartUpload")))?;
+            }
+
+            if let Some(n) = rule.noncurrent_version_expiration_days {
+                writer.write_event(Event::Start(BytesStart::new("NoncurrentVersionExpiration")))?;
+                write_text_element(&mut writer, "NoncurrentDays", &n.to_string())?;
+                writer.write_event(Event::End(BytesEnd::new("NoncurrentVersionExpiration")))?;
+            }
+
+            for transition in &rule.noncurrent_version_transitions {
+                writer.write_event(Event::Start(BytesStart::new("NoncurrentVersionTransition")))?;
+                write_text_element(&mut writer, "NoncurrentDays", &transition.noncurrent_days.to_string())?;
+                write_text_element(&mut writer, "StorageClass", transition.storage_class.as_str())?;
+                writer.write_event(Event::End(BytesEnd::new("NoncurrentVersionTransition")))?;
+            }
+
+            writer.write_event(Event::End(BytesEnd::new("Rule")))?;
+        }
+
+        writer.write_event(Event::End(BytesEnd::new("LifecycleConfiguration")))?;
+
+        Ok(String::from_utf8(writer.into_inner())?)
+    }
+
+    pub(crate) fn from_xml(xml: &str) -> Result<Self> {
+        Self::from_xml_ext(xml, false)
+    }
+
+    /// `strict` identifies if elements which are not modeled should be treated as error.
+    /// Used by read-modify-write operations, so that the configuration put back loses nothing
+    pub(crate) fn from_xml_ext(xml: &str, strict: bool) -> Result<Self> {
+        const KNOWN_ELEMENTS: &[&str] = &[
+            "LifecycleConfiguration",
+            "Rule",
+            "ID",
+            "Prefix",
+            "Status",
+            "Tag",
+            "Key",
+            "Value",
+            "Filter",
+            "And",
+            "ObjectSizeGreaterThan",
+            "ObjectSizeLessThan",
+            "Expiration",
+            "Days",
+            "CreatedBeforeDate",
+            "ExpiredObjectDeleteMarker",
+            "Transition",
+            "StorageClass",
+            "AbortMultipartUpload",
+            "NoncurrentVersionExpiration",
+            "NoncurrentVersionTransition",
+            "NoncurrentDays",
+        ];
+
+        let mut reader = quick_xml::Reader::from_str(xml);
+        let mut tags: Vec<String> = vec![];
+
+        let mut config = Self::default();
+        let mut rule = LifecycleRule::default();
+        let mut transition = LifecycleTransition::default();
+        let mut noncurrent_transition = LifecycleNoncurrentVersionTransition::default();
+        let mut tag_key = String::new();
+        let mut tag_value = String::new();
+
+        loop {
+            match reader.read_event()? {
+                Event::Eof => break,
+
+                Event::Start(e) => {
+                    let name = String::from_utf8_lossy(e.local_name().as_ref()).to_string();
+                    if strict && !KNOWN_ELEMENTS.contains(&name.as_str()) {
+                        return Err(Error::Other(format!("unsupported element in lifecycle configuration: {}", name)));
+                    }
+
+                    match name.as_str() {
+                        "Rule" => rule = LifecycleRule::default(),
+                        "Filter" => rule.filter = Some(LifecycleFilter::default()),
+                        "Expiration" => rule.expiration = Some(LifecycleExpiration::default()),
+                        "Transition" => transition = LifecycleTransition::default(),
+                        "NoncurrentVersionTransition" => noncurrent_transition = LifecycleNoncurrentVersionTransition::default(),
+                        _ => {}
+                    }
+                    tags.push(name);
+                }
+
+                Event::Text(e) => {
+                    let s = e.unescape()?.trim().to_string();
+                    let current = tags.last().map(|t| t.as_str()).unwrap_or_default();
+                    let parent = if tags.len() >= 2 { tags[tags.len() - 2].as_str() } else { "" };
+
+                    match (parent, current) {
+                        ("Rule", "ID") => rule.id = s,
+                        ("Rule", "Prefix") => rule.prefix = s,
+                        ("Rule", "Status") => rule.status = LifecycleRuleStatus::try_from(s)?,
+                        ("Filter" | "And", "Prefix") => {
+                            if let Some(f) = rule.filter.as_mut() {
+                                f.prefix = Some(s);
+                            }
+                        }
+                        ("Filter" | "And", "ObjectSizeGreaterThan") => {
+                            if let Some(f) = rule.filter.as_mut() {
+                                f.object_size_greater_than = Some(s.parse()?);
+                            }
+                        }
+                        ("Filter" | "And", "ObjectSizeLessThan") => {
+                            if let Some(f) = rule.filter.as_mut() {
+                                f.object_size_less_than = Some(s.parse()?);
+                            }
+                        }
+                        ("Tag", "Key") => tag_key = s,
+                        ("Tag", "Value") => tag_value = s,
+                        ("Expiration", "Days") => {
+                            if let Some(exp) = rule.expiration.as_mut() {
+                                exp.days = Some(s.parse()?);
+                            }
+                        }
+                        ("Expiration", "CreatedBeforeDate") => {
+                            if let Some(exp) = rule.expiration.as_mut() {
+                                exp.created_before_date = Some(s);
+                            }
+                        }
+                        ("Expiration", "ExpiredObjectDeleteMarker") => {
+                            if let Some(exp) = rule.expiration.as_mut() {
+                                exp.expired_object_delete_marker = Some(s == "true");
+                            }
+                        }
+                        ("Transition", "Days") => transition.days = Some(s.parse()?),
+                        ("Transition", "CreatedBeforeDate") => transition.created_before_date = Some(s),
+                        ("Transition", "StorageClass") => transition.storage_class = StorageClass::try_from(s)?,
+                        ("AbortMultipartUpload", "Days") => rule.abort_multipart_upload_days = Some(s.parse()?),
+                        ("AbortMultipartUpload", "CreatedBeforeDate") => rule.abort_multipart_upload_created_before_date = Some(s),
+                        ("NoncurrentVersionExpiration", "NoncurrentDays") => rule.noncurrent_version_expiration_days = Some(s.parse()?),
+                        ("NoncurrentVersionTransition", "NoncurrentDays") => noncurrent_transition.noncurrent_days = s.parse()?,
+                        ("NoncurrentVersionTransition", "StorageClass") => noncurrent_transition.storage_class = StorageClass::try_from(s)?,
+                        _ => {}
+                    }
+                }
+
+                Event::End(e) => {
+                    tags.pop();
+                    let parent = tags.last().map(|t| t.as_str()).unwrap_or_default();
+
+                    match e.local_name().as_ref() {
+                        b"Rule" => config.rules.push(std::mem::take(&mut rule)),
+                        b"Transition" => rule.transitions.push(std::mem::take(&mut transition)),
+                        b"NoncurrentVersionTransition" => rule.noncurrent_version_transitions.push(std::mem::take(&mut noncurrent_transition)),
+                        b"Tag" if parent == "Rule" => rule.tags.push((std::mem::take(&mut tag_key), std::mem::take(&mut tag_value))),
+                        b"Tag" if parent == "Filter" || parent == "And" => {
+                            if let Some(f) = rule.filter.as_mut() {
+                                f.tags.push((std::mem::take(&mut tag_key), std::mem::take(&mut tag_value)));
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+
+                _ => {}
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// A CORS rule of bucket
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct CorsRule {
+    /// 允许的跨域请求来源。可以包含最多一个 `*` 通配符，为 `*` 时表示允许所有来源
+    pub allowed_origins: Vec<String>,
+
+    /// 允许的跨域请求方法，取值：`GET`、`PUT`、`DELETE`、`POST`、`HEAD`
+    pub allowed_methods: Vec<String>,
+
+    /// 预检（OPTIONS）请求中 `Access-Control-Request-Headers` 允许的请求头。可以包含最多一个 `*` 通配符
+    pub allowed_headers: Vec<String>,
+
+    /// 允许用户从应用程序中访问的响应头，例如 `ETag`、`x-oss-request-id`。不允许使用通配符
+    pub expose_headers: Vec<String>,
+
+    /// 浏览器对预检（OPTIONS）请求结果的缓存时间，单位为秒
+    pub max_age_seconds: Option<u32>,
+}
+
+/// Bucket CORS configuration
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct CorsConfiguration {
+    /// 最多 10 条规则
+    pub rules: Vec<CorsRule>,
+
+    /// 是否返回 `Vary: Origin` 响应头。默认为 `false`
+    pub response_vary: Option<bool>,
+}
+
+impl CorsConfiguration {
+    pub(crate) fn to_xml(&self) -> Result<String> {
+        let mut writer = quick_xml::Writer::new(Vec::new());
+        writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+
+        writer.write_event(Event::Start(BytesStart::new("CORSConfiguration")))?;
+
+        for rule in &self.rules {
+            writer.write_event(Event::Start(BytesStart::new("CORSRule")))?;
+
+            for s in &rule.allowed_origins {
+                write_text_element(&mut writer, "AllowedOrigin", s)?;
+            }
+
+            for s in &rule.allowed_methods {
+                write_text_element(&mut writer, "AllowedMethod", s)?;
+            }
+
+            for s in &rule.allowed_headers {
+                write_text_element(&mut writer, "AllowedHeader", s)?;
+            }
+
+            for s in &rule.expose_headers {
+                write_text_element(&mut writer, "ExposeHeader", s)?;
+            }
+
+            if let Some(n) = rule.max_age_seconds {
+                write_text_element(&mut writer, "MaxAgeSeconds", &n.to_string())?;
+            }
+
+            writer.write_event(Event::End(BytesEnd::new("CORSRule")))?;
+        }
+
+        if let Some(b) = self.response_vary {
+            write_text_element(&mut writer, "ResponseVary", &b.to_string())?;
+        }
+
+        writer.write_event(Event::End(BytesEnd::new("CORSConfiguration")))?;
+
+        Ok(String::from_utf8(writer.into_inner())?)
+    }
+
+    pub(crate) fn from_xml(xml: &str) -> Result<Self> {
+        let mut reader = quick_xml::Reader::from_str(xml);
+        let mut tags: Vec<String> = vec![];
+
+        let mut config = Self::default();
+        let mut rule = CorsRule::default();
+
+        loop {
+            match reader.read_event()? {
+                Event::Eof => break,
+
+                Event::Start(e) => {
+                    let name = String::from_utf8_lossy(e.local_name().as_ref()).to_string();
+                    if name == "CORSRule" {
+                        rule = CorsRule::default();
+                    }
+                    tags.push(name);
+                }
+
+                Event::Text(e) => {
+                    let s = e.unescape()?.trim().to_string();
+                    let current = tags.last().map(|t| t.as_str()).unwrap_or_default();
+                    let parent = if tags.len() >= 2 { tags[tags.len() - 2].as_str() } else { "" };
+
+                    match (parent, current) {
+                        ("CORSRule", "AllowedOrigin") => rule.allowed_origins.push(s),
+                        ("CORSRule", "AllowedMethod") => rule.allowed_methods.push(s),
+                        ("CORSRule", "AllowedHeader") => rule.allowed_headers.push(s),
+                        ("CORSRule", "ExposeHeader") => rule.expose_headers.push(s),
+                        ("CORSRule", "MaxAgeSeconds") => rule.max_age_seconds = Some(s.parse()?),
+                        ("CORSConfiguration", "ResponseVary") => config.response_vary = Some(s == "true"),
+                        _ => {}
+                    }
+                }
+
+                Event::End(e) => {
+                    tags.pop();
+
+                    if e.local_name().as_ref() == b"CORSRule" {
+                        config.rules.push(std::mem::take(&mut rule));
+                    }
+                }
+
+                _ => {}
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// Index document of static website
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct IndexDocument {
+    /// 默认主页的文件名，例如 `index.html`
+    pub suffix: String,
+
+    /// 访问子目录时是否跳转到子目录下的默认主页
+    pub support_sub_dir: Option<bool>,
+
+    /// 开启子目录默认主页后，默认主页不存在时的行为：
+    ///
+    /// - `0`: 检查 `{dir}.html` 是否存在，存在则返回 302，否则返回 404 或者错误文档
+    /// - `1`: 直接返回 404 或者错误文档
+    /// - `2`: 检查 `{dir}/index.html`，不存在时返回 404 或者错误文档
+    pub index_type: Option<u32>,
+}
+
+/// Error document of static website
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct ErrorDocument {
+    /// 默认 404 页的文件名，例如 `error.html`
+    pub key: String,
+
+    /// 返回错误文档时的 HTTP 状态码，取值：`200`、`404`。默认为 `404`
+    pub http_status: Option<u16>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum RedirectType {
+    /// 镜像回源
+    #[cfg_attr(feature = "serde-support", serde(rename = "Mirror"))]
+    Mirror,
+
+    /// 外部跳转，即 OSS 返回 3xx 请求，指定跳转到另外一个地址
+    #[default]
+    #[cfg_attr(feature = "serde-support", serde(rename = "External"))]
+    External,
+
+    /// 内部跳转，OSS 根据规则将对 `object1` 的访问转换为对 `object2` 的访问
+    #[cfg_attr(feature = "serde-support", serde(rename = "Internal"))]
+    Internal,
+
+    /// 阿里云 CDN 跳转，主要用于阿里云 CDN
+    #[cfg_attr(feature = "serde-support", serde(rename = "AliCDN"))]
+    AliCDN,
+}
+
+impl RedirectType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            RedirectType::Mirror => "Mirror",
+            RedirectType::External => "External",
+            RedirectType::Internal => "Internal",
+            RedirectType::AliCDN => "AliCDN",
+        }
+    }
+}
+
+impl TryFrom<&str> for RedirectType {
+    type Error = Error;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        match value {
+            "Mirror" => Ok(RedirectType::Mirror),
+            "External" => Ok(RedirectType::External),
+            "Internal" => Ok(RedirectType::Internal),
+            "AliCDN" => Ok(RedirectType::AliCDN),
+            _ => Err(Error::Other(format!("Invalid RedirectType value: {}", value))),
+        }
+    }
+}
+
+/// Matching condition of a routing rule. All conditions set must be matched
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct RoutingRuleCondition {
+    /// 只有匹配此前缀的 Object 才能匹配此规则
+    pub key_prefix_equals: Option<String>,
+
+    /// 只有匹配此后缀的 Object 才能匹配此规则
+    pub key_suffix_equals: Option<String>,
+
+    /// 访问指定 Object 时返回此状态码才能匹配此规则，例如镜像回源时为 `404`
+    pub http_error_code_returned_equals: Option<u16>,
+}
+
+/// What to do when a routing rule is matched
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct RoutingRuleRedirect {
+    pub redirect_type: RedirectType,
+
+    /// 跳转时的协议，取值：`http`、`https`。只能在 `External` 和 `AliCDN` 跳转时设置
+    pub protocol: Option<String>,
+
+    /// 跳转时的域名。只能在 `External` 和 `AliCDN` 跳转时设置
+    pub host_name: Option<String>,
+
+    /// 跳转时 Object 名称的前缀将替换成该值。如果前缀为空，则将这个字符串插入 Object 名称的前面
+    pub replace_key_prefix_with: Option<String>,
+
+    /// 跳转时 Object 名称将替换成该值，支持 `${key}` 变量。不能和 `replace_key_prefix_with` 同时设置
+    pub replace_key_with: Option<String>,
+
+    /// 跳转时的状态码，取值：`301`、`302`、`307`。只能在 `External` 和 `AliCDN` 跳转时设置
+    pub http_redirect_code: Option<u16>,
+
+    /// 跳转或者镜像回源时是否携带请求参数
+    pub pass_query_string: Option<bool>,
+
+    /// 镜像回源的源站地址，例如 `https://www.example.com/`。`Mirror` 跳转时必须设置
+    pub mirror_url: Option<String>,
+}
+
+/// A routing rule of static website, e.g. redirect requests with prefix `docs/` to another host
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct RoutingRule {
+    /// 匹配和执行规则的顺序，从 `1` 开始，按照编号依次匹配规则，匹配后执行该规则，不再匹配后续规则
+    pub rule_number: u32,
+    pub condition: RoutingRuleCondition,
+    pub redirect: RoutingRuleRedirect,
+}
+
+/// Bucket static website configuration
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct WebsiteConfiguration {
+    pub index_document: Option<IndexDocument>,
+    pub error_document: Option<ErrorDocument>,
+
+    /// 最多 20 条规则
+    pub routing_rules: Vec<RoutingRule>,
+}
+
+impl WebsiteConfiguration {
+    pub(crate) fn to_xml(&self) -> Result<String> {
+        let mut writer = quick_xml::Writer::new(Vec::new());
+        writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+
+        writer.write_event(Event::Start(BytesStart::new("WebsiteConfiguration")))?;
+
+        if let Some(doc) = &self.index_document {
+            writer.write_event(Event::Start(BytesStart::new("IndexDocument")))?;
+            write_text_element(&mut writer, "Suffix", &doc.suffix)?;
+            if let Some(b) = doc.support_sub_dir {
+                write_text_element(&mut writer, "SupportSubDir", &b.to_string())?;
+            }
+            if let Some(n) = doc.index_type {
+                write_text_element(&mut writer, "Type", &n.to_string())?;
+            }
+            writer.write_event(Event::End(BytesEnd::new("IndexDocument")))?;
+        }
+
+        if let Some(doc) = &self.error_document {
+            writer.write_event(Event::Start(BytesStart::new("ErrorDocument")))?;
+            write_text_element(&mut writer, "Key", &doc.key)?;
+            if let Some(n) = doc.http_status {
+                write_text_element(&mut writer, "HttpStatus", &n.to_string())?;
+            }
+            writer.write_event(Event::End(BytesEnd::new("ErrorDocument")))?;
+        }
+
+        if !self.routing_rules.is_empty() {
+            writer.write_event(Event::Start(BytesStart::new("RoutingRules")))?;
+
+            for rule in &self.routing_rules {
+                writer.write_event(Event::Start(BytesStart::new("RoutingRule")))?;
+                write_text_element(&mut writer, "RuleNumber", &rule.rule_number.to_string())?;
+
+                let cond = &rule.condition;
+                writer.write_event(Event::Start(BytesStart::new("Condition")))?;
+                if let Some(s) = &cond.key_prefix_equals {
+                    write_text_element(&mut writer, "KeyPrefixEquals", s)?;
+                }
+                if let Some(s) = &cond.key_suffix_equals {
+                    write_text_element(&mut writer, "KeySuffixEquals", s)?;
+                }
+                if let Some(n) = cond.http_error_code_returned_equals {
+                    write_text_element(&mut writer, "HttpErrorCodeReturnedEquals", &n.to_string())?;
+                }
+                writer.write_event(Event::End(BytesEnd::new("Condition")))?;
+
+                let redirect = &rule.redirect;
+                writer.write_event(Event::Start(BytesStart::new("Redirect")))?;
+                write_text_element(&mut writer, "RedirectType", redirect.redirect_type.as_str())?;
+                if let Some(b) = redirect.pass_query_string {
+                    write_text_element(&mut writer, "PassQueryString", &b.to_string())?;
+                }
+                if let Some(s) = &redirect.mirror_url {
+                    write_text_element(&mut writer, "MirrorURL", s)?;
+                }
+                if let Some(s) = &redirect.protocol {
+                    write_text_element(&mut writer, "Protocol", s)?;
+                }
+                if let Some(s) = &redirect.host_name {
+                    write_text_element(&mut writer, "HostName", s)?;
+                }
+                if let Some(s) = &redirect.replace_key_prefix_with {
+                    write_text_element(&mut writer, "ReplaceKeyPrefixWith", s)?;
+                }
+                if let Some(s) = &redirect.replace_key_with {
+                    write_text_element(&mut writer, "ReplaceKeyWith", s)?;
+                }
+                if let Some(n) = redirect.http_redirect_code {
+                    write_text_element(&mut writer, "HttpRedirectCode", &n.to_string())?;
+                }
+                writer.write_event(Event::End(BytesEnd::new("Redirect")))?;
+
+                writer.write_event(Event::End(BytesEnd::new("RoutingRule")))?;
+            }
+
+            writer.write_event(Event::End(BytesEnd::new("RoutingRules")))?;
+        }
+
+        writer.write_event(Event::End(BytesEnd::new("WebsiteConfiguration")))?;
+
+        Ok(String::from_utf8(writer.into_inner())?)
+    }
+
+    pub(crate) fn from_xml(xml: &str) -> Result<Self> {
+        let mut reader = quick_xml::Reader::from_str(xml);
+        let mut tags: Vec<String> = vec![];
+
+        let mut config = Self::default();
+        let mut rule = RoutingRule::default();
+
+        loop {
+            match reader.read_event()? {
+                Event::Eof => break,
+
+                Event::Start(e) => {
+                    let name = String::from_utf8_lossy(e.local_name().as_ref()).to_string();
+                    match name.as_str() {
+                        "IndexDocument" => config.index_document = Some(IndexDocument::default()),
+                        "ErrorDocument" => config.error_document = Some(ErrorDocument::default()),
+                        "RoutingRule" => rule = RoutingRule::default(),
+                        _ => {}
+                    }
+                    tags.push(name);
+                }
+
+                Event::Text(e) => {
+                    let s = e.unescape()?.trim().to_string();
+                    let current = tags.last().map(|t| t.as_str()).unwrap_or_default();
+                    let parent = if tags.len() >= 2 { tags[tags.len() - 2].as_str() } else { "" };
+
+                    match (parent, current) {
+                        ("IndexDocument", "Suffix") => config.index_document.get_or_insert_with(Default::default).suffix = s,
+                        ("IndexDocument", "SupportSubDir") => config.index_document.get_or_insert_with(Default::default).support_sub_dir = Some(s == "true"),
+                        ("IndexDocument", "Type") => config.index_document.get_or_insert_with(Default::default).index_type = Some(s.parse()?),
+                        ("ErrorDocument", "Key") => config.error_document.get_or_insert_with(Default::default).key = s,
+                        ("ErrorDocument", "HttpStatus") => config.error_document.get_or_insert_with(Default::default).http_status = Some(s.parse()?),
+                        ("RoutingRule", "RuleNumber") => rule.rule_number = s.parse()?,
+                        ("Condition", "KeyPrefixEquals") => rule.condition.key_prefix_equals = Some(s),
+                        ("Condition", "KeySuffixEquals") => rule.condition.key_suffix_equals = Some(s),
+                        ("Condition", "HttpErrorCodeReturnedEquals") => rule.condition.http_error_code_returned_equals = Some(s.parse()?),
+                        ("Redirect", "RedirectType") => rule.redirect.redirect_type = RedirectType::try_from(s.as_str())?,
+                        ("Redirect", "PassQueryString") => rule.redirect.pass_query_string = Some(s == "true"),
+                        ("Redirect", "MirrorURL") => rule.redirect.mirror_url = Some(s),
+                        ("Redirect", "Protocol") => rule.redirect.protocol = Some(s),
+                        ("Redirect", "HostName") => rule.redirect.host_name = Some(s),
+                        ("Redirect", "ReplaceKeyPrefixWith") => rule.redirect.replace_key_prefix_with = Some(s),
+                        ("Redirect", "ReplaceKeyWith") => rule.redirect.replace_key_with = Some(s),
+                        ("Redirect", "HttpRedirectCode") => rule.redirect.http_redirect_code = Some(s.parse()?),
+                        _ => {}
+                    }
+                }
+
+                Event::End(e) => {
+                    tags.pop();
+
+                    if e.local_name().as_ref() == b"RoutingRule" {
+                        config.routing_rules.push(std::mem::take(&mut rule));
+                    }
+                }
+
+                _ => {}
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// Bucket referer (anti-leech) configuration
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct RefererConfiguration {
+    /// 是否允许 `Referer` 为空的请求访问
+    pub allow_empty_referer: bool,
+
+    /// 匹配 `Referer` 时是否截断 URL 中的查询参数。默认为 `true`
+    pub allow_truncate_query_string: Option<bool>,
+
+    /// 白名单，支持 `*` 和 `?` 通配符，例如 `http://www.aliyun.com`、`https://*.example.com`。
+    /// 为空时不限制 `Referer`
+    pub referer_list: Vec<String>,
+}
+
+impl RefererConfiguration {
+    pub(crate) fn to_xml(&self) -> Result<String> {
+        let mut writer = quick_xml::Writer::new(Vec::new());
+        writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+
+        writer.write_event(Event::Start(BytesStart::new("RefererConfiguration")))?;
+
+        write_text_element(&mut writer, "AllowEmptyReferer", &self.allow_empty_referer.to_string())?;
+
+        if let Some(b) = self.allow_truncate_query_string {
+            write_text_element(&mut writer, "AllowTruncateQueryString", &b.to_string())?;
+        }
+
+        // 白名单为空时也需要 `RefererList` 节点，用于清空白名单
+        writer.write_event(Event::Start(BytesStart::new("RefererList")))?;
+        for s in &self.referer_list {
+            write_text_element(&mut writer, "Referer", s)?;
+        }
+        writer.write_event(Event::End(BytesEnd::new("RefererList")))?;
+
+        writer.write_event(Event::End(BytesEnd::new("RefererConfiguration")))?;
+
+        Ok(String::from_utf8(writer.into_inner())?)
+    }
+
+    pub(crate) fn from_xml(xml: &str) -> Result<Self> {
+        let mut reader = quick_xml::Reader::from_str(xml);
+        let mut tags: Vec<String> = vec![];
+
+        let mut config = Self::default();
+
+        loop {
+            match reader.read_event()? {
+                Event::Eof => break,
+
+                Event::Start(e) => {
+                    tags.push(String::from_utf8_lossy(e.local_name().as_ref()).to_string());
+                }
+
+                Event::Text(e) => {
+                    let s = e.unescape()?.trim().to_string();
+                    let current = tags.last().map(|t| t.as_str()).unwrap_or_default();
+                    let parent = if tags.len() >= 2 { tags[tags.len() - 2].as_str() } else { "" };
+
+                    match (parent, current) {
+                        ("RefererConfiguration", "AllowEmptyReferer") => config.allow_empty_referer = s == "true",
+                        ("RefererConfiguration", "AllowTruncateQueryString") => config.allow_truncate_query_string = Some(s == "true"),
+                        ("RefererList", "Referer") if !s.is_empty() => config.referer_list.push(s),
+                        _ => {}
+                    }
+                }
+
+                Event::End(_) => {
+                    tags.pop();
+                }
+
+                _ => {}
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+impl ServerSideEncryptionRule {
+    pub(crate) fn to_xml(&self) -> Result<String> {
+        let mut writer = quick_xml::Writer::new(Vec::new());
+        writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+
+        writer.write_event(Event::Start(BytesStart::new("ServerSideEncryptionRule")))?;
+        writer.write_event(Event::Start(BytesStart::new("ApplyServerSideEncryptionByDefault")))?;
+
+        write_text_element(&mut writer, "SSEAlgorithm", self.sse_algorithm.as_str())?;
+
+        if let Some(s) = &self.kms_master_key_id {
+            write_text_element(&mut writer, "KMSMasterKeyID", s)?;
+        }
+
+        if let Some(s) = &self.kms_data_encryption {
+            write_text_element(&mut writer, "KMSDataEncryption", s)?;
+        }
+
+        writer.write_event(Event::End(BytesEnd::new("ApplyServerSideEncryptionByDefault")))?;
+        writer.write_event(Event::End(BytesEnd::new("ServerSideEncryptionRule")))?;
+
+        Ok(String::from_utf8(writer.into_inner())?)
+    }
+
+    pub(crate) fn from_xml(xml: &str) -> Result<Self> {
+        let mut reader = quick_xml::Reader::from_str(xml);
+        let mut current_tag = String::new();
+
+        let mut rule = Self::default();
+
+        loop {
+            match reader.read_event()? {
+                Event::Eof => break,
+
+                Event::Start(e) => {
+                    current_tag = String::from_utf8_lossy(e.local_name().as_ref()).to_string();
+                }
+
+                Event::Text(e) => {
+                    let s = e.unescape()?.trim().to_string();
+
+                    match current_tag.as_str() {
+                        "SSEAlgorithm" => rule.sse_algorithm = ServerSideEncryptionAlgorithm::try_from(s)?,
+                        "KMSMasterKeyID" if !s.is_empty() => rule.kms_master_key_id = Some(s),
+                        "KMSDataEncryption" if !s.is_empty() => rule.kms_data_encryption = Some(s),
+                        _ => {}
+                    }
+                }
+
+                Event::End(_) => current_tag.clear(),
+
+                _ => {}
+            }
+        }
+
+        Ok(rule)
+    }
+}
+
+pub(crate) fn write_text_element<W: std::io::Write>(writer: &mut quick_xml::Writer<W>, tag: &str, text: &str) -> Result<()> {
+    writer.write_event(Event::Start(BytesStart::new(tag)))?;
+    writer.write_event(Event::Text(BytesText::new(text)))?;
+    writer.write_event(Event::End(BytesEnd::new(tag)))?;
+    Ok(())
+}
+
+pub(crate) fn build_put_bucket_request(bucket_name: &str, config: &PutBucketConfiguration, options: &Option<PutBucketOptions>) -> Result<OssRequest> {
+    let xml = config.to_xml()?;
+
+    let mut request = crate::request::OssRequest::new()
+        .method(RequestMethod::Put)
+        .bucket(bucket_name)
+        .content_type(common::MIME_TYPE_XML)
+        .text_body(xml);
+
+    if let Some(options) = options {
+        if let Some(acl) = &options.acl {
+            request = request.add_header("x-oss-acl", acl.as_str());
+        }
+
+        if let Some(resource_group_id) = &options.resource_group_id {
+            request = request.add_header("x-oss-resource-group-id", resource_group_id);
+        }
+
+        if !options.tags.is_empty() {
+            if !options.tags.iter().all(|(k, v)| validate_tag_key(k) && validate_tag_value(v)) {
+                return Err(Error::Other(format!("invalid tag name or tag key: {:#?}", options.tags)));
+            }
+
+            let tags_string = options
+                .tags
+                .iter()
+                .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(v)))
+                .collect::<Vec<String>>()
+                .join("&");
+
+            request = request.add_header("x-oss-bucket-tagging", &tags_string);
+        }
+    }
+
+    Ok(request)
+}
+
+pub(crate) fn build_list_buckets_request(options: &Option<ListBucketsOptions>) -> OssRequest {
+    let mut request = OssRequest::new();
+
+    if let Some(opt) = options {
+        if let Some(prefix) = &opt.prefix {
+            request = request.add_query("prefix", prefix);
+        }
+        if let Some(marker) = &opt.marker {
+            request = request.add_query("marker", marker);
+        }
+        if let Some(max_keys) = opt.max_keys {
+            request = request.add_query("max-keys", max_keys.to_string());
+        }
+        if let Some(oss_resource_group_id) = &opt.resource_group_id {
+            request = request.add_header("x-oss-resource-group-id", oss_resource_group_id)
+        }
+    }
+
+    request
+}
+
+pub(crate) fn build_list_objects_request(bucket_name: &str, options: &Option<ListObjectsOptions>) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+    }
+
+    let mut request = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("list-type", "2");
+
+    if let Some(options) = options {
+        if let Some(c) = options.delimiter {
+            request = request.add_query("delimiter", c.to_string());
+        }
+
+        if let Some(s) = &options.prefix {
+            request = request.add_query("prefix", s);
+        }
+
+        if let Some(u) = options.max_keys {
+            if u == 0 || u > 1000 {
+                return Err(Error::Other(format!("invalid max-keys: {}. must between 1 and 1000", u)));
+            }
+            request = request.add_query("max-keys", u.to_string());
+        }
+
+        if let Some(s) = &options.start_after {
+            if s.len() >= 1024 {
+                return Err(Error::Other(format!("invalid start-after: length {} must be less than 1024 bytes", s.len())));
+            }
+            request = request.add_query("start-after", s);
+        }
+
+        if let Some(s) = &options.continuation_token {
+            request = request.add_query("continuation-token", s);
+        }
+
+        if let Some(b) = &options.fetch_owner {
+            request = request.add_query("fetch-owner", b.to_string());
+        }
+    }
+
+    Ok(request)
+}
+
+pub(crate) fn build_list_object_versions_request(bucket_name: &str, options: &Option<ListObjectVersionsOptions>) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+    }
+
+    let mut request = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("versions", "");
+
+    if let Some(options) = options {
+        if let Some(c) = options.delimiter {
+            request = request.add_query("delimiter", c.to_string());
+        }
+
+        if let Some(s) = &options.prefix {
+            request = request.add_query("prefix", s);
+        }
+
+        if let Some(u) = options.max_keys {
+            if u == 0 || u > 1000 {
+                return Err(Error::Other(format!("invalid max-keys: {}. must between 1 and 1000", u)));
+            }
+            request = request.add_query("max-keys", u.to_string());
+        }
+
+        if let Some(s) = &options.key_marker {
+            request = request.add_query("key-marker", s);
+        }
+
+        if let Some(s) = &options.version_id_marker {
+            if options.key_marker.is_none() {
+                return Err(Error::Other("version-id-marker must be used with key-marker".to_string()));
+            }
+            request = request.add_query("version-id-marker", s);
+        }
+    }
+
+    Ok(request)
+}
+
+pub(crate) fn build_get_bucket_lifecycle_request(bucket_name: &str) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+    }
+
+    Ok(OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("lifecycle", ""))
+}
+
+pub(crate) fn build_put_bucket_lifecycle_request(bucket_name: &str, config: &LifecycleConfiguration) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+    }
+
+    if config.rules.is_empty() {
+        return Err(Error::Other("lifecycle rules cannot be empty".to_string()));
+    }
+
+    for rule in &config.rules {
+        let filter_tags = rule.filter.iter().flat_map(|f| f.tags.iter());
+        for (k, v) in rule.tags.iter().chain(filter_tags) {
+            if !validate_tag_key(k) || !validate_tag_value(v) {
+                return Err(Error::Other(format!("invalid tag key or tag value in lifecycle rule: {}={}", k, v)));
+            }
+        }
+    }
+
+    let xml = config.to_xml()?;
+    let md5 = content_md5_base64(xml.as_bytes());
+
+    let request = OssRequest::new()
+        .method(RequestMethod::Put)
+        .bucket(bucket_name)
+        .add_query("lifecycle", "")
+        .add_header("content-md5", &md5)
+        .content_type(common::MIME_TYPE_XML)
+        .text_body(xml);
+
+    Ok(request)
+}
+
+pub(crate) fn build_put_bucket_cors_request(bucket_name: &str, config: &CorsConfiguration) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+    }
+
+    if config.rules.is_empty() || config.rules.len() > 10 {
+        return Err(Error::Other(format!(
+            "invalid cors rules count: {}. must be between 1 and 10",
+            config.rules.len()
+        )));
+    }
+
+    for rule in &config.rules {
+        if rule.allowed_origins.is_empty() || rule.allowed_methods.is_empty() {
+            return Err(Error::Other("allowed origins and allowed methods of cors rule cannot be empty".to_string()));
+        }
+
+        if let Some(m) = rule
+            .allowed_methods
+            .iter()
+            .find(|m| !["GET", "PUT", "DELETE", "POST", "HEAD"].contains(&m.as_str()))
+        {
+            return Err(Error::Other(format!("invalid allowed method of cors rule: {}", m)));
+        }
+    }
+
+    let xml = config.to_xml()?;
+    let md5 = content_md5_base64(xml.as_bytes());
+
+    let request = OssRequest::new()
+        .method(RequestMethod::Put)
+        .bucket(bucket_name)
+        .add_query("cors", "")
+        .add_header("content-md5", &md5)
+        .content_type(common::MIME_TYPE_XML)
+        .text_body(xml);
+
+    Ok(request)
+}
+
+pub(crate) fn build_put_bucket_website_request(bucket_name: &str, config: &WebsiteConfiguration) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+    }
+
+    if config.index_document.is_none() && config.error_document.is_none() && config.routing_rules.is_empty() {
+        return Err(Error::Other(
+            "website configuration must contain at least one of index document, error document and routing rules".to_string(),
+        ));
+    }
+
+    if config.index_document.as_ref().is_some_and(|d| d.suffix.is_empty()) {
+        return Err(Error::Other("suffix of index document cannot be empty".to_string()));
+    }
+
+    if config.error_document.as_ref().is_some_and(|d| d.key.is_empty()) {
+        return Err(Error::Other("key of error document cannot be empty".to_string()));
+    }
+
+    if config.routing_rules.len() > 20 {
+        return Err(Error::Other(format!(
+            "invalid routing rules count: {}. must not be greater than 20",
+            config.routing_rules.len()
+        )));
+    }
+
+    for rule in &config.routing_rules {
+        if rule.rule_number == 0 {
+            return Err(Error::Other("rule number of routing rule must be greater than 0".to_string()));
+        }
+
+        if rule.redirect.replace_key_with.is_some() && rule.redirect.replace_key_prefix_with.is_some() {
+            return Err(Error::Other(format!(
+                "replace key with and replace key prefix with cannot be both set in routing rule {}",
+                rule.rule_number
+            )));
+        }
+
+        if rule.redirect.redirect_type == RedirectType::Mirror && rule.redirect.mirror_url.is_none() {
+            return Err(Error::Other(format!(
+                "mirror url is required by mirror redirect in routing rule {}",
+                rule.rule_number
+            )));
+        }
+    }
+
+    let xml = config.to_xml()?;
+    let md5 = content_md5_base64(xml.as_bytes());
+
+    let request = OssRequest::new()
+        .method(RequestMethod::Put)
+        .bucket(bucket_name)
+        .add_query("website", "")
+        .add_header("content-md5", &md5)
+        .content_type(common::MIME_TYPE_XML)
+        .text_body(xml);
+
+    Ok(request)
+}
+
+pub(crate) fn bucket_tags_to_xml(tags: &[(String, String)]) -> Result<String> {
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+
+    writer.write_event(Event::Start(BytesStart::new("Tagging")))?;
+    writer.write_event(Event::Start(BytesStart::new("TagSet")))?;
+    for (k, v) in tags {
+        writer.write_event(Event::Start(BytesStart::new("Tag")))?;
+        write_text_element(&mut writer, "Key", k)?;
+        write_text_element(&mut writer, "Value", v)?;
+        writer.write_event(Event::End(BytesEnd::new("Tag")))?;
+    }
+    writer.write_event(Event::End(BytesEnd::new("TagSet")))?;
+    writer.write_event(Event::End(BytesEnd::new("Tagging")))?;
+
+    Ok(String::from_utf8(writer.into_inner())?)
+}
+
+/// 按照响应中的顺序返回标签
+pub(crate) fn parse_bucket_tags_from_xml(xml: &str) -> Result<Vec<(String, String)>> {
+    let mut reader = quick_xml::Reader::from_str(xml);
+    let mut tags: Vec<String> = vec![];
+    let mut key = String::new();
+    let mut value = String::new();
+
+    let mut list = vec![];
+
+    loop {
+        match reader.read_event()? {
+            Event::Eof => break,
+
+            Event::Start(e) => {
+                tags.push(String::from_utf8_lossy(e.local_name().as_ref()).to_string());
+            }
+
+            Event::Text(e) => {
+                let s = e.unescape()?.trim().to_string();
+                let current = tags.last().map(|t| t.as_str()).unwrap_or_default();
+                let parent = if tags.len() >= 2 { tags[tags.len() - 2].as_str() } else { "" };
+
+                match (parent, current) {
+                    ("Tag", "Key") => key = s,
+                    ("Tag", "Value") => value = s,
+                    _ => {}
+                }
+            }
+
+            Event::End(e) => {
+                if e.local_name().as_ref() == b"Tag" {
+                    list.push((std::mem::take(&mut key), std::mem::take(&mut value)));
+                }
+                tags.pop();
+            }
+
+            _ => {}
+        }
+    }
+
+    Ok(list)
+}
+
+pub(crate) fn build_put_bucket_tagging_request(bucket_name: &str, tags: &[(String, String)]) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+    }
+
+    if tags.is_empty() {
+        return Err(Error::Other("tags cannot be empty".to_string()));
+    }
+
+    if tags.len() > 20 {
+        return Err(Error::Other(format!("at most 20 tags are allowed for a bucket, got {}", tags.len())));
+    }
+
+    for (i, (k, v)) in tags.iter().enumerate() {
+        if !validate_tag_key(k) {
+            return Err(Error::Other(format!("invalid tag key: {}", k)));
+        }
+
+        if !validate_bucket_tag_value(v) {
+            return Err(Error::Other(format!("invalid tag value: {}", v)));
+        }
+
+        if tags[..i].iter().any(|(prev, _)| prev == k) {
+            return Err(Error::Other(format!("duplicated tag key: {}", k)));
+        }
+    }
+
+    let xml = bucket_tags_to_xml(tags)?;
+    let md5 = content_md5_base64(xml.as_bytes());
+
+    let request = OssRequest::new()
+        .method(RequestMethod::Put)
+        .bucket(bucket_name)
+        .add_query("tagging", "")
+        .add_header("content-md5", &md5)
+        .content_type(common::MIME_TYPE_XML)
+        .text_body(xml);
+
+    Ok(request)
+}
+
+pub(crate) fn build_put_bucket_referer_request(bucket_name: &str, config: &RefererConfiguration) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+    }
+
+    if config.referer_list.iter().any(|s| s.trim().is_empty()) {
+        return Err(Error::Other("referer in referer list cannot be empty".to_string()));
+    }
+
+    let xml = config.to_xml()?;
+    let md5 = content_md5_base64(xml.as_bytes());
+
+    let request = OssRequest::new()
+        .method(RequestMethod::Put)
+        .bucket(bucket_name)
+        .add_query("referer", "")
+        .add_header("content-md5", &md5)
+        .content_type(common::MIME_TYPE_XML)
+        .text_body(xml);
+
+    Ok(request)
+}
+
+/// 策略内容是任意的 JSON，这里只校验其是否为合法的 JSON，其余交给服务端校验
+pub(crate) fn build_put_bucket_policy_request(bucket_name: &str, policy: &str) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+    }
+
+    if policy.trim().is_empty() {
+        return Err(Error::Other("bucket policy cannot be empty".to_string()));
+    }
+
+    if let Err(e) = serde_json::from_str::<serde_json::Value>(policy) {
+        return Err(Error::Other(format!("bucket policy is not a valid json: {}", e)));
+    }
+
+    let request = OssRequest::new()
+        .method(RequestMethod::Put)
+        .bucket(bucket_name)
+        .add_query("policy", "")
+        .content_type(common::MIME_TYPE_JSON)
+        .text_body(policy);
+
+    Ok(request)
+}
+
+pub(crate) fn build_put_bucket_encryption_request(bucket_name: &str, rule: &ServerSideEncryptionRule) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+    }
+
+    if rule.sse_algorithm != ServerSideEncryptionAlgorithm::KMS && (rule.kms_master_key_id.is_some() || rule.kms_data_encryption.is_some()) {
+        return Err(Error::Other(format!(
+            "kms master key id and kms data encryption are only allowed with KMS, but the algorithm is {}",
+            rule.sse_algorithm
+        )));
+    }
+
+    if let Some(s) = &rule.kms_data_encryption {
+        if s != "SM4" {
+            return Err(Error::Other(format!("invalid kms data encryption: {}. only SM4 is supported", s)));
+        }
+    }
+
+    let xml = rule.to_xml()?;
+    let md5 = content_md5_base64(xml.as_bytes());
+
+    let request = OssRequest::new()
+        .method(RequestMethod::Put)
+        .bucket(bucket_name)
+        .add_query("encryption", "")
+        .add_header("content-md5", &md5)
+        .content_type(common::MIME_TYPE_XML)
+        .text_body(xml);
+
+    Ok(request)
+}
+
+#[cfg(test)]
+mod test_bucket_common {
+    use crate::{
+        common::{ServerSideEncryptionAlgorithm, ServerSideEncryptionRule, StorageClass},
+        error::Error,
+        request::{RequestBody, RequestMethod},
+        util::content_md5_base64,
+    };
+
+    use super::{
+        bucket_tags_to_xml, build_list_object_versions_request, build_list_objects_request, build_put_bucket_cors_request, build_put_bucket_encryption_request,
+        build_put_bucket_lifecycle_request, build_put_bucket_policy_request, build_put_bucket_referer_request, build_put_bucket_tagging_request,
+        build_put_bucket_website_request, parse_bucket_tags_from_xml, BucketAcl, BucketDetail, CorsConfiguration, CorsRule, IndexDocument,
+        LifecycleConfiguration, LifecycleExpiration, LifecycleFilter, LifecycleNoncurrentVersionTransition, LifecycleRule, LifecycleRuleStatus,
+        LifecycleTransition, ListObjectVersionsOptionsBuilder, ListObjectVersionsResult, ListObjectsOptionsBuilder, RedirectType, RefererConfiguration,
+        RoutingRule, RoutingRuleCondition, RoutingRuleRedirect, WebsiteConfiguration,
+    };
+
+    #[test]
+    fn test_lifecycle_and_filter_round_trip() {
+        let config = LifecycleConfiguration {
+            rules: vec![LifecycleRule {
+                id: "expire-temp".to_string(),
+                prefix: "".to_string(),
+                status: LifecycleRuleStatus::Enabled,
+                filter: Some(LifecycleFilter {
+                    prefix: Some("logs/".to_string()),
+                    tags: vec![("temp".to_string(), "true".to_string()), ("owner".to_string(), "ci".to_string())],
+                    object_size_greater_than: Some(1024),
+                    object_size_less_than: Some(1024 * 1024),
+                }),
+                expiration: Some(LifecycleExpiration {
+                    days: Some(7),
+                    ..Default::default()
+                }),
+                transitions: vec![LifecycleTransition {
+                    days: Some(3),
+                    created_before_date: None,
+                    storage_class: StorageClass::IA,
+                }],
+                abort_multipart_upload_days: Some(1),
+                ..Default::default()
+            }],
+        };
+
+        let xml = config.to_xml().unwrap();
+        assert!(xml.contains("<Filter><And><Prefix>logs/</Prefix><Tag><Key>temp</Key><Value>true</Value></Tag>"));
+
+        let parsed = LifecycleConfiguration::from_xml(&xml).unwrap();
+        assert_eq!(config, parsed);
+    }
+
+    #[test]
+    fn test_lifecycle_versioning_elements_round_trip() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <LifecycleConfiguration>
+          <Rule>
+            <ID>tagged</ID>
+            <Prefix>logs/</Prefix>
+            <Status>Enabled</Status>
+            <Tag><Key>temp</Key><Value>true</Value></Tag>
+            <Expiration><Days>7</Days></Expiration>
+            <AbortMultipartUpload><CreatedBeforeDate>2002-10-11T00:00:00.000Z</CreatedBeforeDate></AbortMultipartUpload>
+          </Rule>
+          <Rule>
+            <ID>versions</ID>
+            <Prefix></Prefix>
+            <Status>Enabled</Status>
+            <Expiration><ExpiredObjectDeleteMarker>true</ExpiredObjectDeleteMarker></Expiration>
+            <NoncurrentVersionExpiration><NoncurrentDays>30</NoncurrentDays></NoncurrentVersionExpiration>
+            <NoncurrentVersionTransition><NoncurrentDays>10</NoncurrentDays><StorageClass>IA</StorageClass></NoncurrentVersionTransition>
+            <NoncurrentVersionTransition><NoncurrentDays>20</NoncurrentDays><StorageClass>Archive</StorageClass></NoncurrentVersionTransition>
+          </Rule>
+        </LifecycleConfiguration>"#;
+
+        let config = LifecycleConfiguration::from_xml_ext(xml, true).unwrap();
+        assert_eq!(
+            config.rules,
+            vec![
+                LifecycleRule {
+                    id: "tagged".to_string(),
+                    prefix: "logs/".to_string(),
+                    status: LifecycleRuleStatus::Enabled,
+                    tags: vec![("temp".to_string(), "true".to_string())],
+                    expiration: Some(LifecycleExpiration {
+                        days: Some(7),
+                        ..Default::default()
+                    }),
+                    abort_multipart_upload_created_before_date: Some("2002-10-11T00:00:00.000Z".to_string()),
+                    ..Default::default()
+                },
+                LifecycleRule {
+                    id: "versions".to_string(),
+                    status: LifecycleRuleStatus::Enabled,
+                    expiration: Some(LifecycleExpiration {
+                        expired_object_delete_marker: Some(true),
+                        ..Default::default()
+                    }),
+                    noncurrent_version_expiration_days: Some(30),
+                    noncurrent_version_transitions: vec![
+                        LifecycleNoncurrentVersionTransition {
+                            noncurrent_days: 10,
+                            storage_class: StorageClass::IA,
+                        },
+                        LifecycleNoncurrentVersionTransition {
+                            noncurrent_days: 20,
+                            storage_class: StorageClass::Archive,
+                        },
+                    ],
+                    ..Default::default()
+                },
+            ]
+        );
+
+        let parsed = LifecycleConfiguration::from_xml_ext(&config.to_xml().unwrap(), true).unwrap();
+        assert_eq!(config, parsed);
+    }
+
+    #[test]
+    fn test_lifecycle_strict_unknown_element() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <LifecycleConfiguration>
+          <Rule>
+            <ID>rule1</ID>
+            <Prefix></Prefix>
+            <Status>Enabled</Status>
+            <Transition><Days>30</Days><StorageClass>IA</StorageClass><IsAccessTime>true</IsAccessTime></Transition>
+          </Rule>
+        </LifecycleConfiguration>"#;
+
+        assert!(LifecycleConfiguration::from_xml(xml).is_ok());
+        assert!(matches!(
+            LifecycleConfiguration::from_xml_ext(xml, true),
+            Err(Error::Other(s)) if s.contains("IsAccessTime")
+        ));
+    }
+
+    #[test]
+    fn test_lifecycle_add_and_remove_rule() {
+        let rule = |id: &str| LifecycleRule {
+            id: id.to_string(),
+            prefix: format!("{}/", id),
+            status: LifecycleRuleStatus::Enabled,
+            expiration: Some(LifecycleExpiration {
+                days: Some(30),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let mut config = LifecycleConfiguration { rules: vec![rule("logs")] };
+
+        config.add_rule(rule("tmp")).unwrap();
+        assert_eq!(vec!["logs", "tmp"], config.rules.iter().map(|r| r.id.as_str()).collect::<Vec<_>>());
+
+        assert!(config.add_rule(rule("logs")).is_err());
+        assert_eq!(2, config.rules.len());
+
+        assert!(config.remove_rule("logs"));
+        assert!(!config.remove_rule("logs"));
+        assert_eq!(vec![rule("tmp")], config.rules);
+    }
+
+    #[test]
+    fn test_lifecycle_single_condition_filter() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <LifecycleConfiguration>
+          <Rule>
+            <ID>rule1</ID>
+            <Prefix></Prefix>
+            <Status>Disabled</Status>
+            <Filter>
+              <Tag><Key>temp</Key><Value>true</Value></Tag>
+            </Filter>
+            <Expiration><CreatedBeforeDate>2002-10-11T00:00:00.000Z</CreatedBeforeDate></Expiration>
+          </Rule>
+        </LifecycleConfiguration>"#;
+
+        let config = LifecycleConfiguration::from_xml(xml).unwrap();
+        assert_eq!(1, config.rules.len());
+
+        let rule = &config.rules[0];
+        assert_eq!(LifecycleRuleStatus::Disabled, rule.status);
+        assert_eq!(vec![("temp".to_string(), "true".to_string())], rule.filter.as_ref().unwrap().tags);
+        assert_eq!(None, rule.filter.as_ref().unwrap().prefix);
+        assert_eq!(
+            Some("2002-10-11T00:00:00.000Z"),
+            rule.expiration.as_ref().unwrap().created_before_date.as_deref()
+        );
+
+        let xml = config.to_xml().unwrap();
+        assert!(xml.contains("<Filter><Tag><Key>temp</Key><Value>true</Value></Tag></Filter>"));
+    }
+
+    #[test]
+    fn test_build_put_bucket_lifecycle_request() {
+        let config = LifecycleConfiguration {
+            rules: vec![LifecycleRule {
+                id: "expire-temp".to_string(),
+                prefix: "temp/".to_string(),
+                status: LifecycleRuleStatus::Enabled,
+                expiration: Some(LifecycleExpiration {
+                    days: Some(1),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }],
+        };
+
+        let request = build_put_bucket_lifecycle_request("yuanyq", &config).unwrap();
+        assert_eq!(RequestMethod::Put, request.method);
+        assert!(request.query.contains_key("lifecycle"));
+        assert_eq!(
+            Some(&content_md5_base64(config.to_xml().unwrap().as_bytes())),
+            request.headers.get("content-md5")
+        );
+
+        assert!(build_put_bucket_lifecycle_request("yuanyq", &LifecycleConfiguration::default()).is_err());
+        assert!(build_put_bucket_lifecycle_request("-invalid", &config).is_err());
+    }
+
+    #[test]
+    fn test_cors_configuration() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <CORSConfiguration>
+            <CORSRule>
+              <AllowedOrigin>*</AllowedOrigin>
+              <AllowedMethod>PUT</AllowedMethod>
+              <AllowedMethod>GET</AllowedMethod>
+              <AllowedHeader>Authorization</AllowedHeader>
+            </CORSRule>
+            <CORSRule>
+              <AllowedOrigin>http://example.com</AllowedOrigin>
+              <AllowedOrigin>http://example.net</AllowedOrigin>
+              <AllowedMethod>GET</AllowedMethod>
+              <AllowedHeader>Authorization</AllowedHeader>
+              <ExposeHeader>x-oss-test</ExposeHeader>
+              <ExposeHeader>x-oss-test1</ExposeHeader>
+              <MaxAgeSeconds>100</MaxAgeSeconds>
+            </CORSRule>
+            <ResponseVary>false</ResponseVary>
+        </CORSConfiguration>"#;
+
+        let config = CorsConfiguration::from_xml(xml).unwrap();
+        assert_eq!(2, config.rules.len());
+        assert_eq!(Some(false), config.response_vary);
+        assert_eq!(vec!["PUT".to_string(), "GET".to_string()], config.rules[0].allowed_methods);
+        assert_eq!(None, config.rules[0].max_age_seconds);
+        assert_eq!(
+            vec!["http://example.com".to_string(), "http://example.net".to_string()],
+            config.rules[1].allowed_origins
+        );
+        assert_eq!(vec!["x-oss-test".to_string(), "x-oss-test1".to_string()], config.rules[1].expose_headers);
+        assert_eq!(Some(100), config.rules[1].max_age_seconds);
+
+        let parsed = CorsConfiguration::from_xml(&config.to_xml().unwrap()).unwrap();
+        assert_eq!(config, parsed);
+    }
+
+    #[test]
+    fn test_build_put_bucket_cors_request() {
+        let rule = CorsRule {
+            allowed_origins: vec!["*".to_string()],
+            allowed_methods: vec!["PUT".to_string()],
+            ..Default::default()
+        };
+
+        let config = CorsConfiguration {
+            rules: vec![rule.clone()],
+            response_vary: None,
+        };
+
+        let request = build_put_bucket_cors_request("yuanyq", &config).unwrap();
+        assert_eq!(RequestMethod::Put, request.method);
+        assert!(request.query.contains_key("cors"));
+        assert_eq!(
+            Some(&content_md5_base64(config.to_xml().unwrap().as_bytes())),
+            request.headers.get("content-md5")
+        );
+
+        assert!(build_put_bucket_cors_request("yuanyq", &CorsConfiguration::default()).is_err());
+
+        let config = CorsConfiguration {
+            rules: vec![rule.clone(); 11],
+            response_vary: None,
+        };
+        assert!(build_put_bucket_cors_request("yuanyq", &config).is_err());
+
+        let config = CorsConfiguration {
+            rules: vec![CorsRule {
+                allowed_methods: vec!["OPTIONS".to_string()],
+                ..rule.clone()
+            }],
+            response_vary: None,
+        };
+        assert!(build_put_bucket_cors_request("yuanyq", &config).is_err());
+
+        let config = CorsConfiguration {
+            rules: vec![CorsRule {
+                allowed_origins: vec![],
+                ..rule
+            }],
+            response_vary: None,
+        };
+        assert!(build_put_bucket_cors_request("yuanyq", &config).is_err());
+    }
+
+    #[test]
+    fn test_build_list_object_versions_request() {
+        let options = ListObjectVersionsOptionsBuilder::new()
+            .prefix("a/")
+            .delimiter('/')
+            .key_marker("a/b.txt")
+            .version_id_marker("CAEQ")
+            .max_keys(100)
+            .build();
+
+        let request = build_list_object_versions_request("yuanyq", &Some(options)).unwrap();
+        assert!(request.query.contains_key("versions"));
+        assert_eq!(Some(&"a/".to_string()), request.query.get("prefix"));
+        assert_eq!(Some(&"/".to_string()), request.query.get("delimiter"));
+        assert_eq!(Some(&"a/b.txt".to_string()), request.query.get("key-marker"));
+        assert_eq!(Some(&"CAEQ".to_string()), request.query.get("version-id-marker"));
+        assert_eq!(Some(&"100".to_string()), request.query.get("max-keys"));
+
+        let options = ListObjectVersionsOptionsBuilder::new().version_id_marker("CAEQ").build();
+        assert!(build_list_object_versions_request("yuanyq", &Some(options)).is_err());
+
+        let options = ListObjectVersionsOptionsBuilder::new().max_keys(0).build();
+        assert!(build_list_object_versions_request("yuanyq", &Some(options)).is_err());
+    }
+
+    #[test]
+    fn test_bucket_encryption() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <ServerSideEncryptionRule>
+          <ApplyServerSideEncryptionByDefault>
+            <SSEAlgorithm>KMS</SSEAlgorithm>
+            <KMSMasterKeyID></KMSMasterKeyID>
+            <KMSDataEncryption>SM4</KMSDataEncryption>
+          </ApplyServerSideEncryptionByDefault>
+        </ServerSideEncryptionRule>"#;
+
+        let rule = ServerSideEncryptionRule::from_xml(xml).unwrap();
+        assert_eq!(ServerSideEncryptionAlgorithm::KMS, rule.sse_algorithm);
+        assert_eq!(None, rule.kms_master_key_id);
+        assert_eq!(Some("SM4"), rule.kms_data_encryption.as_deref());
+        assert_eq!(rule, ServerSideEncryptionRule::from_xml(&rule.to_xml().unwrap()).unwrap());
+
+        let rule = ServerSideEncryptionRule {
+            sse_algorithm: ServerSideEncryptionAlgorithm::KMS,
+            kms_master_key_id: Some("key-id".to_string()),
+            kms_data_encryption: None,
+        };
+        let request = build_put_bucket_encryption_request("yuanyq", &rule).unwrap();
+        assert_eq!(RequestMethod::Put, request.method);
+        assert!(request.query.contains_key("encryption"));
+        assert_eq!(Some(&content_md5_base64(rule.to_xml().unwrap().as_bytes())), request.headers.get("content-md5"));
+        assert!(rule
+            .to_xml()
+            .unwrap()
+            .contains("<ApplyServerSideEncryptionByDefault><SSEAlgorithm>KMS</SSEAlgorithm><KMSMasterKeyID>key-id</KMSMasterKeyID>"));
+
+        let rule = ServerSideEncryptionRule {
+            sse_algorithm: ServerSideEncryptionAlgorithm::AES256,
+            kms_master_key_id: Some("key-id".to_string()),
+            kms_data_encryption: None,
+        };
+        assert!(build_put_bucket_encryption_request("yuanyq", &rule).is_err());
+
+        let rule = ServerSideEncryptionRule {
+            sse_algorithm: ServerSideEncryptionAlgorithm::KMS,
+            kms_master_key_id: None,
+            kms_data_encryption: Some("AES256".to_string()),
+        };
+        assert!(build_put_bucket_encryption_request("yuanyq", &rule).is_err());
+    }
+
+    #[test]
+    fn test_website_configuration() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <WebsiteConfiguration>
+          <IndexDocument>
+            <Suffix>index.html</Suffix>
+            <SupportSubDir>true</SupportSubDir>
+            <Type>0</Type>
+          </IndexDocument>
+          <ErrorDocument>
+            <Key>error.html</Key>
+            <HttpStatus>404</HttpStatus>
+          </ErrorDocument>
+          <RoutingRules>
+            <RoutingRule>
+              <RuleNumber>1</RuleNumber>
+              <Condition>
+                <KeyPrefixEquals>abc/</KeyPrefixEquals>
+                <HttpErrorCodeReturnedEquals>404</HttpErrorCodeReturnedEquals>
+              </Condition>
+              <Redirect>
+                <RedirectType>Mirror</RedirectType>
+                <PassQueryString>true</PassQueryString>
+                <MirrorURL>http://example.com/</MirrorURL>
+              </Redirect>
+            </RoutingRule>
+            <RoutingRule>
+              <RuleNumber>2</RuleNumber>
+              <Condition>
+                <KeyPrefixEquals>docs/</KeyPrefixEquals>
+              </Condition>
+              <Redirect>
+                <RedirectType>External</RedirectType>
+                <Protocol>https</Protocol>
+                <HostName>example.com</HostName>
+                <ReplaceKeyPrefixWith>help/</ReplaceKeyPrefixWith>
+                <HttpRedirectCode>301</HttpRedirectCode>
+              </Redirect>
+            </RoutingRule>
+          </RoutingRules>
+        </WebsiteConfiguration>"#;
+
+        let config = WebsiteConfiguration::from_xml(xml).unwrap();
+        let index = config.index_document.as_ref().unwrap();
+        assert_eq!("index.html", index.suffix);
+        assert_eq!(Some(true), index.support_sub_dir);
+        assert_eq!(Some(0), index.index_type);
+        assert_eq!(Some(404), config.error_document.as_ref().unwrap().http_status);
+
+        assert_eq!(2, config.routing_rules.len());
+        assert_eq!(RedirectType::Mirror, config.routing_rules[0].redirect.redirect_type);
+        assert_eq!(Some(404), config.routing_rules[0].condition.http_error_code_returned_equals);
+        assert_eq!(Some("http://example.com/"), config.routing_rules[0].redirect.mirror_url.as_deref());
+
+        let rule = &config.routing_rules[1];
+        assert_eq!(2, rule.rule_number);
+        assert_eq!(Some("docs/"), rule.condition.key_prefix_equals.as_deref());
+        assert_eq!(RedirectType::External, rule.redirect.redirect_type);
+        assert_eq!(Some("help/"), rule.redirect.replace_key_prefix_with.as_deref());
+        assert_eq!(Some(301), rule.redirect.http_redirect_code);
+
+        assert_eq!(config, WebsiteConfiguration::from_xml(&config.to_xml().unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_build_put_bucket_website_request() {
+        let config = WebsiteConfiguration {
+            index_document: Some(IndexDocument {
+                suffix: "index.html".to_string(),
+                ..Default::default()
+            }),
+            error_document: None,
+            routing_rules: vec![RoutingRule {
+                rule_number: 1,
+                condition: RoutingRuleCondition {
+                    key_prefix_equals: Some("docs/".to_string()),
+                    ..Default::default()
+                },
+                redirect: RoutingRuleRedirect {
+                    redirect_type: RedirectType::External,
+                    host_name: Some("example.com".to_string()),
+                    ..Default::default()
+                },
+            }],
+        };
+
+        let request = build_put_bucket_website_request("yuanyq", &config).unwrap();
+        assert_eq!(RequestMethod::Put, request.method);
+        assert!(request.query.contains_key("website"));
+        assert_eq!(
+            Some(&content_md5_base64(config.to_xml().unwrap().as_bytes())),
+            request.headers.get("content-md5")
+        );
+
+        assert!(build_put_bucket_website_request("yuanyq", &WebsiteConfiguration::default()).is_err());
+        assert!(build_put_bucket_website_request("-invalid", &config).is_err());
+
+        let mut invalid = config.clone();
+        invalid.index_document = Some(IndexDocument::default());
+        assert!(build_put_bucket_website_request("yuanyq", &invalid).is_err());
+
+        let mut invalid = config.clone();
+        invalid.routing_rules[0].redirect.redirect_type = RedirectType::Mirror;
+        assert!(build_put_bucket_website_request("yuanyq", &invalid).is_err());
+
+        let mut invalid = config.clone();
+        invalid.routing_rules[0].redirect.replace_key_with = Some("${key}.html".to_string());
+        invalid.routing_rules[0].redirect.replace_key_prefix_with = Some("help/".to_string());
+        assert!(build_put_bucket_website_request("yuanyq", &invalid).is_err());
+
+        let mut invalid = config;
+        invalid.routing_rules = vec![invalid.routing_rules[0].clone(); 21];
+        assert!(build_put_bucket_website_request("yuanyq", &invalid).is_err());
+    }
+
+    #[test]
+    fn test_bucket_tagging() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <Tagging>
+          <TagSet>
+            <Tag>
+              <Key>owner</Key>
+              <Value>ci</Value>
+            </Tag>
+            <Tag>
+              <Key>env</Key>
+              <Value></Value>
+            </Tag>
+          </TagSet>
+        </Tagging>"#;
+
+        let tags = parse_bucket_tags_from_xml(xml).unwrap();
+        assert_eq!(vec![("owner".to_string(), "ci".to_string()), ("env".to_string(), "".to_string())], tags);
+        assert_eq!(tags, parse_bucket_tags_from_xml(&bucket_tags_to_xml(&tags).unwrap()).unwrap());
+        assert!(parse_bucket_tags_from_xml("<Tagging><TagSet></TagSet></Tagging>").unwrap().is_empty());
+
+        // bucket 标签的值允许为空，读取到的标签可以原样写回
+        let request = build_put_bucket_tagging_request("yuanyq", &tags).unwrap();
+        assert!(matches!(&request.body, RequestBody::Text(s) if *s == bucket_tags_to_xml(&tags).unwrap()));
+        assert!(build_put_bucket_tagging_request("yuanyq", &[("k".to_string(), "v".repeat(257))]).is_err());
+
+        let tags = vec![("owner".to_string(), "ci".to_string()), ("env".to_string(), "test".to_string())];
+        let request = build_put_bucket_tagging_request("yuanyq", &tags).unwrap();
+        assert_eq!(RequestMethod::Put, request.method);
+        assert!(request.query.contains_key("tagging"));
+        assert_eq!(
+            Some(&content_md5_base64(bucket_tags_to_xml(&tags).unwrap().as_bytes())),
+            request.headers.get("content-md5")
+        );
+
+        assert!(build_put_bucket_tagging_request("yuanyq", &[]).is_err());
+        assert!(build_put_bucket_tagging_request("-invalid", &tags).is_err());
+        assert!(build_put_bucket_tagging_request("yuanyq", &[("".to_string(), "v".to_string())]).is_err());
+        assert!(build_put_bucket_tagging_request("yuanyq", &[("k".to_string(), "v".to_string()), ("k".to_string(), "w".to_string())]).is_err());
+
+        let too_many = (0..21).map(|i| (format!("k{}", i), "v".to_string())).collect::<Vec<_>>();
+        assert!(build_put_bucket_tagging_request("yuanyq", &too_many).is_err());
+        assert!(build_put_bucket_tagging_request("yuanyq", &too_many[..20]).is_ok());
+    }
+
+    #[test]
+    fn test_build_put_bucket_policy_request() {
+        let policy = r#"{"Version":"1","Statement":[{"Effect":"Allow","Action":["oss:GetObject"],"Principal":["*"],"Resource":["acs:oss:*:*:yuanyq/*"]}]}"#;
+
+        let request = build_put_bucket_policy_request("yuanyq", policy).unwrap();
+        assert_eq!(RequestMethod::Put, request.method);
+        assert!(request.query.contains_key("policy"));
+        assert_eq!(Some(&"application/json".to_string()), request.headers.get("content-type"));
+
+        assert!(build_put_bucket_policy_request("yuanyq", "").is_err());
+        assert!(build_put_bucket_policy_request("yuanyq", "{\"Version\":").is_err());
+        assert!(build_put_bucket_policy_request("-invalid", policy).is_err());
+    }
+
+    #[test]
+    fn test_referer_configuration() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <RefererConfiguration>
+          <AllowEmptyReferer>false</AllowEmptyReferer>
+          <AllowTruncateQueryString>true</AllowTruncateQueryString>
+          <RefererList>
+            <Referer>http://www.aliyun.com</Referer>
+            <Referer>https://*.example.com</Referer>
+          </RefererList>
+        </RefererConfiguration>"#;
+
+        let config = RefererConfiguration::from_xml(xml).unwrap();
+        assert!(!config.allow_empty_referer);
+        assert_eq!(Some(true), config.allow_truncate_query_string);
+        assert_eq!(
+            vec!["http://www.aliyun.com".to_string(), "https://*.example.com".to_string()],
+            config.referer_list
+        );
+        assert_eq!(config, RefererConfiguration::from_xml(&config.to_xml().unwrap()).unwrap());
+
+        let request = build_put_bucket_referer_request("yuanyq", &config).unwrap();
+        assert_eq!(RequestMethod::Put, request.method);
+        assert!(request.query.contains_key("referer"));
+        assert_eq!(
+            Some(&content_md5_base64(config.to_xml().unwrap().as_bytes())),
+            request.headers.get("content-md5")
+        );
+
+        // 空白名单也要发送 `RefererList` 节点
+        let config = RefererConfiguration {
+            allow_empty_referer: true,
+            ..Default::default()
+        };
+        assert!(config.to_xml().unwrap().contains("<RefererList></RefererList>"));
+        assert_eq!(config, RefererConfiguration::from_xml(&config.to_xml().unwrap()).unwrap());
+
+        let config = RefererConfiguration {
+            referer_list: vec![" ".to_string()],
+            ..Default::default()
+        };
+        assert!(build_put_bucket_referer_request("yuanyq", &config).is_err());
+        assert!(build_put_bucket_referer_request("-invalid", &RefererConfiguration::default()).is_err());
+    }
+
+    #[test]
+    fn test_list_objects_start_after() {
+        let options = ListObjectsOptionsBuilder::new().start_after("a/b.txt").continuation_token("token").build();
+        let request = build_list_objects_request("yuanyq", &Some(options)).unwrap();
+        assert_eq!(Some(&"a/b.txt".to_string()), request.query.get("start-after"));
+        assert_eq!(Some(&"token".to_string()), request.query.get("continuation-token"));
+
+        let options = ListObjectsOptionsBuilder::new().start_after("a".repeat(1024)).build();
+        assert!(build_list_objects_request("yuanyq", &Some(options)).is_err());
+    }
+
+    /// Continuation token is base64 string which may contain `+`, `/` and `=`.
+    /// They must be percent encoded in both the signed canonical query and the url sent
+    #[test]
+    fn test_list_objects_continuation_token_encoding() {
+        let token = "Ch4+YS9iLmp/eHQ=";
+        let options = ListObjectsOptionsBuilder::new().continuation_token(token).build();
+        let request = build_list_objects_request("yuanyq", &Some(options)).unwrap();
+
+        let query = request.build_canonical_query_string();
+        assert_eq!("continuation-token=Ch4%2BYS9iLmp%2FeHQ%3D&list-type=2", query);
+
+        let url = url::Url::parse(&format!("https://yuanyq.oss-cn-beijing.aliyuncs.com/?{}", query)).unwrap();
+        assert_eq!(Some(query.as_str()), url.query());
+        assert!(url.query_pairs().any(|(k, v)| k == "continuation-token" && v == token));
+    }
+
+    #[test]
+    fn test_bucket_detail_access_control_list() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <BucketInfo>
+          <Bucket>
+            <AccessMonitor>Enabled</AccessMonitor>
+            <CreationDate>2013-07-31T10:56:21.000Z</CreationDate>
+            <ExtranetEndpoint>oss-cn-hangzhou.aliyuncs.com</ExtranetEndpoint>
+            <IntranetEndpoint>oss-cn-hangzhou-internal.aliyuncs.com</IntranetEndpoint>
+            <Location>oss-cn-hangzhou</Location>
+            <StorageClass>Standard</StorageClass>
+            <TransferAcceleration>Disabled</TransferAcceleration>
+            <CrossRegionReplication>Disabled</CrossRegionReplication>
+            <Name>oss-example</Name>
+            <ResourceGroupId>rg-aek27tc********</ResourceGroupId>
+            <Owner>
+              <DisplayName>username</DisplayName>
+              <ID>27183473914****</ID>
+            </Owner>
+            <AccessControlList>
+              <Grant>public-read</Grant>
+            </AccessControlList>
+            <Comment>test</Comment>
+            <ServerSideEncryptionRule>
+              <SSEAlgorithm>None</SSEAlgorithm>
+            </ServerSideEncryptionRule>
+            <BucketPolicy>
+              <LogBucket>examplebucket</LogBucket>
+              <LogPrefix>log/</LogPrefix>
+            </BucketPolicy>
+          </Bucket>
+        </BucketInfo>"#;
+
+        let detail = BucketDetail::from_xml(xml).unwrap();
+        assert_eq!("oss-example", detail.name);
+        assert_eq!(vec![BucketAcl::PublicRead], detail.access_control_list);
+        assert_eq!("27183473914****", detail.owner.id);
+        assert_eq!("examplebucket", detail.bucket_policy.log_bucket);
+
+        let xml = xml.replace("public-read", "public-read-write-all");
+        assert!(BucketDetail::from_xml(&xml).is_err());
+    }
+
+    #[test]
+    fn test_list_object_versions_result() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <ListVersionsResult>
+            <Name>examplebucket</Name>
+            <Prefix>example</Prefix>
+            <KeyMarker></KeyMarker>
+            <VersionIdMarker></VersionIdMarker>
+            <MaxKeys>100</MaxKeys>
+            <Delimiter></Delimiter>
+            <IsTruncated>true</IsTruncated>
+            <NextKeyMarker>example</NextKeyMarker>
+            <NextVersionIdMarker>CAEQMxiBgICbof2D0BYiIGRhZjgwMzJiMjA3MjQ0ODE5MWYxZDYwMzJlZjU1****</NextVersionIdMarker>
+            <DeleteMarker>
+                <Key>example</Key>
+                <VersionId>CAEQMxiBgICAof2D0BYiIDJhMGE3N2M1YTI1NDQzOGY5NTkyNTI3MGYyMzJm****</VersionId>
+                <IsLatest>true</IsLatest>
+                <LastModified>2019-04-09T07:27:28.000Z</LastModified>
+                <Owner>
+                    <ID>1234512528586****</ID>
+                    <DisplayName>12345125285864390</DisplayName>
+                </Owner>
+            </DeleteMarker>
+            <Version>
+                <Key>example</Key>
+                <VersionId>CAEQMxiBgMDNoP2D0BYiIDE3MWUxNzgxZDQxNTRiODI5OGYwZGMwNGY3MzZjN****</VersionId>
+                <IsLatest>false</IsLatest>
+                <LastModified>2019-04-09T07:27:28.000Z</LastModified>
+                <ETag>"250F8A0AE989679A22926A875F0A2****"</ETag>
+                <Type>Normal</Type>
+                <Size>93731</Size>
+                <StorageClass>Standard</StorageClass>
+                <Owner>
+                    <ID>1234512528586****</ID>
+                    <DisplayName>12345125285864390</DisplayName>
+                </Owner>
+            </Version>
+        </ListVersionsResult>"#;
+
+        let ret = ListObjectVersionsResult::from_xml(xml).unwrap();
+        assert_eq!("examplebucket", ret.name);
+        assert_eq!("example", ret.prefix);
+        assert!(ret.is_truncated);
+        assert!(ret.key_marker.is_none());
+        assert_eq!(Some("example"), ret.next_key_marker.as_deref());
+        assert_eq!(1, ret.delete_markers.len());
+        assert!(ret.delete_markers[0].is_latest);
+        assert_eq!(1, ret.versions.len());
+        assert_eq!("250F8A0AE989679A22926A875F0A2****", ret.versions[0].etag);
+        assert_eq!(93731, ret.versions[0].size);
+        assert!(!ret.versions[0].is_latest);
+    }
 }
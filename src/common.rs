@@ -1,20 +1,221 @@
 //! Common types: structs and enumerations
-use std::{collections::HashMap, fmt::Display};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    hash::{BuildHasher, RandomState},
+    sync::Arc,
+    time::Duration,
+};
 
 use quick_xml::events::Event;
 
-use crate::error::Error;
+use crate::error::{Error, OssErrorCode};
+use crate::request::RequestMethod;
 use crate::Result;
 
-pub const VERSION: &str = "0.2.5";
+pub const VERSION: &str = "0.3.0";
 
 pub const MIME_TYPE_XML: &str = "application/xml";
+pub const MIME_TYPE_JSON: &str = "application/json";
 pub const DELETE_MULTIPLE_OBJECTS_LIMIT: usize = 1000;
 pub const SIGNATURE_VERSION: &str = "OSS4-HMAC-SHA256";
 pub const UNSIGNED_PAYLOAD: &str = "UNSIGNED-PAYLOAD";
 pub const MIN_BUCKET_NAME_LENGTH: usize = 3;
 pub const MAX_BUCKET_NAME_LENGTH: usize = 63;
 pub const MAX_LIST_OBJECTS_LIMIT: u32 = 1000;
+pub const MAX_OBJECT_TAGS: usize = 10;
+
+/// CopyObject 接口只能拷贝小于 1GB 的文件，大于等于 1GB 的文件需要使用 UploadPartCopy 分片拷贝
+pub const MAX_COPY_OBJECT_SIZE: u64 = 1024 * 1024 * 1024;
+
+/// 回调失败时 OSS 在 203 响应体中返回的错误码
+pub(crate) const CALLBACK_FAILED_CODE: &str = "CallbackFailed";
+
+/// Preset of timeout, retry and connection pool settings for typical workloads.
+/// Use it with `ClientBuilder::profile`, individual setters called afterwards override the preset values.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum ClientProfile {
+    /// 使用 `reqwest` 默认的超时和连接池设置，不重试
+    #[default]
+    Default,
+
+    /// 大文件上传下载、批量处理等后台任务：较长的超时时间，更多的重试次数和更大的连接池
+    Batch,
+
+    /// 面向用户的交互式请求：较短的超时时间，尽快失败，只重试一次
+    Interactive,
+}
+
+impl ClientProfile {
+    /// Total timeout of a request, including connecting, sending and reading the response
+    pub fn timeout(&self) -> Option<Duration> {
+        match self {
+            ClientProfile::Default => None,
+            ClientProfile::Batch => Some(Duration::from_secs(30 * 60)),
+            ClientProfile::Interactive => Some(Duration::from_secs(10)),
+        }
+    }
+
+    pub fn connect_timeout(&self) -> Option<Duration> {
+        match self {
+            ClientProfile::Default => None,
+            ClientProfile::Batch => Some(Duration::from_secs(30)),
+            ClientProfile::Interactive => Some(Duration::from_secs(3)),
+        }
+    }
+
+    /// Max retry times for a failed request. `0` means no retry
+    pub fn max_retries(&self) -> u32 {
+        match self {
+            ClientProfile::Default => 0,
+            ClientProfile::Batch => 5,
+            ClientProfile::Interactive => 1,
+        }
+    }
+
+    pub fn pool_max_idle_per_host(&self) -> Option<usize> {
+        match self {
+            ClientProfile::Default => None,
+            ClientProfile::Batch => Some(64),
+            ClientProfile::Interactive => Some(8),
+        }
+    }
+}
+
+/// Check if a failed request could be retried:
+/// timeout or connection errors, 5xx status code and OSS internal errors.
+/// This is the default predicate of [`RetryConfig::retry_on`], custom predicates could fall back to it.
+pub fn is_retryable_error(e: &Error) -> bool {
+    match e {
+        Error::ReqwestError(e) => e.is_timeout() || e.is_connect(),
+        Error::StatusError(status) => status.is_server_error(),
+        Error::ApiError(e) => matches!(
+            e.error_code(),
+            OssErrorCode::InternalError | OssErrorCode::ServiceUnavailable | OssErrorCode::RequestTimeout
+        ),
+        _ => false,
+    }
+}
+
+/// Retry policy of failed requests. Set it with `ClientBuilder::retry_config`.
+///
+/// Only idempotent requests (`GET`, `HEAD`, `PUT` and `DELETE`) are retried, `POST` requests are never retried,
+/// e.g. append object, initiate and complete multipart upload.
+/// The request body is rebuilt for each attempt, files are re-opened and read from the beginning of the range.
+///
+/// Wait before the `n`-th retry is a random duration in `[d / 2, d]`, where `d = min(base_delay * 2^(n - 1), max_delay)`.
+/// If the failed response has a `Retry-After` header, wait as it says instead, but no longer than `max_delay`.
+///
+/// ```
+/// use std::time::Duration;
+/// use ali_oss_rs::common::{is_retryable_error, RetryConfig};
+/// use ali_oss_rs::error::OssErrorCode;
+///
+/// let config = RetryConfig::new(3)
+///     .base_delay(Duration::from_millis(100))
+///     .max_delay(Duration::from_secs(2))
+///     .retry_on(|e| is_retryable_error(e) || e.oss_error_code() == Some(OssErrorCode::RequestTimeTooSkewed));
+/// ```
+#[derive(Clone)]
+pub struct RetryConfig {
+    /// Max retry times for a failed request. `0` means no retry
+    pub max_retries: u32,
+
+    /// Wait before the first retry, doubled for each retry after. Default is 200ms
+    pub base_delay: Duration,
+
+    /// The upper limit of wait between retries. Default is 5s
+    pub max_delay: Duration,
+
+    /// Decides if a failed request should be retried. Default is [`is_retryable_error`]
+    pub retry_on: Arc<dyn Fn(&Error) -> bool + Send + Sync>,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            retry_on: Arc::new(is_retryable_error),
+        }
+    }
+}
+
+impl std::fmt::Debug for RetryConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryConfig")
+            .field("max_retries", &self.max_retries)
+            .field("base_delay", &self.base_delay)
+            .field("max_delay", &self.max_delay)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RetryConfig {
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            ..Default::default()
+        }
+    }
+
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn retry_on<F>(mut self, retry_on: F) -> Self
+    where
+        F: Fn(&Error) -> bool + Send + Sync + 'static,
+    {
+        self.retry_on = Arc::new(retry_on);
+        self
+    }
+
+    /// Check if the request should be retried after `attempt` retries already done
+    pub(crate) fn should_retry(&self, method: RequestMethod, attempt: u32, e: &Error) -> bool {
+        method != RequestMethod::Post && attempt < self.max_retries && (self.retry_on)(e)
+    }
+
+    /// Wait before the `attempt`-th retry (starts from 1)
+    pub(crate) fn backoff(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        // `Retry-After` 由服务端给出，同样受 `max_delay` 限制，避免请求被挂起过久
+        if let Some(d) = retry_after {
+            return d.min(self.max_delay);
+        }
+
+        let factor = 1u32 << attempt.saturating_sub(1).min(16);
+        let delay = self.base_delay.saturating_mul(factor).min(self.max_delay);
+
+        // 在 [d / 2, d] 之间随机等待，避免大量客户端同时重试。`RandomState` 每次创建时的种子都是随机的
+        let half = delay / 2;
+        let jitter = RandomState::new().hash_one(attempt) % (half.as_millis() as u64 + 1);
+        half + Duration::from_millis(jitter)
+    }
+}
+
+/// Parse `Retry-After` header value, which is either seconds or a http date
+pub(crate) fn parse_retry_after(value: &str) -> Option<Duration> {
+    if let Ok(n) = value.trim().parse::<u64>() {
+        return Some(Duration::from_secs(n));
+    }
+
+    let date = chrono::DateTime::parse_from_rfc2822(value.trim()).ok()?;
+    (date.with_timezone(&chrono::Utc) - chrono::Utc::now()).to_std().ok()
+}
+
+/// Check if the response content of an api call with callback is a `CallbackFailed` error.
+/// OSS responds with status 203 in this case, the object is stored but the callback failed.
+pub(crate) fn is_callback_failed(content: &str) -> bool {
+    content.contains(&format!("<Code>{}</Code>", CALLBACK_FAILED_CODE))
+}
 
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
@@ -412,6 +613,95 @@ impl TryFrom<&String> for ServerSideEncryptionAlgorithm {
     }
 }
 
+/// Object 级别的保留策略（WORM）模式。没有默认值，需要调用方明确选择
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum ObjectRetentionMode {
+    /// 合规模式。保留期内任何用户（包括主账号）都不能删除或者覆盖 Object，也不能缩短保留期
+    #[cfg_attr(feature = "serde-support", serde(rename = "COMPLIANCE"))]
+    Compliance,
+
+    /// 治理模式。拥有特殊权限的用户可以修改保留设置或者删除 Object
+    #[cfg_attr(feature = "serde-support", serde(rename = "GOVERNANCE"))]
+    Governance,
+}
+
+impl ObjectRetentionMode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ObjectRetentionMode::Compliance => "COMPLIANCE",
+            ObjectRetentionMode::Governance => "GOVERNANCE",
+        }
+    }
+}
+
+impl AsRef<str> for ObjectRetentionMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for ObjectRetentionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<&str> for ObjectRetentionMode {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        match value {
+            "COMPLIANCE" => Ok(ObjectRetentionMode::Compliance),
+            "GOVERNANCE" => Ok(ObjectRetentionMode::Governance),
+            _ => Err(Error::Other(format!("Invalid ObjectRetentionMode value: {}", value))),
+        }
+    }
+}
+
+impl TryFrom<String> for ObjectRetentionMode {
+    type Error = crate::error::Error;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+/// Object 级别的保留策略（WORM）。
+///
+/// **注意** 需要账号以及 Bucket 已经开通并启用了 Object 级别的保留策略，否则设置了保留策略的请求会失败。
+/// 保留期内 Object 不能被删除或者覆盖，合规模式下的设置无法撤销，请谨慎使用。
+/// 因此没有实现 `Default`，需要通过 [`ObjectRetention::new`] 明确指定模式和保留截止时间。
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct ObjectRetention {
+    pub mode: ObjectRetentionMode,
+
+    /// 保留截止时间，ISO 8601 格式，例如：`2030-01-01T00:00:00.000Z`
+    pub retain_until_date: String,
+}
+
+impl ObjectRetention {
+    pub fn new(mode: ObjectRetentionMode, retain_until_date: impl Into<String>) -> Self {
+        Self {
+            mode,
+            retain_until_date: retain_until_date.into(),
+        }
+    }
+
+    /// 从响应头 `x-oss-object-retention-mode` 和 `x-oss-object-retention-retain-until-date` 解析，两者都存在时才返回
+    pub(crate) fn from_headers(headers: &mut HashMap<String, String>) -> Option<Self> {
+        let mode = headers.remove("x-oss-object-retention-mode");
+        let retain_until_date = headers.remove("x-oss-object-retention-retain-until-date");
+
+        match (mode.and_then(|s| ObjectRetentionMode::try_from(s).ok()), retain_until_date) {
+            (Some(mode), Some(retain_until_date)) => Some(Self { mode, retain_until_date }),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
 #[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
@@ -423,6 +713,22 @@ pub struct ServerSideEncryptionRule {
     pub kms_data_encryption: Option<String>,
 }
 
+/// Metadata of a response, useful for diagnosing and filing support tickets with Aliyun.
+/// Returned by the `*_with_meta` methods
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct ResponseMeta {
+    /// 响应头 `x-oss-request-id`
+    pub request_id: String,
+
+    /// HTTP 状态码
+    pub status: u16,
+
+    /// 从发出请求到收到响应的耗时，包含重试的时间
+    pub elapsed: Duration,
+}
+
 /// Object type enumeration
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
 #[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
@@ -633,6 +939,38 @@ pub struct VersionIdOnlyOptions {
     pub version_id: Option<String>,
 }
 
+/// Options which can target a specific version of an object in a versioning enabled bucket.
+/// The version id is sent as `versionId` query parameter.
+///
+/// ```
+/// use ali_oss_rs::common::WithVersionId;
+/// use ali_oss_rs::object_common::HeadObjectOptions;
+///
+/// let options = HeadObjectOptions::with_version_id("CAEQNhiBgMDJgZCA0BYiIDc4MGZjZGI2OTBjOTRmNTE5NmU5NmFhZjhjYmY0****");
+/// assert!(options.version_id().is_some());
+/// ```
+pub trait WithVersionId: Default {
+    fn version_id(&self) -> Option<&str>;
+    fn set_version_id(&mut self, version_id: Option<String>);
+
+    /// Create default options targeting the given version
+    fn with_version_id(version_id: impl Into<String>) -> Self {
+        let mut options = Self::default();
+        options.set_version_id(Some(version_id.into()));
+        options
+    }
+}
+
+impl WithVersionId for VersionIdOnlyOptions {
+    fn version_id(&self) -> Option<&str> {
+        self.version_id.as_deref()
+    }
+
+    fn set_version_id(&mut self, version_id: Option<String>) {
+        self.version_id = version_id;
+    }
+}
+
 /// Build tags string
 pub(crate) fn build_tag_string(tags: &HashMap<String, String>) -> String {
     tags.iter()
@@ -28,7 +28,188 @@ pub struct ErrorResponse {
     pub recommend_doc: String,
 }
 
+/// Typed error code of [`ErrorResponse`]. Only the frequently used codes are listed,
+/// others are kept as [`OssErrorCode::Other`].
+/// More codes may be added in later versions, so matching on it needs a wildcard arm.
+///
+/// Official document: <https://help.aliyun.com/zh/oss/support/http-status-code>
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OssErrorCode {
+    AccessDenied,
+    BucketAlreadyExists,
+    BucketNotEmpty,
+    CallbackFailed,
+    EntityTooLarge,
+    EntityTooSmall,
+    FileAlreadyExists,
+    InternalError,
+    InvalidAccessKeyId,
+    InvalidArgument,
+    InvalidBucketName,
+    InvalidDigest,
+    InvalidObjectName,
+    InvalidObjectState,
+    InvalidPart,
+    InvalidPartOrder,
+    MalformedXml,
+    MethodNotAllowed,
+    MissingArgument,
+    MissingContentLength,
+    NoSuchBucket,
+    NoSuchBucketPolicy,
+    NoSuchCorsConfiguration,
+    NoSuchKey,
+    NoSuchLifecycle,
+    NoSuchUpload,
+    NoSuchWebsiteConfiguration,
+    ObjectNotAppendable,
+    PositionNotEqualToLength,
+    PreconditionFailed,
+    RequestTimeTooSkewed,
+    RequestTimeout,
+    RestoreAlreadyInProgress,
+    ServiceUnavailable,
+    SignatureDoesNotMatch,
+    TooManyBuckets,
+
+    /// 未列出的错误码，保留原始字符串
+    Other(String),
+}
+
+impl OssErrorCode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::AccessDenied => "AccessDenied",
+            Self::BucketAlreadyExists => "BucketAlreadyExists",
+            Self::BucketNotEmpty => "BucketNotEmpty",
+            Self::CallbackFailed => "CallbackFailed",
+            Self::EntityTooLarge => "EntityTooLarge",
+            Self::EntityTooSmall => "EntityTooSmall",
+            Self::FileAlreadyExists => "FileAlreadyExists",
+            Self::InternalError => "InternalError",
+            Self::InvalidAccessKeyId => "InvalidAccessKeyId",
+            Self::InvalidArgument => "InvalidArgument",
+            Self::InvalidBucketName => "InvalidBucketName",
+            Self::InvalidDigest => "InvalidDigest",
+            Self::InvalidObjectName => "InvalidObjectName",
+            Self::InvalidObjectState => "InvalidObjectState",
+            Self::InvalidPart => "InvalidPart",
+            Self::InvalidPartOrder => "InvalidPartOrder",
+            Self::MalformedXml => "MalformedXML",
+            Self::MethodNotAllowed => "MethodNotAllowed",
+            Self::MissingArgument => "MissingArgument",
+            Self::MissingContentLength => "MissingContentLength",
+            Self::NoSuchBucket => "NoSuchBucket",
+            Self::NoSuchBucketPolicy => "NoSuchBucketPolicy",
+            Self::NoSuchCorsConfiguration => "NoSuchCORSConfiguration",
+            Self::NoSuchKey => "NoSuchKey",
+            Self::NoSuchLifecycle => "NoSuchLifecycle",
+            Self::NoSuchUpload => "NoSuchUpload",
+            Self::NoSuchWebsiteConfiguration => "NoSuchWebsiteConfiguration",
+            Self::ObjectNotAppendable => "ObjectNotAppendable",
+            Self::PositionNotEqualToLength => "PositionNotEqualToLength",
+            Self::PreconditionFailed => "PreconditionFailed",
+            Self::RequestTimeTooSkewed => "RequestTimeTooSkewed",
+            Self::RequestTimeout => "RequestTimeout",
+            Self::RestoreAlreadyInProgress => "RestoreAlreadyInProgress",
+            Self::ServiceUnavailable => "ServiceUnavailable",
+            Self::SignatureDoesNotMatch => "SignatureDoesNotMatch",
+            Self::TooManyBuckets => "TooManyBuckets",
+            Self::Other(s) => s.as_str(),
+        }
+    }
+}
+
+impl Display for OssErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl AsRef<str> for OssErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<&str> for OssErrorCode {
+    fn from(s: &str) -> Self {
+        match s {
+            "AccessDenied" => Self::AccessDenied,
+            "BucketAlreadyExists" => Self::BucketAlreadyExists,
+            "BucketNotEmpty" => Self::BucketNotEmpty,
+            "CallbackFailed" => Self::CallbackFailed,
+            "EntityTooLarge" => Self::EntityTooLarge,
+            "EntityTooSmall" => Self::EntityTooSmall,
+            "FileAlreadyExists" => Self::FileAlreadyExists,
+            "InternalError" => Self::InternalError,
+            "InvalidAccessKeyId" => Self::InvalidAccessKeyId,
+            "InvalidArgument" => Self::InvalidArgument,
+            "InvalidBucketName" => Self::InvalidBucketName,
+            "InvalidDigest" => Self::InvalidDigest,
+            "InvalidObjectName" => Self::InvalidObjectName,
+            "InvalidObjectState" => Self::InvalidObjectState,
+            "InvalidPart" => Self::InvalidPart,
+            "InvalidPartOrder" => Self::InvalidPartOrder,
+            "MalformedXML" => Self::MalformedXml,
+            "MethodNotAllowed" => Self::MethodNotAllowed,
+            "MissingArgument" => Self::MissingArgument,
+            "MissingContentLength" => Self::MissingContentLength,
+            "NoSuchBucket" => Self::NoSuchBucket,
+            "NoSuchBucketPolicy" => Self::NoSuchBucketPolicy,
+            "NoSuchCORSConfiguration" => Self::NoSuchCorsConfiguration,
+            "NoSuchKey" => Self::NoSuchKey,
+            "NoSuchLifecycle" => Self::NoSuchLifecycle,
+            "NoSuchUpload" => Self::NoSuchUpload,
+            "NoSuchWebsiteConfiguration" => Self::NoSuchWebsiteConfiguration,
+            "ObjectNotAppendable" => Self::ObjectNotAppendable,
+            "PositionNotEqualToLength" => Self::PositionNotEqualToLength,
+            "PreconditionFailed" => Self::PreconditionFailed,
+            "RequestTimeTooSkewed" => Self::RequestTimeTooSkewed,
+            "RequestTimeout" => Self::RequestTimeout,
+            "RestoreAlreadyInProgress" => Self::RestoreAlreadyInProgress,
+            "ServiceUnavailable" => Self::ServiceUnavailable,
+            "SignatureDoesNotMatch" => Self::SignatureDoesNotMatch,
+            "TooManyBuckets" => Self::TooManyBuckets,
+            _ => Self::Other(s.to_string()),
+        }
+    }
+}
+
+impl From<String> for OssErrorCode {
+    fn from(s: String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
 impl ErrorResponse {
+    /// Typed error code parsed from `<Code>`
+    pub fn error_code(&self) -> OssErrorCode {
+        OssErrorCode::from(self.code.as_str())
+    }
+
+    pub fn code_str(&self) -> &str {
+        &self.code
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn request_id(&self) -> &str {
+        &self.request_id
+    }
+
+    pub fn host_id(&self) -> &str {
+        &self.host_id
+    }
+
+    /// 错误详情码（`<EC>`），可以在 <https://api.aliyun.com/troubleshoot> 中查询
+    pub fn ec(&self) -> &str {
+        &self.ec
+    }
+
     pub fn from_xml(xml_content: &str) -> crate::Result<Self> {
         let mut reader = quick_xml::Reader::from_str(xml_content);
         let mut ret = Self::default();
@@ -65,6 +246,27 @@ impl ErrorResponse {
     }
 }
 
+/// Some operations (e.g. CompleteMultipartUpload) may respond `200 OK` with an `<Error>` xml body.
+/// Returns the api error in this case, so it does not slip through as success
+pub(crate) fn error_from_ok_response(status: reqwest::StatusCode, content: &str) -> Option<Error> {
+    if status != reqwest::StatusCode::OK {
+        return None;
+    }
+
+    let mut reader = quick_xml::Reader::from_str(content);
+
+    // 只检查根元素，避免把内容中恰好包含 `<Error>` 的响应当成错误
+    loop {
+        match reader.read_event() {
+            Ok(quick_xml::events::Event::Start(e)) if e.local_name().as_ref() == b"Error" => break,
+            Ok(quick_xml::events::Event::Decl(_) | quick_xml::events::Event::Comment(_) | quick_xml::events::Event::Text(_)) => {}
+            _ => return None,
+        }
+    }
+
+    ErrorResponse::from_xml(content).ok().map(|e| Error::ApiError(Box::new(e)))
+}
+
 impl Display for ErrorResponse {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Code: {}, Message: {}, Request Id: {}", self.code, self.message, self.request_id)
@@ -109,6 +311,102 @@ pub enum Error {
     #[error("{0}")]
     DecodeError(#[from] base64::DecodeError),
 
+    /// The object is not modified since the time specified by `if_modified_since`,
+    /// or the etag matches `if_none_match`. Responded with status code `304`
+    #[error("not modified")]
+    NotModified,
+
+    /// CopyObject only works between buckets in the same region.
+    /// Download the source object and upload it to the destination bucket instead
+    #[error("cross region copy is not supported: source bucket is in {source_region}, destination bucket is in {dest_region}. download the object and upload it to the destination bucket instead")]
+    CrossRegionCopyUnsupported { source_region: String, dest_region: String },
+
+    /// The CRC64 of uploaded or downloaded data does not match `x-oss-hash-crc64ecma` returned by OSS.
+    /// Returned by uploads with `verify_crc64` enabled and by downloads verifying CRC64.
+    /// The data may be corrupted during transmission
+    #[error("crc64 mismatch: expected {expected}, actual {actual}")]
+    CrcMismatch { expected: u64, actual: u64 },
+
+    /// Environment variable required by `Client::try_from_env` is missing, empty or not valid unicode
+    #[error("env var {name} {reason}")]
+    EnvVarError { name: String, reason: String },
+
     #[error("{0}")]
     Other(String),
 }
+
+impl Error {
+    /// Returns the typed OSS error code if this is an [`Error::ApiError`]
+    pub fn oss_error_code(&self) -> Option<OssErrorCode> {
+        match self {
+            Error::ApiError(e) => Some(e.error_code()),
+            _ => None,
+        }
+    }
+
+    /// The bucket or object does not exist: status `404` without a response body,
+    /// or api error with code `NoSuchKey` / `NoSuchBucket`
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Error::StatusError(status) => *status == reqwest::StatusCode::NOT_FOUND,
+            Error::ApiError(e) => matches!(e.error_code(), OssErrorCode::NoSuchKey | OssErrorCode::NoSuchBucket),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_error {
+    use super::{Error, ErrorResponse, OssErrorCode};
+
+    #[test]
+    fn test_error_code() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+  <Code>NoSuchKey</Code>
+  <Message>The specified key does not exist.</Message>
+  <RequestId>5C3D9175B6FC201293AD****</RequestId>
+  <HostId>examplebucket.oss-cn-hangzhou.aliyuncs.com</HostId>
+  <EC>0026-00000001</EC>
+</Error>"#;
+
+        let e = ErrorResponse::from_xml(xml).unwrap();
+        assert_eq!(OssErrorCode::NoSuchKey, e.error_code());
+        assert_eq!("NoSuchKey", e.code_str());
+        assert_eq!("5C3D9175B6FC201293AD****", e.request_id());
+        assert_eq!("examplebucket.oss-cn-hangzhou.aliyuncs.com", e.host_id());
+        assert_eq!("0026-00000001", e.ec());
+
+        let e = Error::ApiError(Box::new(e));
+        assert_eq!(Some(OssErrorCode::NoSuchKey), e.oss_error_code());
+        assert_eq!(None, Error::Other("NoSuchKey".to_string()).oss_error_code());
+
+        for code in ["AccessDenied", "SignatureDoesNotMatch", "MalformedXML", "NoSuchCORSConfiguration"] {
+            let c = OssErrorCode::from(code);
+            assert!(!matches!(c, OssErrorCode::Other(_)));
+            assert_eq!(code, c.as_str());
+        }
+
+        assert_eq!(OssErrorCode::Other("SomethingNew".to_string()), OssErrorCode::from("SomethingNew"));
+        assert_eq!("SomethingNew", OssErrorCode::from("SomethingNew").to_string());
+    }
+
+    #[test]
+    fn test_is_not_found() {
+        let api_error = |code: &str| {
+            Error::ApiError(Box::new(ErrorResponse {
+                code: code.to_string(),
+                ..Default::default()
+            }))
+        };
+
+        assert!(Error::StatusError(reqwest::StatusCode::NOT_FOUND).is_not_found());
+        assert!(api_error("NoSuchKey").is_not_found());
+        assert!(api_error("NoSuchBucket").is_not_found());
+
+        assert!(!Error::StatusError(reqwest::StatusCode::FORBIDDEN).is_not_found());
+        assert!(!api_error("AccessDenied").is_not_found());
+        assert!(!api_error("NoSuchUpload").is_not_found());
+        assert!(!Error::Other("NoSuchKey".to_string()).is_not_found());
+    }
+}
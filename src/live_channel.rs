@@ -0,0 +1,70 @@
+//! Bucket live channels (RTMP ingest)
+
+use crate::{
+    live_channel_common::{build_list_live_channels_request, ListLiveChannelsOptions, ListLiveChannelsResult},
+    Client, Result,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait LiveChannelOperations {
+    /// List live channels of a bucket
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/listlivechannel>
+    async fn list_live_channels<S>(&self, bucket_name: S, options: Option<ListLiveChannelsOptions>) -> Result<ListLiveChannelsResult>
+    where
+        S: AsRef<str> + Send;
+}
+
+#[async_trait]
+impl LiveChannelOperations for Client {
+    /// List live channels of a bucket
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/listlivechannel>
+    async fn list_live_channels<S>(&self, bucket_name: S, options: Option<ListLiveChannelsOptions>) -> Result<ListLiveChannelsResult>
+    where
+        S: AsRef<str> + Send,
+    {
+        let request = build_list_live_channels_request(bucket_name.as_ref(), &options)?;
+
+        let (_, xml) = self.do_request::<String>(request).await?;
+
+        ListLiveChannelsResult::from_xml(&xml)
+    }
+}
+
+#[cfg(test)]
+pub mod test_live_channel_async {
+    use std::sync::Once;
+
+    use crate::{live_channel::LiveChannelOperations, live_channel_common::ListLiveChannelsOptions, Client};
+
+    static INIT: Once = Once::new();
+
+    fn setup() {
+        INIT.call_once(|| {
+            simple_logger::init_with_level(log::Level::Debug).unwrap();
+            dotenvy::dotenv().unwrap();
+        });
+    }
+
+    #[tokio::test]
+    async fn test_list_live_channels_async() {
+        setup();
+
+        let client = Client::from_env();
+
+        let options = ListLiveChannelsOptions {
+            max_keys: Some(10),
+            ..Default::default()
+        };
+
+        let res = client.list_live_channels("yuanyq", Some(options)).await;
+        assert!(res.is_ok());
+
+        let ret = res.unwrap();
+        log::debug!("{:#?}", ret);
+
+        assert!(ret.live_channels.len() <= 10);
+    }
+}
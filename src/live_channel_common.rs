@@ -0,0 +1,289 @@
+use std::fmt::Display;
+
+use quick_xml::events::Event;
+
+use crate::{
+    error::Error,
+    request::{OssRequest, RequestMethod},
+    util::validate_bucket_name,
+    Result,
+};
+
+/// LiveChannel 的状态
+///
+/// - `enabled`: 启用
+/// - `disabled`: 禁用。禁用时不能向该 LiveChannel 推流
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum LiveChannelStatus {
+    #[default]
+    #[cfg_attr(feature = "serde-support", serde(rename = "enabled"))]
+    Enabled,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "disabled"))]
+    Disabled,
+}
+
+impl LiveChannelStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            LiveChannelStatus::Enabled => "enabled",
+            LiveChannelStatus::Disabled => "disabled",
+        }
+    }
+}
+
+impl AsRef<str> for LiveChannelStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for LiveChannelStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<&str> for LiveChannelStatus {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        match value {
+            "enabled" => Ok(LiveChannelStatus::Enabled),
+            "disabled" => Ok(LiveChannelStatus::Disabled),
+            _ => Err(Error::Other(format!("invalid live channel status: {}", value))),
+        }
+    }
+}
+
+impl TryFrom<String> for LiveChannelStatus {
+    type Error = crate::error::Error;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+/// Options for listing live channels
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct ListLiveChannelsOptions {
+    /// 只返回名称以 `prefix` 开头的 LiveChannel
+    pub prefix: Option<String>,
+
+    /// 从 `marker` 之后按字母排序的第一个 LiveChannel 开始返回
+    pub marker: Option<String>,
+
+    /// 返回的 LiveChannel 的最大个数。取值范围：1 ~ 1000，默认值：100
+    pub max_keys: Option<u32>,
+}
+
+/// Summary of a live channel in the list live channels result
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct LiveChannelSummary {
+    pub name: String,
+    pub description: String,
+    pub status: LiveChannelStatus,
+    pub last_modified: String,
+
+    /// 推流地址，例如：`rtmp://test-bucket.oss-cn-hangzhou.aliyuncs.com/live/channel-0`
+    pub publish_urls: Vec<String>,
+
+    /// 播放地址，例如：`http://test-bucket.oss-cn-hangzhou.aliyuncs.com/channel-0/playlist.m3u8`
+    pub play_urls: Vec<String>,
+}
+
+impl LiveChannelSummary {
+    pub(crate) fn from_xml_reader(reader: &mut quick_xml::Reader<&[u8]>) -> Result<Self> {
+        let mut tag = String::new();
+        let mut parent_tag = String::new();
+        let mut data = LiveChannelSummary::default();
+
+        loop {
+            match reader.read_event()? {
+                Event::Eof => break,
+                Event::Start(t) => {
+                    tag = String::from_utf8_lossy(t.local_name().as_ref()).to_string();
+                    if tag == "PublishUrls" || tag == "PlayUrls" {
+                        parent_tag = tag.clone();
+                    }
+                }
+                Event::Text(text) => {
+                    let s = text.unescape()?.trim().to_string();
+
+                    match (parent_tag.as_str(), tag.as_str()) {
+                        ("PublishUrls", "Url") => data.publish_urls.push(s),
+                        ("PlayUrls", "Url") => data.play_urls.push(s),
+                        (_, "Name") => data.name = s,
+                        (_, "Description") => data.description = s,
+                        (_, "Status") => data.status = LiveChannelStatus::try_from(s)?,
+                        (_, "LastModified") => data.last_modified = s,
+                        _ => {}
+                    }
+                }
+                Event::End(t) => {
+                    tag.clear();
+                    match t.local_name().as_ref() {
+                        b"PublishUrls" | b"PlayUrls" => parent_tag.clear(),
+                        b"LiveChannel" => break,
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(data)
+    }
+}
+
+/// List live channels result
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct ListLiveChannelsResult {
+    pub prefix: Option<String>,
+    pub marker: Option<String>,
+    pub max_keys: Option<u32>,
+    pub is_truncated: bool,
+    pub next_marker: Option<String>,
+    pub live_channels: Vec<LiveChannelSummary>,
+}
+
+impl ListLiveChannelsResult {
+    pub(crate) fn from_xml(xml: &str) -> Result<Self> {
+        let mut reader = quick_xml::Reader::from_str(xml);
+        let mut tag = String::new();
+        let mut ret = ListLiveChannelsResult::default();
+
+        loop {
+            match reader.read_event()? {
+                Event::Eof => break,
+                Event::Start(t) => match t.local_name().as_ref() {
+                    b"LiveChannel" => ret.live_channels.push(LiveChannelSummary::from_xml_reader(&mut reader)?),
+                    _ => tag = String::from_utf8_lossy(t.local_name().as_ref()).to_string(),
+                },
+                Event::Text(text) => {
+                    let s = text.unescape()?.trim().to_string();
+                    match tag.as_str() {
+                        "Prefix" => ret.prefix = if s.is_empty() { None } else { Some(s) },
+                        "Marker" => ret.marker = if s.is_empty() { None } else { Some(s) },
+                        "MaxKeys" => ret.max_keys = if s.is_empty() { None } else { Some(s.parse().unwrap_or_default()) },
+                        "IsTruncated" => ret.is_truncated = s == "true",
+                        "NextMarker" => ret.next_marker = if s.is_empty() { None } else { Some(s) },
+                        _ => {}
+                    }
+                }
+                Event::End(_) => tag.clear(),
+                _ => {}
+            }
+        }
+
+        Ok(ret)
+    }
+}
+
+pub(crate) fn build_list_live_channels_request(bucket_name: &str, options: &Option<ListLiveChannelsOptions>) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+    }
+
+    let mut request = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("live", "");
+
+    if let Some(options) = options {
+        if let Some(s) = &options.prefix {
+            request = request.add_query("prefix", s);
+        }
+
+        if let Some(s) = &options.marker {
+            request = request.add_query("marker", s);
+        }
+
+        if let Some(u) = options.max_keys {
+            if u == 0 || u > 1000 {
+                return Err(Error::Other(format!("invalid max-keys: {}. must between 1 and 1000", u)));
+            }
+            request = request.add_query("max-keys", u.to_string());
+        }
+    }
+
+    Ok(request)
+}
+
+#[cfg(test)]
+mod test_live_channel_common {
+    use crate::live_channel_common::{build_list_live_channels_request, ListLiveChannelsOptions, ListLiveChannelsResult, LiveChannelStatus};
+
+    #[test]
+    fn test_parse_list_live_channels_result() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListLiveChannelResult>
+  <Prefix></Prefix>
+  <Marker></Marker>
+  <MaxKeys>1</MaxKeys>
+  <IsTruncated>true</IsTruncated>
+  <NextMarker>channel-0</NextMarker>
+  <LiveChannel>
+    <Name>channel-0</Name>
+    <Description>test &amp; demo</Description>
+    <Status>disabled</Status>
+    <LastModified>2016-07-30T01:54:21.000Z</LastModified>
+    <PublishUrls>
+      <Url>rtmp://test-bucket.oss-cn-hangzhou.aliyuncs.com/live/channel-0</Url>
+    </PublishUrls>
+    <PlayUrls>
+      <Url>http://test-bucket.oss-cn-hangzhou.aliyuncs.com/channel-0/playlist.m3u8</Url>
+    </PlayUrls>
+  </LiveChannel>
+</ListLiveChannelResult>"#;
+
+        let ret = ListLiveChannelsResult::from_xml(xml).unwrap();
+        assert!(ret.prefix.is_none());
+        assert!(ret.marker.is_none());
+        assert_eq!(Some(1), ret.max_keys);
+        assert!(ret.is_truncated);
+        assert_eq!(Some("channel-0".to_string()), ret.next_marker);
+        assert_eq!(1, ret.live_channels.len());
+
+        let channel = &ret.live_channels[0];
+        assert_eq!("channel-0", channel.name);
+        assert_eq!("test & demo", channel.description);
+        assert_eq!(LiveChannelStatus::Disabled, channel.status);
+        assert_eq!("2016-07-30T01:54:21.000Z", channel.last_modified);
+        assert_eq!(
+            vec!["rtmp://test-bucket.oss-cn-hangzhou.aliyuncs.com/live/channel-0".to_string()],
+            channel.publish_urls
+        );
+        assert_eq!(
+            vec!["http://test-bucket.oss-cn-hangzhou.aliyuncs.com/channel-0/playlist.m3u8".to_string()],
+            channel.play_urls
+        );
+    }
+
+    #[test]
+    fn test_build_list_live_channels_request() {
+        let request = build_list_live_channels_request("test-bucket", &None).unwrap();
+        assert_eq!(Some(&"".to_string()), request.query.get("live"));
+
+        let options = ListLiveChannelsOptions {
+            prefix: Some("channel".to_string()),
+            marker: Some("channel-0".to_string()),
+            max_keys: Some(10),
+        };
+        let request = build_list_live_channels_request("test-bucket", &Some(options)).unwrap();
+        assert_eq!(Some(&"channel".to_string()), request.query.get("prefix"));
+        assert_eq!(Some(&"channel-0".to_string()), request.query.get("marker"));
+        assert_eq!(Some(&"10".to_string()), request.query.get("max-keys"));
+
+        let options = ListLiveChannelsOptions {
+            max_keys: Some(1001),
+            ..Default::default()
+        };
+        assert!(build_list_live_channels_request("test-bucket", &Some(options)).is_err());
+        assert!(build_list_live_channels_request("INVALID_BUCKET", &None).is_err());
+    }
+}
@@ -4,18 +4,21 @@ use std::{ops::Range, path::Path};
 
 use async_trait::async_trait;
 use base64::{prelude::BASE64_STANDARD, Engine};
+use futures::{StreamExt, TryStreamExt};
 
 use crate::{
     error::Error,
     multipart_common::{
-        build_complete_multipart_uploads_request, build_initiate_multipart_uploads_request, build_list_multipart_uploads_request, build_list_parts_request,
-        build_upload_part_copy_request, build_upload_part_request, CompleteMultipartUploadApiResponse, CompleteMultipartUploadOptions,
-        CompleteMultipartUploadRequest, CompleteMultipartUploadResult, InitiateMultipartUploadOptions, InitiateMultipartUploadResult,
-        ListMultipartUploadsOptions, ListMultipartUploadsResult, ListPartsOptions, ListPartsResult, UploadPartCopyOptions, UploadPartCopyRequest,
-        UploadPartCopyResult, UploadPartRequest, UploadPartResult,
+        build_abort_multipart_upload_request, build_complete_multipart_uploads_request, build_initiate_multipart_uploads_request,
+        build_list_multipart_uploads_request, build_list_parts_request, build_upload_file_parts, build_upload_part_copy_request, build_upload_part_request,
+        is_no_such_upload, upload_checkpoint_path, CompleteMultipartUploadOptions, CompleteMultipartUploadRequest, CompleteMultipartUploadResult,
+        InitiateMultipartUploadOptions, InitiateMultipartUploadResult, ListMultipartUploadsOptions, ListMultipartUploadsResult, ListPartsOptions,
+        ListPartsResult, ListPartsResultItem, UploadCheckpoint, UploadConfig, UploadPartCopyOptions, UploadPartCopyRequest, UploadPartCopyResult,
+        UploadPartRequest, UploadPartResult,
     },
-    request::{OssRequest, RequestMethod},
-    util::{validate_bucket_name, validate_object_key},
+    object::hash_request_body,
+    object_common::request_body_content_md5,
+    util::validate_bucket_name,
     Client, RequestBody, Result,
 };
 
@@ -128,6 +131,91 @@ pub trait MultipartUploadsOperations {
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send,
         S3: AsRef<str> + Send;
+
+    /// Upload a large file with multipart uploads: initiate, upload parts of `config.part_size` bytes,
+    /// at most `config.concurrency` parts at the same time, and complete. The multipart upload is aborted if any part fails.
+    ///
+    /// The callback in `options`, if any, is sent with complete multipart upload request.
+    /// The `timeout` in `options`, if any, applies to initiating the upload and to each part separately, not to the whole upload.
+    async fn upload_large_file<S1, S2, P>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        file_path: P,
+        config: UploadConfig,
+        options: Option<InitiateMultipartUploadOptions>,
+    ) -> Result<CompleteMultipartUploadResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        P: AsRef<Path> + Send;
+
+    /// Upload a large file like `upload_large_file`, but resumable. A checkpoint file `<file name>.oss-checkpoint.json`
+    /// is saved next to the source file after each part is uploaded. Calling it again after an interruption
+    /// reloads the checkpoint, reconciles it with the uploaded parts by ListParts, and uploads the missing parts only.
+    ///
+    /// Each part is recorded with its crc64, parts whose crc64 does not match the local file are uploaded again when resuming.
+    /// The checkpoint is invalid if the source file size or modification time changed, the multipart upload of it is aborted
+    /// and the upload starts over. The multipart upload is not aborted on failure so it can be resumed.
+    /// The checkpoint file is deleted after completion.
+    async fn upload_large_file_resumable<S1, S2, P>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        file_path: P,
+        config: UploadConfig,
+        options: Option<InitiateMultipartUploadOptions>,
+    ) -> Result<CompleteMultipartUploadResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        P: AsRef<Path> + Send;
+}
+
+impl Client {
+    /// List all parts of the multipart upload, page by page
+    async fn list_all_parts(&self, bucket_name: &str, object_key: &str, upload_id: &str) -> Result<Vec<ListPartsResultItem>> {
+        let mut options = ListPartsOptions::default();
+        let mut parts = vec![];
+
+        loop {
+            let ret = self.list_parts(bucket_name, object_key, upload_id, Some(options.clone())).await?;
+            parts.extend(ret.parts);
+
+            match (ret.next_part_number_marker, ret.is_truncated) {
+                (Some(marker), true) => options.part_number_marker = Some(marker),
+                _ => break,
+            }
+        }
+
+        Ok(parts)
+    }
+}
+
+/// Async version of `UploadCheckpoint::load`, reading the checkpoint file with `tokio::fs`
+async fn load_upload_checkpoint(path: &Path) -> Option<UploadCheckpoint> {
+    let data = tokio::fs::read(path).await.ok()?;
+    UploadCheckpoint::from_json(&data)
+}
+
+/// Run `UploadCheckpoint::verify_parts` in the blocking thread pool, since it reads the uploaded parts of the file
+async fn verify_upload_checkpoint_parts(mut checkpoint: UploadCheckpoint, file_path: &Path, all_parts: &[(u32, Range<u64>)]) -> Result<UploadCheckpoint> {
+    let file_path = file_path.to_path_buf();
+    let all_parts = all_parts.to_vec();
+
+    tokio::task::spawn_blocking(move || checkpoint.verify_parts(&file_path, &all_parts).map(|_| checkpoint))
+        .await
+        .map_err(|e| Error::Other(format!("verifying checkpoint parts failed: {}", e)))?
+}
+
+/// Async version of `UploadCheckpoint::save`, writing the checkpoint file with `tokio::fs`
+async fn save_upload_checkpoint(checkpoint: &UploadCheckpoint, path: &Path) -> Result<()> {
+    let tmp = UploadCheckpoint::temp_path(path);
+
+    tokio::fs::write(&tmp, serde_json::to_vec(checkpoint)?).await?;
+    tokio::fs::rename(&tmp, path).await?;
+
+    Ok(())
 }
 
 #[async_trait]
@@ -196,12 +284,15 @@ impl MultipartUploadsOperations for Client {
         S2: AsRef<str> + Send,
         P: AsRef<Path> + Send,
     {
-        let request = build_upload_part_request(
-            bucket_name.as_ref(),
-            object_key.as_ref(),
-            RequestBody::File(file_path.as_ref().to_path_buf(), Some(range)),
-            params,
-        )?;
+        let body = RequestBody::File(file_path.as_ref().to_path_buf(), Some(range));
+
+        // 在阻塞线程池中计算文件的 MD5，避免在异步运行时中读取文件
+        let mut params = params;
+        if params.content_md5.is_none() && params.compute_content_md5 {
+            params.content_md5 = Some(hash_request_body(&body, request_body_content_md5).await?);
+        }
+
+        let request = build_upload_part_request(bucket_name.as_ref(), object_key.as_ref(), body, params)?;
 
         let (headers, _) = self.do_request::<()>(request).await?;
 
@@ -217,7 +308,12 @@ impl MultipartUploadsOperations for Client {
         S2: AsRef<str> + Send,
         B: Into<Vec<u8>> + Send,
     {
-        let request = build_upload_part_request(bucket_name.as_ref(), object_key.as_ref(), RequestBody::Bytes(buffer.into()), params)?;
+        let request = build_upload_part_request(
+            bucket_name.as_ref(),
+            object_key.as_ref(),
+            RequestBody::Bytes(bytes::Bytes::from(buffer.into())),
+            params,
+        )?;
 
         let (headers, _) = self.do_request::<()>(request).await?;
 
@@ -282,16 +378,13 @@ impl MultipartUploadsOperations for Client {
     {
         let with_callback = if let Some(opt) = &options { opt.callback.is_some() } else { false };
 
-        let request = build_complete_multipart_uploads_request(bucket_name.as_ref(), object_key.as_ref(), data, &options)?;
-        let (_, content) = self.do_request::<String>(request).await?;
+        let bucket_name = bucket_name.as_ref();
+        let object_key = object_key.as_ref();
 
-        if with_callback {
-            Ok(CompleteMultipartUploadResult::CallbackResponse(content))
-        } else {
-            Ok(CompleteMultipartUploadResult::ApiResponse(CompleteMultipartUploadApiResponse::from_xml(
-                &content,
-            )?))
-        }
+        let request = build_complete_multipart_uploads_request(bucket_name, object_key, data, &options)?;
+        let (headers, content) = self.do_request::<String>(request).await?;
+
+        CompleteMultipartUploadResult::from_response(bucket_name, object_key, with_callback, headers, content)
     }
 
     /// About multipart uploads
@@ -302,31 +395,192 @@ impl MultipartUploadsOperations for Client {
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send,
         S3: AsRef<str> + Send,
+    {
+        let request = build_abort_multipart_upload_request(bucket_name.as_ref(), object_key.as_ref(), upload_id.as_ref())?;
+
+        self.do_request::<()>(request).await?;
+
+        Ok(())
+    }
+
+    /// Upload a large file with multipart uploads: initiate, upload parts of `config.part_size` bytes,
+    /// at most `config.concurrency` parts at the same time, and complete. The multipart upload is aborted if any part fails.
+    ///
+    /// The callback in `options`, if any, is sent with complete multipart upload request.
+    /// The `timeout` in `options`, if any, applies to initiating the upload and to each part separately, not to the whole upload.
+    async fn upload_large_file<S1, S2, P>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        file_path: P,
+        config: UploadConfig,
+        options: Option<InitiateMultipartUploadOptions>,
+    ) -> Result<CompleteMultipartUploadResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        P: AsRef<Path> + Send,
     {
         let bucket_name = bucket_name.as_ref();
         let object_key = object_key.as_ref();
+        let file_path = file_path.as_ref();
+
+        let file_size = tokio::fs::metadata(file_path).await?.len();
+        let parts = build_upload_file_parts(file_size, &config)?;
+
+        let callback = options.as_ref().and_then(|opt| opt.callback.clone());
+        let timeout = options.as_ref().and_then(|opt| opt.timeout);
+
+        let upload_id = self.initiate_multipart_uploads(bucket_name, object_key, options).await?.upload_id;
+
+        let uploaded = futures::stream::iter(parts)
+            .map(|(part_number, range)| {
+                let params = UploadPartRequest {
+                    timeout,
+                    ..UploadPartRequest::new(part_number, &upload_id)
+                };
+                async move {
+                    let ret = self.upload_part_from_file(bucket_name, object_key, file_path, range, params).await?;
+                    Ok::<_, Error>((part_number, ret.etag))
+                }
+            })
+            .buffer_unordered(config.concurrency.max(1))
+            .try_collect::<Vec<_>>()
+            .await;
+
+        let mut parts = match uploaded {
+            Ok(parts) => parts,
+            Err(e) => {
+                // 尽力清理已经上传的分片，清理失败时返回原始的错误
+                let _ = self.abort_multipart_uploads(bucket_name, object_key, &upload_id).await;
+                return Err(e);
+            }
+        };
+
+        parts.sort_by_key(|(part_number, _)| *part_number);
 
-        if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        let data = CompleteMultipartUploadRequest {
+            upload_id: upload_id.clone(),
+            parts,
+        };
+
+        let options = callback.map(|callback| CompleteMultipartUploadOptions { callback: Some(callback) });
+
+        match self.complete_multipart_uploads(bucket_name, object_key, data, options).await {
+            Ok(ret) => Ok(ret),
+            Err(e) => {
+                let _ = self.abort_multipart_uploads(bucket_name, object_key, &upload_id).await;
+                Err(e)
+            }
         }
+    }
 
-        if !validate_object_key(object_key) {
-            return Err(Error::Other(format!("invalid object key: {}", object_key)));
+    /// Upload a large file like `upload_large_file`, but resumable. A checkpoint file `<file name>.oss-checkpoint.json`
+    /// is saved next to the source file after each part is uploaded. Calling it again after an interruption
+    /// reloads the checkpoint, reconciles it with the uploaded parts by ListParts, and uploads the missing parts only.
+    ///
+    /// Each part is recorded with its crc64, parts whose crc64 does not match the local file are uploaded again when resuming.
+    /// The checkpoint is invalid if the source file size or modification time changed, the multipart upload of it is aborted
+    /// and the upload starts over. The multipart upload is not aborted on failure so it can be resumed.
+    /// The checkpoint file is deleted after completion.
+    async fn upload_large_file_resumable<S1, S2, P>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        file_path: P,
+        config: UploadConfig,
+        options: Option<InitiateMultipartUploadOptions>,
+    ) -> Result<CompleteMultipartUploadResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        P: AsRef<Path> + Send,
+    {
+        let bucket_name = bucket_name.as_ref();
+        let object_key = object_key.as_ref();
+        let file_path = file_path.as_ref();
+
+        let meta = tokio::fs::metadata(file_path).await?;
+        let all_parts = build_upload_file_parts(meta.len(), &config)?;
+        let checkpoint_path = upload_checkpoint_path(file_path);
+
+        let callback = options.as_ref().and_then(|opt| opt.callback.clone());
+        let timeout = options.as_ref().and_then(|opt| opt.timeout);
+
+        let mut checkpoint = None;
+
+        if let Some(mut cp) = load_upload_checkpoint(&checkpoint_path).await {
+            if cp.matches(bucket_name, object_key, config.part_size, &meta) {
+                match self.list_all_parts(bucket_name, object_key, &cp.upload_id).await {
+                    Ok(uploaded) => {
+                        cp.reconcile(&uploaded, &all_parts);
+                        checkpoint = Some(verify_upload_checkpoint_parts(cp, file_path, &all_parts).await?);
+                    }
+                    // 分片上传已经完成或者被取消，重新开始
+                    Err(e) if is_no_such_upload(&e) => {}
+                    Err(e) => return Err(e),
+                }
+            } else {
+                // 检查点失效，取消旧的分片上传，否则已经上传的分片会一直占用存储空间
+                match self.abort_multipart_uploads(&cp.bucket, &cp.object_key, &cp.upload_id).await {
+                    Ok(_) => {}
+                    Err(e) if is_no_such_upload(&e) => {}
+                    Err(e) => return Err(e),
+                }
+            }
         }
 
-        if upload_id.as_ref().is_empty() {
-            return Err(Error::Other("invalid upload id: [empty]".to_string()));
+        let mut checkpoint = match checkpoint {
+            Some(cp) => cp,
+            None => {
+                let upload_id = self.initiate_multipart_uploads(bucket_name, object_key, options).await?.upload_id;
+                UploadCheckpoint::new(bucket_name, object_key, &upload_id, config.part_size, &meta)?
+            }
+        };
+
+        save_upload_checkpoint(&checkpoint, &checkpoint_path).await?;
+
+        let upload_id = checkpoint.upload_id.clone();
+
+        let mut results = futures::stream::iter(checkpoint.pending_parts(all_parts))
+            .map(|(part_number, range)| {
+                let params = UploadPartRequest {
+                    timeout,
+                    ..UploadPartRequest::new(part_number, &upload_id)
+                };
+                async move { (part_number, self.upload_part_from_file(bucket_name, object_key, file_path, range, params).await) }
+            })
+            .buffer_unordered(config.concurrency.max(1));
+
+        let mut error = None;
+
+        while let Some((part_number, ret)) = results.next().await {
+            match ret {
+                Ok(ret) => {
+                    checkpoint.add_part(part_number, ret);
+                    save_upload_checkpoint(&checkpoint, &checkpoint_path).await?;
+                }
+                Err(e) => {
+                    error = Some(e);
+                    break;
+                }
+            }
         }
 
-        let request = OssRequest::new()
-            .method(RequestMethod::Delete)
-            .bucket(bucket_name)
-            .object(object_key)
-            .add_query("uploadId", upload_id);
+        drop(results);
 
-        self.do_request::<()>(request).await?;
+        if let Some(e) = error {
+            return Err(e);
+        }
 
-        Ok(())
+        let options = callback.map(|callback| CompleteMultipartUploadOptions { callback: Some(callback) });
+        let ret = self
+            .complete_multipart_uploads(bucket_name, object_key, checkpoint.complete_request(), options)
+            .await?;
+
+        let _ = tokio::fs::remove_file(&checkpoint_path).await;
+
+        Ok(ret)
     }
 }
 
@@ -342,8 +596,8 @@ mod test_multipart_async {
 
     use crate::{
         Client, multipart::MultipartUploadsOperations, multipart_common::{
-            CompleteMultipartUploadOptions, CompleteMultipartUploadRequest, CompleteMultipartUploadResult, InitiateMultipartUploadOptionsBuilder, UploadPartCopyOptionsBuilder, UploadPartCopyRequest, UploadPartRequest
-        }, object::ObjectOperations, object_common::{CallbackBodyParameter, CallbackBuilder}
+            CompleteMultipartUploadOptions, CompleteMultipartUploadRequest, CompleteMultipartUploadResult, InitiateMultipartUploadOptionsBuilder, UploadPartCopyOptionsBuilder, UploadPartCopyRequest, UploadPartRequest, UploadConfig, UploadCheckpoint, MIN_PART_SIZE, upload_checkpoint_path
+        }, object::ObjectOperations, object_common::{CallbackBodyParameter, CallbackBuilder}, util
     };
 
     static INIT: Once = Once::new();
@@ -442,10 +696,7 @@ mod test_multipart_async {
         let mut upload_results = vec![];
 
         for (i, rng) in ranges.iter().enumerate() {
-            let upload_data = UploadPartRequest {
-                part_number: (i + 1) as u32,
-                upload_id: upload_id.clone(),
-            };
+            let upload_data = UploadPartRequest::new((i + 1) as u32, &upload_id).compute_content_md5(true);
 
             log::debug!("begin to upload part {}", i);
 
@@ -540,10 +791,7 @@ mod test_multipart_async {
             let mut partial = stream.take(rng.end - rng.start);
             partial.read_to_end(&mut buf).unwrap();
 
-            let upload_data = UploadPartRequest {
-                part_number: part_no,
-                upload_id: upload_id.clone(),
-            };
+            let upload_data = UploadPartRequest::new(part_no, &upload_id);
 
             let upload_response = client.upload_part_from_buffer(bucket, &object, buf, upload_data).await;
 
@@ -671,10 +919,7 @@ mod test_multipart_async {
         let mut upload_results = vec![];
 
         for (i, rng) in ranges.iter().enumerate() {
-            let upload_data = UploadPartRequest {
-                part_number: (i + 1) as u32,
-                upload_id: upload_id.clone(),
-            };
+            let upload_data = UploadPartRequest::new((i + 1) as u32, &upload_id);
 
             log::debug!("begin to upload part {}", i);
 
@@ -735,7 +980,7 @@ mod test_multipart_async {
 
         let ret = comp_response.unwrap();
 
-        if let CompleteMultipartUploadResult::CallbackResponse(s) = ret {
+        if let CompleteMultipartUploadResult::CallbackResponse(s, _) = ret {
             assert!(s.contains(&serde_json::to_string(&object).unwrap()));
         } else {
             panic!("no callback json content returned");
@@ -743,6 +988,67 @@ mod test_multipart_async {
 
         client.delete_object(bucket, &object, None).await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_upload_large_file_resumable_async() {
+        setup();
+
+        let client = Client::from_env();
+
+        let bucket = "yuanyq";
+        let object = format!("rust-sdk-test/large-file-{}.bin", Uuid::new_v4());
+
+        let file = std::env::temp_dir().join(format!("ali-oss-rs-large-file-{}.bin", Uuid::new_v4()));
+        let content = (0..MIN_PART_SIZE * 3 + 1234).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+        std::fs::write(&file, &content).unwrap();
+
+        // 模拟上传中断：先上传第一个分片并保存检查点
+        let meta = std::fs::metadata(&file).unwrap();
+        let upload_id = client.initiate_multipart_uploads(bucket, &object, None).await.unwrap().upload_id;
+        let ret = client
+            .upload_part_from_file(bucket, &object, &file, 0..MIN_PART_SIZE, UploadPartRequest::new(1, &upload_id))
+            .await
+            .unwrap();
+        let mut checkpoint = UploadCheckpoint::new(bucket, &object, &upload_id, MIN_PART_SIZE, &meta).unwrap();
+        checkpoint.add_part(1, ret);
+        checkpoint.save(&upload_checkpoint_path(&file)).unwrap();
+
+        let config = UploadConfig::new(MIN_PART_SIZE, 2);
+        let ret = client.upload_large_file_resumable(bucket, &object, &file, config, None).await;
+        assert!(ret.is_ok());
+        assert!(!upload_checkpoint_path(&file).exists());
+        std::fs::remove_file(&file).unwrap();
+
+        let data = client.get_object_to_buffer(bucket, &object, None).await.unwrap();
+        assert_eq!(content, data);
+
+        client.delete_object(bucket, &object, None).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_upload_large_file_async() {
+        setup();
+
+        let client = Client::from_env();
+
+        let bucket = "yuanyq";
+        let object = format!("rust-sdk-test/large-file-{}.bin", Uuid::new_v4());
+
+        let file = std::env::temp_dir().join(format!("ali-oss-rs-large-file-{}.bin", Uuid::new_v4()));
+        let content = (0..MIN_PART_SIZE * 3 + 1234).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+        std::fs::write(&file, &content).unwrap();
+
+        let config = UploadConfig::new(MIN_PART_SIZE, 2);
+        let ret = client.upload_large_file(bucket, &object, &file, config, None).await;
+        std::fs::remove_file(&file).unwrap();
+        assert!(ret.is_ok());
+        assert!(util::is_multipart_etag(&ret.unwrap().api_response().etag));
+
+        let data = client.get_object_to_buffer(bucket, &object, None).await.unwrap();
+        assert_eq!(content, data);
+
+        client.delete_object(bucket, &object, None).await.unwrap();
+    }
 }
 
 #[cfg(test)]
@@ -1,22 +1,66 @@
 //! Multipart upload types
 
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    ops::Range,
+    path::{Path, PathBuf},
+    time::{Duration, UNIX_EPOCH},
+};
 
 use base64::{prelude::BASE64_STANDARD, Engine};
 use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
 
 use crate::{
     common,
-    error::Error,
-    object_common::{build_put_object_request, Callback, PutObjectOptions, PutObjectOptionsBuilder},
+    error::{Error, OssErrorCode},
+    object_common::{
+        build_put_object_request, encode_copy_source, request_body_content_md5, request_body_crc64, response_crc64, ByteRange, Callback, PutObjectOptions,
+        PutObjectOptionsBuilder,
+    },
     request::{OssRequest, RequestMethod},
-    util::{sanitize_etag, validate_bucket_name, validate_object_key},
+    util::{explain_invalid_object_key, part_ranges, sanitize_etag, validate_bucket_name, validate_object_key},
     RequestBody, Result,
 };
 
 pub type InitiateMultipartUploadOptions = PutObjectOptions;
 pub type InitiateMultipartUploadOptionsBuilder = PutObjectOptionsBuilder;
 
+/// 分片的最小大小（最后一个分片除外）：100KB
+pub const MIN_PART_SIZE: u64 = 100 * 1024;
+
+/// 分片的最大大小：5GB
+pub const MAX_PART_SIZE: u64 = 5 * 1024 * 1024 * 1024;
+
+/// 一次分片上传最多的分片数量
+pub const MAX_PART_COUNT: u64 = 10000;
+
+/// Config for uploading large file with multipart uploads
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct UploadConfig {
+    /// 每个分片的大小，取值范围是 `[100KB, 5GB]`，最后一个分片可以小于这个值。默认为 8MB
+    pub part_size: u64,
+
+    /// 同时上传的分片数量。默认为 4
+    pub concurrency: usize,
+}
+
+impl Default for UploadConfig {
+    fn default() -> Self {
+        Self {
+            part_size: 8 * 1024 * 1024,
+            concurrency: 4,
+        }
+    }
+}
+
+impl UploadConfig {
+    pub fn new(part_size: u64, concurrency: usize) -> Self {
+        Self { part_size, concurrency }
+    }
+}
+
 /// Initiate mutlipart upload result
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
@@ -72,6 +116,17 @@ pub struct UploadPartRequest {
 
     /// The upload id returned from InitiateMultipartUpload
     pub upload_id: String,
+
+    /// 当前 Part 内容的 MD5 摘要算法结果的 base64 字符串。
+    /// 设置后会作为 `content-md5` 请求头发送，OSS 会计算收到的 Part 内容的 MD5 并检查一致性，不一致时上传失败。
+    pub content_md5: Option<String>,
+
+    /// 如果为 `true` 并且没有指定 `content_md5`，则在发送请求前根据 Part 的内容（文件的指定范围或者内存数据）计算 `content-md5`。
+    /// 对于从文件上传的 Part，需要额外读取一次该范围的文件内容。默认为 `false`
+    pub compute_content_md5: bool,
+
+    /// 本次请求的超时时间，覆盖客户端的 `timeout`
+    pub timeout: Option<Duration>,
 }
 
 impl UploadPartRequest {
@@ -82,8 +137,27 @@ impl UploadPartRequest {
         Self {
             part_number,
             upload_id: upload_id.as_ref().to_string(),
+            content_md5: None,
+            compute_content_md5: false,
+            timeout: None,
         }
     }
+
+    pub fn content_md5(mut self, content_md5: impl Into<String>) -> Self {
+        self.content_md5 = Some(content_md5.into());
+        self
+    }
+
+    pub fn compute_content_md5(mut self, compute_content_md5: bool) -> Self {
+        self.compute_content_md5 = compute_content_md5;
+        self
+    }
+
+    /// Total timeout of this request, overriding the timeout of the client
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
 }
 
 /// Upload part result.
@@ -95,6 +169,9 @@ pub struct UploadPartResult {
 
     /// Used when call `CompleteMultipartUpload`
     pub etag: String,
+
+    /// 响应头 `x-oss-hash-crc64ecma` 中的 Part 内容的 CRC64
+    pub crc64: Option<u64>,
 }
 
 impl From<HashMap<String, String>> for UploadPartResult {
@@ -102,6 +179,7 @@ impl From<HashMap<String, String>> for UploadPartResult {
         Self {
             request_id: headers.remove("x-oss-request-id").unwrap_or_default(),
             etag: sanitize_etag(headers.remove("etag").unwrap_or_default()),
+            crc64: response_crc64(&headers),
         }
     }
 }
@@ -124,8 +202,12 @@ pub struct UploadPartCopyRequest {
     /// The upload id returned from InitiateMultipartUpload
     pub upload_id: String,
 
-    /// The object key **without** bucket part because UploadPartCopy can copy objects in the same bucket only. e.g. `path/to/sub-path/obj_key.zip`.
+    /// The object key **without** bucket part. e.g. `path/to/sub-path/obj_key.zip`.
     pub source_object_key: String,
+
+    /// 源 Object 所在的 Bucket，为 `None` 时表示和目标 Object 在同一个 Bucket。
+    /// 源 Bucket 和目标 Bucket 必须在同一个地域
+    pub source_bucket_name: Option<String>,
 }
 
 impl UploadPartCopyRequest {
@@ -138,8 +220,15 @@ impl UploadPartCopyRequest {
             part_number,
             upload_id: upload_id.as_ref().to_string(),
             source_object_key: source_object_key.as_ref().to_string(),
+            source_bucket_name: None,
         }
     }
+
+    /// Copy from an object in another bucket of the same region
+    pub fn source_bucket(mut self, source_bucket_name: impl Into<String>) -> Self {
+        self.source_bucket_name = Some(source_bucket_name.into());
+        self
+    }
 }
 
 /// Other options for upload part copy
@@ -157,6 +246,11 @@ pub struct UploadPartCopyOptions {
     /// - 当指定的范围不符合规范时，则拷贝整个源 Object
     pub copy_source_range: Option<String>,
 
+    /// 源 Object 的大小。
+    /// 指定后，构造请求时会检查 `copy_source_range` 是否超出了源 Object 的范围，
+    /// 避免 OSS 因为范围不合法而拷贝整个源 Object，导致分片拷贝的结果错误
+    pub source_size: Option<u64>,
+
     pub copy_source_if_match: Option<String>,
     pub copy_source_if_none_match: Option<String>,
     pub copy_source_if_unmodified_since: Option<String>,
@@ -183,6 +277,20 @@ impl UploadPartCopyOptionsBuilder {
         self
     }
 
+    /// Set copy source range by byte offsets. Both `start` and `end` are inclusive,
+    /// the range is formatted as `bytes=start-end` and validated while building request.
+    /// Open-ended and suffix ranges are not accepted by upload part copy, so only a `start`, `end` pair is taken here
+    pub fn copy_source_byte_range(mut self, start: u64, end: u64) -> Self {
+        self.options.copy_source_range = Some(ByteRange::FromTo(start, end).to_header_value());
+        self
+    }
+
+    /// Set the source object size to check copy source range against
+    pub fn source_size(mut self, size: u64) -> Self {
+        self.options.source_size = Some(size);
+        self
+    }
+
     pub fn copy_source_if_match<S: Into<String>>(mut self, etag: S) -> Self {
         self.options.copy_source_if_match = Some(etag.into());
         self
@@ -307,8 +415,62 @@ pub enum CompleteMultipartUploadResult {
 
     /// This is your callback response content string when you put object with callback specified.
     /// `.0` should be a valid JSON string.
+    /// If OSS failed to call your callback server, it responds with status `203` and `.0` is the `CallbackFailed` error XML.
+    /// The object is stored anyway. Use `callback_failed()` to check this case.
+    /// `.1` is the information of the completed object. The bucket and key are the ones in the request,
+    /// the etag and version id are taken from the response headers.
     #[cfg_attr(feature = "serde-camelcase", serde(rename = "callbackResponse"))]
-    CallbackResponse(String),
+    CallbackResponse(String, CompleteMultipartUploadApiResponse),
+}
+
+impl CompleteMultipartUploadResult {
+    /// Build the result from the response of complete multipart upload.
+    /// With callback specified, the response body is the callback response instead of the `CompleteMultipartUploadResult` XML
+    pub(crate) fn from_response(
+        bucket_name: &str,
+        object_key: &str,
+        with_callback: bool,
+        mut headers: HashMap<String, String>,
+        content: String,
+    ) -> Result<Self> {
+        if with_callback {
+            let data = CompleteMultipartUploadApiResponse {
+                bucket: bucket_name.to_string(),
+                key: object_key.to_string(),
+                etag: sanitize_etag(headers.remove("etag").unwrap_or_default()),
+                version_id: headers.remove("x-oss-version-id"),
+                request_id: headers.remove("x-oss-request-id").unwrap_or_default(),
+            };
+            Ok(CompleteMultipartUploadResult::CallbackResponse(content, data))
+        } else {
+            let mut data = CompleteMultipartUploadApiResponse::from_xml(&content)?;
+            data.version_id = headers.remove("x-oss-version-id");
+            data.request_id = headers.remove("x-oss-request-id").unwrap_or_default();
+            Ok(CompleteMultipartUploadResult::ApiResponse(data))
+        }
+    }
+
+    /// The information of the completed object, no matter callback is specified or not.
+    pub fn api_response(&self) -> &CompleteMultipartUploadApiResponse {
+        match self {
+            CompleteMultipartUploadResult::ApiResponse(r) => r,
+            CompleteMultipartUploadResult::CallbackResponse(_, r) => r,
+        }
+    }
+
+    /// The version id of the completed object.
+    /// Only available when the bucket has versioning enabled.
+    pub fn version_id(&self) -> Option<&str> {
+        self.api_response().version_id.as_deref()
+    }
+
+    /// Whether the object is completed but OSS failed to call your callback server (HTTP status `203`).
+    pub fn callback_failed(&self) -> bool {
+        match self {
+            CompleteMultipartUploadResult::ApiResponse(_) => false,
+            CompleteMultipartUploadResult::CallbackResponse(s, _) => common::is_callback_failed(s),
+        }
+    }
 }
 
 /// Request data for complete multipart upload
@@ -319,6 +481,12 @@ pub struct CompleteMultipartUploadApiResponse {
     pub bucket: String,
     pub key: String,
     pub etag: String,
+
+    /// 目标 Bucket 开启了版本控制时，合并生成的 Object 的版本 ID
+    pub version_id: Option<String>,
+
+    /// 响应头 `x-oss-request-id`
+    pub request_id: String,
 }
 
 impl CompleteMultipartUploadApiResponse {
@@ -640,11 +808,14 @@ pub(crate) fn build_initiate_multipart_uploads_request(
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
     }
 
     let mut request = build_put_object_request(bucket_name, object_key, RequestBody::Empty, options)?;
 
+    // `content-md5` 是上传内容的摘要，初始化分片上传时没有请求体，不发送
+    request.headers_mut().remove("content-md5");
+
     request = request
         .method(RequestMethod::Post)
         .bucket(bucket_name)
@@ -654,16 +825,244 @@ pub(crate) fn build_initiate_multipart_uploads_request(
     Ok(request)
 }
 
+pub(crate) fn build_abort_multipart_upload_request(bucket_name: &str, object_key: &str, upload_id: &str) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+    }
+
+    if !validate_object_key(object_key) {
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
+    }
+
+    if upload_id.is_empty() {
+        return Err(Error::Other("invalid upload id: [empty]".to_string()));
+    }
+
+    Ok(OssRequest::new()
+        .method(RequestMethod::Delete)
+        .bucket(bucket_name)
+        .object(object_key)
+        .add_query("uploadId", upload_id))
+}
+
+/// Split a file of `file_size` bytes into `(part_number, range)` for uploading large file.
+/// `range` is half-open, which is the same as `RequestBody::File`
+pub(crate) fn build_upload_file_parts(file_size: u64, config: &UploadConfig) -> Result<Vec<(u32, Range<u64>)>> {
+    if !(MIN_PART_SIZE..=MAX_PART_SIZE).contains(&config.part_size) {
+        return Err(Error::Other(format!(
+            "invalid part size: {}. must be between {} and {}",
+            config.part_size, MIN_PART_SIZE, MAX_PART_SIZE
+        )));
+    }
+
+    if file_size == 0 {
+        return Err(Error::Other(
+            "can not upload empty file with multipart uploads, use put object instead".to_string(),
+        ));
+    }
+
+    if file_size.div_ceil(config.part_size) > MAX_PART_COUNT {
+        return Err(Error::Other(format!(
+            "too many parts: file size {} with part size {} exceeds {} parts",
+            file_size, config.part_size, MAX_PART_COUNT
+        )));
+    }
+
+    Ok(part_ranges(file_size, config.part_size)
+        .into_iter()
+        .map(|(part_number, start, end)| (part_number, start..end + 1))
+        .collect())
+}
+
+/// Checkpoint file of resumable upload: `<file name>.oss-checkpoint.json` in the same directory of the source file
+pub(crate) fn upload_checkpoint_path(file_path: &Path) -> PathBuf {
+    let mut name = file_path.file_name().map(|s| s.to_os_string()).unwrap_or_default();
+    name.push(".oss-checkpoint.json");
+    file_path.with_file_name(name)
+}
+
+fn file_mtime_millis(meta: &std::fs::Metadata) -> Result<u64> {
+    let mtime = meta.modified()?.duration_since(UNIX_EPOCH).map_err(|e| Error::Other(e.to_string()))?;
+    Ok(mtime.as_millis() as u64)
+}
+
+/// Uploaded part recorded in checkpoint
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct UploadCheckpointPart {
+    pub part_number: u32,
+    pub etag: String,
+
+    /// 上传分片时响应头中的 CRC64，恢复上传时和本地文件对应分片的 CRC64 比较。
+    /// 从 ListParts 结果中补充的分片没有该值
+    #[serde(default)]
+    pub crc64: Option<u64>,
+}
+
+/// 断点续传上传的检查点。以 JSON 格式保存在源文件旁边，重试时加载并跳过已经上传的分片。
+/// 源文件的大小或者修改时间变化后，检查点失效，需要重新上传
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct UploadCheckpoint {
+    pub bucket: String,
+    pub object_key: String,
+    pub upload_id: String,
+    pub part_size: u64,
+    pub file_size: u64,
+
+    /// 源文件的修改时间，Unix 时间戳（毫秒）
+    pub file_mtime: u64,
+    pub parts: Vec<UploadCheckpointPart>,
+}
+
+impl UploadCheckpoint {
+    pub fn new(bucket_name: &str, object_key: &str, upload_id: &str, part_size: u64, meta: &std::fs::Metadata) -> Result<Self> {
+        Ok(Self {
+            bucket: bucket_name.to_string(),
+            object_key: object_key.to_string(),
+            upload_id: upload_id.to_string(),
+            part_size,
+            file_size: meta.len(),
+            file_mtime: file_mtime_millis(meta)?,
+            parts: vec![],
+        })
+    }
+
+    /// Load checkpoint from file. Returns `None` if the file does not exist or is broken.
+    /// Use `matches` to check if it is still valid for the upload
+    #[cfg(any(feature = "blocking", test))]
+    pub fn load(path: &Path) -> Option<Self> {
+        Self::from_json(&std::fs::read(path).ok()?)
+    }
+
+    /// Parse checkpoint from the content of checkpoint file
+    pub fn from_json(data: &[u8]) -> Option<Self> {
+        serde_json::from_slice::<Self>(data).ok().filter(|cp| !cp.upload_id.is_empty())
+    }
+
+    /// If the checkpoint matches the upload: destination, part size, source file size and modification time
+    pub fn matches(&self, bucket_name: &str, object_key: &str, part_size: u64, meta: &std::fs::Metadata) -> bool {
+        self.bucket == bucket_name
+            && self.object_key == object_key
+            && self.part_size == part_size
+            && self.file_size == meta.len()
+            && file_mtime_millis(meta).is_ok_and(|t| t == self.file_mtime)
+    }
+
+    /// Write to a temporary file first and rename it, so the checkpoint is not broken if the process is killed while saving
+    #[cfg(any(feature = "blocking", test))]
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let tmp = Self::temp_path(path);
+
+        std::fs::write(&tmp, serde_json::to_vec(self)?)?;
+        std::fs::rename(&tmp, path)?;
+
+        Ok(())
+    }
+
+    /// The temporary file which the checkpoint is written to before renaming
+    pub fn temp_path(path: &Path) -> PathBuf {
+        let mut tmp = path.as_os_str().to_os_string();
+        tmp.push(".tmp");
+        PathBuf::from(tmp)
+    }
+
+    /// Keep the parts which are listed by ListParts with the expected size only.
+    /// Parts uploaded but not recorded (e.g. killed before saving the checkpoint) are taken too
+    pub fn reconcile(&mut self, uploaded: &[ListPartsResultItem], all_parts: &[(u32, Range<u64>)]) {
+        let expected_sizes = all_parts.iter().map(|(n, r)| (*n, r.end - r.start)).collect::<HashMap<_, _>>();
+
+        let mut parts = uploaded
+            .iter()
+            .filter(|p| expected_sizes.get(&p.part_number) == Some(&p.size))
+            .map(|p| UploadCheckpointPart {
+                part_number: p.part_number,
+                etag: p.etag.clone(),
+                crc64: self
+                    .parts
+                    .iter()
+                    .find(|cp| cp.part_number == p.part_number && cp.etag == p.etag)
+                    .and_then(|cp| cp.crc64),
+            })
+            .collect::<Vec<_>>();
+
+        parts.sort_by_key(|p| p.part_number);
+        self.parts = parts;
+    }
+
+    /// Drop the parts whose crc64 does not match the same range of the local file, so they are uploaded again.
+    /// Parts without crc64 are kept. Reads the file, the async client calls this in `spawn_blocking`
+    pub fn verify_parts(&mut self, file_path: &Path, all_parts: &[(u32, Range<u64>)]) -> Result<()> {
+        let ranges = all_parts.iter().cloned().collect::<HashMap<_, _>>();
+        let mut parts = Vec::with_capacity(self.parts.len());
+
+        for part in std::mem::take(&mut self.parts) {
+            if let (Some(crc64), Some(range)) = (part.crc64, ranges.get(&part.part_number)) {
+                let local = request_body_crc64(&RequestBody::File(file_path.to_path_buf(), Some(range.clone())))?;
+                if local != crc64 {
+                    oss_log!(
+                        warn,
+                        "crc64 of part {} mismatched, expected: {}, local: {}. upload it again",
+                        part.part_number,
+                        crc64,
+                        local
+                    );
+                    continue;
+                }
+            }
+
+            parts.push(part);
+        }
+
+        self.parts = parts;
+        Ok(())
+    }
+
+    pub fn add_part(&mut self, part_number: u32, result: UploadPartResult) {
+        self.parts.retain(|p| p.part_number != part_number);
+        self.parts.push(UploadCheckpointPart {
+            part_number,
+            etag: result.etag,
+            crc64: result.crc64,
+        });
+    }
+
+    /// The parts not uploaded yet
+    pub fn pending_parts(&self, all_parts: Vec<(u32, Range<u64>)>) -> Vec<(u32, Range<u64>)> {
+        all_parts.into_iter().filter(|(n, _)| !self.parts.iter().any(|p| p.part_number == *n)).collect()
+    }
+
+    pub fn complete_request(&self) -> CompleteMultipartUploadRequest {
+        let mut parts = self.parts.iter().map(|p| (p.part_number, p.etag.clone())).collect::<Vec<_>>();
+        parts.sort_by_key(|(n, _)| *n);
+
+        CompleteMultipartUploadRequest {
+            upload_id: self.upload_id.clone(),
+            parts,
+        }
+    }
+}
+
+pub(crate) fn is_no_such_upload(e: &Error) -> bool {
+    e.oss_error_code() == Some(OssErrorCode::NoSuchUpload)
+}
+
 pub(crate) fn build_upload_part_request(bucket_name: &str, object_key: &str, body: RequestBody, params: UploadPartRequest) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
         return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
     }
 
-    let UploadPartRequest { part_number, upload_id } = params;
+    let UploadPartRequest {
+        part_number,
+        upload_id,
+        content_md5,
+        compute_content_md5,
+        timeout,
+    } = params;
 
     if !(1..=10000).contains(&part_number) {
         return Err(Error::Other(format!(
@@ -676,15 +1075,56 @@ pub(crate) fn build_upload_part_request(bucket_name: &str, object_key: &str, bod
         return Err(Error::Other("invalid upload id. upload id must not be empty".to_string()));
     }
 
-    let request = OssRequest::new()
+    let content_md5 = match content_md5 {
+        Some(s) => Some(s),
+        None if compute_content_md5 => Some(request_body_content_md5(&body)?),
+        None => None,
+    };
+
+    let mut request = OssRequest::new()
         .method(RequestMethod::Put)
         .bucket(bucket_name)
         .object(object_key)
         .add_query("partNumber", part_number.to_string())
-        .add_query("uploadId", upload_id)
-        .body(body);
+        .add_query("uploadId", upload_id);
 
-    Ok(request)
+    if let Some(s) = content_md5 {
+        request = request.add_header("content-md5", s);
+    }
+
+    if let Some(t) = timeout {
+        request = request.timeout(t);
+    }
+
+    Ok(request.body(body))
+}
+
+/// OSS 在拷贝范围不合法时会静默地拷贝整个源 Object，所以在发送请求前检查拷贝范围。
+/// 拷贝范围必须是 `bytes=start-end` 格式，`start <= end`，并且如果指定了源 Object 大小，`end` 不能超出源 Object
+fn validate_copy_source_range(range: &str, source_size: Option<u64>) -> Result<()> {
+    let (start, end) = range
+        .strip_prefix("bytes=")
+        .and_then(|s| s.split_once('-'))
+        .and_then(|(a, b)| Some((a.trim().parse::<u64>().ok()?, b.trim().parse::<u64>().ok()?)))
+        .ok_or(Error::Other(format!("invalid copy source range: {}. must be `bytes=start-end`", range)))?;
+
+    if start > end {
+        return Err(Error::Other(format!(
+            "invalid copy source range: {}. start must not be greater than end",
+            range
+        )));
+    }
+
+    if let Some(size) = source_size {
+        if end >= size {
+            return Err(Error::Other(format!(
+                "invalid copy source range: {}. out of source object size {}",
+                range, size
+            )));
+        }
+    }
+
+    Ok(())
 }
 
 pub(crate) fn build_upload_part_copy_request(
@@ -698,11 +1138,17 @@ pub(crate) fn build_upload_part_copy_request(
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid destination object key: {}", object_key)));
+        return Err(Error::Other(format!(
+            "invalid destination object key: {}",
+            explain_invalid_object_key(object_key)
+        )));
     }
 
     if !validate_object_key(&data.source_object_key) {
-        return Err(Error::Other(format!("invalid source object key: {}", data.source_object_key)));
+        return Err(Error::Other(format!(
+            "invalid source object key: {}",
+            explain_invalid_object_key(&data.source_object_key)
+        )));
     }
 
     if !(1..=10000).contains(&data.part_number) {
@@ -713,14 +1159,23 @@ pub(crate) fn build_upload_part_copy_request(
         part_number,
         upload_id,
         source_object_key,
+        source_bucket_name,
     } = data;
 
+    let source_bucket_name = source_bucket_name.as_deref().unwrap_or(bucket_name);
+    if !validate_bucket_name(source_bucket_name) {
+        return Err(Error::Other(format!("invalid source bucket name: {}", source_bucket_name)));
+    }
+
     if upload_id.is_empty() {
         return Err(Error::Other("invalid upload id: must not be empty".to_string()));
     }
 
     if !validate_object_key(&source_object_key) {
-        return Err(Error::Other(format!("invalid source object key: {}", source_object_key)));
+        return Err(Error::Other(format!(
+            "invalid source object key: {}",
+            explain_invalid_object_key(&source_object_key)
+        )));
     }
 
     let mut request = OssRequest::new()
@@ -730,17 +1185,17 @@ pub(crate) fn build_upload_part_copy_request(
         .add_query("uploadId", upload_id)
         .add_query("partNumber", part_number.to_string());
 
-    let mut copy_source = format!("/{}/{}", bucket_name, source_object_key);
-    if let Some(opt) = options {
-        if let Some(v) = &opt.source_object_version_id {
-            copy_source = format!("{}?versionId={}", copy_source, v);
-        }
-    }
+    let copy_source = encode_copy_source(
+        source_bucket_name,
+        &source_object_key,
+        options.as_ref().and_then(|opt| opt.source_object_version_id.as_deref()),
+    );
 
     request = request.add_header("x-oss-copy-source", &copy_source);
 
     if let Some(options) = options {
         if let Some(s) = &options.copy_source_range {
+            validate_copy_source_range(s, options.source_size)?;
             request = request.add_header("x-oss-copy-source-range", s);
         }
 
@@ -775,7 +1230,7 @@ pub(crate) fn build_complete_multipart_uploads_request(
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
     }
 
     if data.upload_id.is_empty() {
@@ -805,10 +1260,8 @@ pub(crate) fn build_complete_multipart_uploads_request(
             let callback_base64 = BASE64_STANDARD.encode(&callback_json);
             request = request.add_header("x-oss-callback", callback_base64);
 
-            if !cb.custom_variables.is_empty() {
-                let callback_vars_json = serde_json::to_string(&cb.custom_variables)?;
-                let callback_vars_base64 = BASE64_STANDARD.encode(&callback_vars_json);
-                request = request.add_header("x-oss-callback-var", callback_vars_base64);
+            if let Some(s) = cb.custom_variables_header() {
+                request = request.add_header("x-oss-callback-var", s);
             }
         }
     }
@@ -853,7 +1306,7 @@ pub(crate) fn build_list_parts_request(bucket_name: &str, object_key: &str, uplo
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
     }
 
     if upload_id.is_empty() {
@@ -881,7 +1334,20 @@ pub(crate) fn build_list_parts_request(bucket_name: &str, object_key: &str, uplo
 
 #[cfg(test)]
 mod test_multipart_common {
-    use super::ListMultipartUploadsResult;
+    use std::collections::HashMap;
+
+    use super::{
+        build_abort_multipart_upload_request, build_upload_file_parts, build_upload_part_copy_request, build_upload_part_request, upload_checkpoint_path,
+        CompleteMultipartUploadApiResponse, CompleteMultipartUploadResult, ListMultipartUploadsResult, ListPartsResultItem, UploadCheckpoint,
+        UploadCheckpointPart, UploadConfig, UploadPartCopyOptionsBuilder, UploadPartCopyRequest, UploadPartRequest, UploadPartResult, MAX_PART_COUNT,
+        MAX_PART_SIZE, MIN_PART_SIZE,
+    };
+    use crate::{
+        object_common::{request_body_crc64, ByteRange},
+        request::RequestMethod,
+        util::content_md5_base64,
+        RequestBody,
+    };
 
     #[test]
     fn test_list_multipart_uploads_result() {
@@ -931,4 +1397,335 @@ mod test_multipart_common {
 
         println!("{:#?}", data);
     }
+
+    #[test]
+    fn test_complete_multipart_upload_result_from_response() {
+        let headers = HashMap::from([
+            ("etag".to_string(), "\"095C0D4B1E84B7C8E2C0F3D0C0B1A2B3-3\"".to_string()),
+            ("x-oss-version-id".to_string(), "v1".to_string()),
+            ("x-oss-request-id".to_string(), "534B371674E88A4D8906****".to_string()),
+        ]);
+
+        // 没有回调，从响应的 XML 中解析
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <CompleteMultipartUploadResult>
+            <EncodingType>url</EncodingType>
+            <Location>http://oss-example.oss-cn-hangzhou.aliyuncs.com/multipart.data</Location>
+            <Bucket>oss-example</Bucket>
+            <Key>multipart.data</Key>
+            <ETag>"095C0D4B1E84B7C8E2C0F3D0C0B1A2B3-3"</ETag>
+        </CompleteMultipartUploadResult>"#;
+        let ret = CompleteMultipartUploadResult::from_response("oss-example", "multipart.data", false, headers.clone(), xml.to_string()).unwrap();
+        assert!(matches!(ret, CompleteMultipartUploadResult::ApiResponse(_)));
+        assert_eq!("oss-example", ret.api_response().bucket);
+        assert_eq!("multipart.data", ret.api_response().key);
+        assert_eq!("095C0D4B1E84B7C8E2C0F3D0C0B1A2B3-3", ret.api_response().etag);
+        assert_eq!(Some("v1"), ret.version_id());
+        assert_eq!("534B371674E88A4D8906****", ret.api_response().request_id);
+
+        // 有回调时，响应体是回调服务器返回的内容，ETag 从响应头中获取
+        let ret = CompleteMultipartUploadResult::from_response("oss-example", "multipart.data", true, headers, "{\"status\":\"ok\"}".to_string()).unwrap();
+        match &ret {
+            CompleteMultipartUploadResult::CallbackResponse(s, _) => assert_eq!("{\"status\":\"ok\"}", s),
+            _ => panic!("callback response expected"),
+        }
+        assert_eq!("oss-example", ret.api_response().bucket);
+        assert_eq!("multipart.data", ret.api_response().key);
+        assert_eq!("095C0D4B1E84B7C8E2C0F3D0C0B1A2B3-3", ret.api_response().etag);
+        assert_eq!(Some("v1"), ret.version_id());
+        assert!(!ret.callback_failed());
+    }
+
+    #[test]
+    fn test_complete_multipart_upload_callback_failed() {
+        let ret = CompleteMultipartUploadResult::CallbackResponse("{\"status\":\"ok\"}".to_string(), CompleteMultipartUploadApiResponse::default());
+        assert!(!ret.callback_failed());
+
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <Error>
+            <Code>CallbackFailed</Code>
+            <Message>Error status : 502.</Message>
+            <RequestId>5C3D8D2A0ACA54D87B43****</RequestId>
+        </Error>"#;
+        let ret = CompleteMultipartUploadResult::CallbackResponse(xml.to_string(), CompleteMultipartUploadApiResponse::default());
+        assert!(ret.callback_failed());
+
+        let ret = CompleteMultipartUploadResult::ApiResponse(CompleteMultipartUploadApiResponse::default());
+        assert!(!ret.callback_failed());
+    }
+
+    #[test]
+    fn test_upload_part_copy_range() {
+        let build = |options| build_upload_part_copy_request("yuanyq", "dest.zip", UploadPartCopyRequest::new(1, "upload-id", "source.zip"), &Some(options));
+
+        let options = UploadPartCopyOptionsBuilder::new().copy_source_byte_range(0, 99).source_size(100).build();
+        let request = build(options).unwrap();
+        assert_eq!(Some(&"bytes=0-99".to_string()), request.headers.get("x-oss-copy-source-range"));
+
+        // 超出源 Object 的范围
+        let options = UploadPartCopyOptionsBuilder::new().copy_source_byte_range(0, 100).source_size(100).build();
+        assert!(build(options).is_err());
+
+        let options = UploadPartCopyOptionsBuilder::new().copy_source_byte_range(10, 9).build();
+        assert!(build(options).is_err());
+
+        let options = UploadPartCopyOptionsBuilder::new().copy_source_range("bytes=10-").build();
+        assert!(build(options).is_err());
+
+        let options = UploadPartCopyOptionsBuilder::new().copy_source_byte_range(0, 99).source_size(100).build();
+        assert_eq!(Some("bytes=0-99"), options.copy_source_range.as_deref());
+        assert!(build(options).is_ok());
+
+        let options = UploadPartCopyOptionsBuilder::new()
+            .copy_source_range(ByteRange::Suffix(10).to_header_value())
+            .build();
+        assert!(build(options).is_err());
+
+        // 不知道源 Object 大小时不检查上界
+        let options = UploadPartCopyOptionsBuilder::new().copy_source_range("bytes=100-199").build();
+        assert!(build(options).is_ok());
+    }
+
+    #[test]
+    fn test_upload_part_copy_source() {
+        let request = build_upload_part_copy_request("yuanyq", "dest.zip", UploadPartCopyRequest::new(1, "upload-id", "a/b c.zip"), &None).unwrap();
+        assert_eq!(Some(&"/yuanyq/a%2Fb%20c.zip".to_string()), request.headers.get("x-oss-copy-source"));
+
+        // 从其他 Bucket 拷贝，key 和版本号都需要编码
+        let data = UploadPartCopyRequest::new(1, "upload-id", "测试/源.zip").source_bucket("source-bucket");
+        let options = UploadPartCopyOptionsBuilder::new().source_object_version_id("v1+/=").build();
+        let request = build_upload_part_copy_request("yuanyq", "dest.zip", data, &Some(options)).unwrap();
+        assert_eq!(
+            Some(&"/source-bucket/%E6%B5%8B%E8%AF%95%2F%E6%BA%90.zip?versionId=v1%2B%2F%3D".to_string()),
+            request.headers.get("x-oss-copy-source")
+        );
+        assert_eq!("yuanyq", request.bucket_name);
+
+        let data = UploadPartCopyRequest::new(1, "upload-id", "a.zip").source_bucket("Invalid_Bucket");
+        assert!(build_upload_part_copy_request("yuanyq", "dest.zip", data, &None).is_err());
+    }
+
+    #[test]
+    fn test_build_abort_multipart_upload_request() {
+        let request = build_abort_multipart_upload_request("yuanyq", "a.zip", "upload-id").unwrap();
+        assert_eq!(RequestMethod::Delete, request.method);
+        assert_eq!(Some(&"upload-id".to_string()), request.query.get("uploadId"));
+
+        assert!(build_abort_multipart_upload_request("yuanyq", "a.zip", "").is_err());
+        assert!(build_abort_multipart_upload_request("yuanyq", "", "upload-id").is_err());
+        assert!(build_abort_multipart_upload_request("-invalid", "a.zip", "upload-id").is_err());
+    }
+
+    #[test]
+    fn test_upload_checkpoint() {
+        let dir = std::env::temp_dir();
+        let file = dir.join("ali-oss-rs-test-upload-checkpoint.bin");
+        std::fs::write(&file, vec![0u8; 300]).unwrap();
+        let meta = std::fs::metadata(&file).unwrap();
+
+        let checkpoint_path = upload_checkpoint_path(&file);
+        assert_eq!(dir.join("ali-oss-rs-test-upload-checkpoint.bin.oss-checkpoint.json"), checkpoint_path);
+
+        let all_parts = vec![(1, 0..100), (2, 100..200), (3, 200..300)];
+
+        let mut checkpoint = UploadCheckpoint::new("yuanyq", "a.bin", "upload-id", 100, &meta).unwrap();
+        checkpoint.add_part(
+            2,
+            UploadPartResult {
+                request_id: "req".to_string(),
+                etag: "etag-2".to_string(),
+                crc64: Some(2),
+            },
+        );
+        checkpoint.save(&checkpoint_path).unwrap();
+
+        let loaded = UploadCheckpoint::load(&checkpoint_path).unwrap();
+        assert_eq!(checkpoint, loaded);
+        assert_eq!(Some(2), loaded.parts[0].crc64);
+        assert!(loaded.matches("yuanyq", "a.bin", 100, &meta));
+        assert_eq!(vec![(1, 0..100), (3, 200..300)], loaded.pending_parts(all_parts.clone()));
+
+        // 上传目标或者分片大小不同时，检查点无效
+        assert!(!loaded.matches("yuanyq", "b.bin", 100, &meta));
+        assert!(!loaded.matches("yuanyq", "a.bin", 200, &meta));
+
+        // 源文件大小变化后，检查点无效
+        std::fs::write(&file, vec![0u8; 301]).unwrap();
+        let changed_meta = std::fs::metadata(&file).unwrap();
+        assert!(!loaded.matches("yuanyq", "a.bin", 100, &changed_meta));
+
+        std::fs::remove_file(&file).unwrap();
+        std::fs::remove_file(&checkpoint_path).unwrap();
+        assert!(UploadCheckpoint::load(&checkpoint_path).is_none());
+
+        // 旧版本保存的检查点没有 crc64
+        let json = r#"{"bucket":"yuanyq","objectKey":"a.bin","uploadId":"upload-id","partSize":100,"fileSize":300,"fileMtime":0,"parts":[{"partNumber":1,"etag":"etag-1"}]}"#;
+        assert_eq!(None, UploadCheckpoint::from_json(json.as_bytes()).unwrap().parts[0].crc64);
+
+        // 以 ListParts 的结果为准：丢弃 OSS 上不存在的分片，保留未记录但大小正确的分片
+        let uploaded = vec![
+            ListPartsResultItem {
+                part_number: 1,
+                etag: "etag-1".to_string(),
+                size: 100,
+                last_modified: String::new(),
+            },
+            ListPartsResultItem {
+                part_number: 3,
+                etag: "etag-3".to_string(),
+                size: 50,
+                last_modified: String::new(),
+            },
+        ];
+        checkpoint.reconcile(&uploaded, &all_parts);
+        assert_eq!(
+            vec![UploadCheckpointPart {
+                part_number: 1,
+                etag: "etag-1".to_string(),
+                crc64: None,
+            }],
+            checkpoint.parts
+        );
+        assert_eq!(vec![(2, 100..200), (3, 200..300)], checkpoint.pending_parts(all_parts));
+
+        let request = checkpoint.complete_request();
+        assert_eq!("upload-id", request.upload_id);
+        assert_eq!(vec![(1, "etag-1".to_string())], request.parts);
+    }
+
+    #[test]
+    fn test_upload_checkpoint_verify_parts() {
+        let file = std::env::temp_dir().join("ali-oss-rs-test-upload-checkpoint-verify.bin");
+        std::fs::write(&file, (0..300).map(|i| i as u8).collect::<Vec<_>>()).unwrap();
+        let meta = std::fs::metadata(&file).unwrap();
+
+        let all_parts = vec![(1, 0..100), (2, 100..200), (3, 200..300)];
+        let part_crc64 = |range: std::ops::Range<u64>| request_body_crc64(&RequestBody::File(file.clone(), Some(range))).unwrap();
+
+        let mut checkpoint = UploadCheckpoint::new("yuanyq", "a.bin", "upload-id", 100, &meta).unwrap();
+        checkpoint.parts = vec![
+            UploadCheckpointPart {
+                part_number: 1,
+                etag: "etag-1".to_string(),
+                crc64: Some(part_crc64(0..100)),
+            },
+            UploadCheckpointPart {
+                part_number: 2,
+                etag: "etag-2".to_string(),
+                crc64: Some(part_crc64(0..100)),
+            },
+            UploadCheckpointPart {
+                part_number: 3,
+                etag: "etag-3".to_string(),
+                crc64: None,
+            },
+        ];
+
+        // 分片 2 的 CRC64 与本地文件不一致，需要重新上传
+        checkpoint.verify_parts(&file, &all_parts).unwrap();
+        assert_eq!(vec![1, 3], checkpoint.parts.iter().map(|p| p.part_number).collect::<Vec<_>>());
+        assert_eq!(vec![(2, 100..200)], checkpoint.pending_parts(all_parts));
+
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[test]
+    fn test_upload_part_result_crc64() {
+        let headers = HashMap::from([
+            ("etag".to_string(), "\"ABC\"".to_string()),
+            ("x-oss-hash-crc64ecma".to_string(), "123".to_string()),
+        ]);
+        let ret = UploadPartResult::from(headers);
+        assert_eq!("ABC", ret.etag);
+        assert_eq!(Some(123), ret.crc64);
+    }
+
+    #[test]
+    fn test_build_upload_file_parts() {
+        let config = UploadConfig::new(MIN_PART_SIZE, 4);
+
+        let parts = build_upload_file_parts(MIN_PART_SIZE * 2 + 1, &config).unwrap();
+        assert_eq!(3, parts.len());
+        assert_eq!((1, 0..MIN_PART_SIZE), parts[0]);
+        assert_eq!((3, MIN_PART_SIZE * 2..MIN_PART_SIZE * 2 + 1), parts[2]);
+
+        // 只有一个分片时，可以小于最小分片大小
+        assert_eq!(vec![(1, 0..10)], build_upload_file_parts(10, &config).unwrap());
+
+        assert!(build_upload_file_parts(0, &config).is_err());
+        assert!(build_upload_file_parts(100, &UploadConfig::new(MIN_PART_SIZE - 1, 4)).is_err());
+        assert!(build_upload_file_parts(100, &UploadConfig::new(MAX_PART_SIZE + 1, 4)).is_err());
+        assert!(build_upload_file_parts(MIN_PART_SIZE * MAX_PART_COUNT + 1, &config).is_err());
+        assert_eq!(
+            MAX_PART_COUNT as usize,
+            build_upload_file_parts(MIN_PART_SIZE * MAX_PART_COUNT, &config).unwrap().len()
+        );
+    }
+
+    #[test]
+    fn test_upload_part_content_md5() {
+        let body = || RequestBody::Bytes(bytes::Bytes::from_static(b"hello world"));
+
+        let request = build_upload_part_request("yuanyq", "a.zip", body(), UploadPartRequest::new(1, "upload-id")).unwrap();
+        assert!(!request.headers.contains_key("content-md5"));
+
+        let request = build_upload_part_request("yuanyq", "a.zip", body(), UploadPartRequest::new(1, "upload-id").content_md5("abc")).unwrap();
+        assert_eq!(Some(&"abc".to_string()), request.headers.get("content-md5"));
+
+        let request = build_upload_part_request("yuanyq", "a.zip", body(), UploadPartRequest::new(1, "upload-id").compute_content_md5(true)).unwrap();
+        assert_eq!(Some(&content_md5_base64(b"hello world")), request.headers.get("content-md5"));
+
+        // 只计算文件指定范围的内容
+        let file = std::env::temp_dir().join("ali-oss-rs-test-upload-part-content-md5.txt");
+        std::fs::write(&file, b"0123456789hello world0123456789").unwrap();
+        let body = RequestBody::File(file.clone(), Some(10..21));
+        let request = build_upload_part_request("yuanyq", "a.zip", body, UploadPartRequest::new(1, "upload-id").compute_content_md5(true)).unwrap();
+        assert_eq!(Some(&content_md5_base64(b"hello world")), request.headers.get("content-md5"));
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[test]
+    fn test_upload_part_timeout() {
+        let body = || RequestBody::Bytes(bytes::Bytes::from_static(b"hello world"));
+
+        let request = build_upload_part_request("yuanyq", "a.zip", body(), UploadPartRequest::new(1, "upload-id")).unwrap();
+        assert!(request.timeout.is_none());
+
+        let params = UploadPartRequest::new(1, "upload-id").timeout(std::time::Duration::from_secs(600));
+        let request = build_upload_part_request("yuanyq", "a.zip", body(), params).unwrap();
+        assert_eq!(Some(std::time::Duration::from_secs(600)), request.timeout);
+    }
+
+    #[test]
+    fn test_complete_multipart_upload_ok_status_with_error_body() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <Error>
+            <Code>InternalError</Code>
+            <Message>We encountered an internal error. Please try again.</Message>
+            <RequestId>5C3D9175B6FC201293AD****</RequestId>
+            <HostId>oss-example.oss-cn-hangzhou.aliyuncs.com</HostId>
+        </Error>"#;
+
+        match crate::error::error_from_ok_response(reqwest::StatusCode::OK, xml) {
+            Some(crate::error::Error::ApiError(e)) => {
+                assert_eq!("InternalError", e.code);
+                assert_eq!("5C3D9175B6FC201293AD****", e.request_id);
+            }
+            other => panic!("expected api error, got {:?}", other),
+        }
+
+        // 203 表示回调失败，由调用方单独处理
+        assert!(crate::error::error_from_ok_response(reqwest::StatusCode::NON_AUTHORITATIVE_INFORMATION, xml).is_none());
+
+        let ok = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <CompleteMultipartUploadResult>
+            <Location>http://oss-example.oss-cn-hangzhou.aliyuncs.com/multipart.data</Location>
+            <Bucket>oss-example</Bucket>
+            <Key>multipart.data</Key>
+            <ETag>"B864DB6A936D376F9F8D3ED3BBE540****"</ETag>
+        </CompleteMultipartUploadResult>"#;
+
+        assert!(crate::error::error_from_ok_response(reqwest::StatusCode::OK, ok).is_none());
+        assert!(CompleteMultipartUploadApiResponse::from_xml(ok).is_ok());
+    }
 }
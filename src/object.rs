@@ -1,26 +1,82 @@
-use std::path::Path;
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{Arc, OnceLock},
+};
 
 use async_trait::async_trait;
-use base64::{prelude::BASE64_STANDARD, Engine};
-use futures::TryStreamExt;
-use reqwest::StatusCode;
-use tokio::io::AsyncWriteExt;
+use futures::{StreamExt, TryStreamExt};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 use crate::{
+    acl::{BucketAclOperations, GetObjectAclOptions, ObjectAclOperations},
+    bucket::{list_objects_pages, BucketOperations},
+    bucket_common::{ListObjectVersionsOptions, ListObjectsOptions},
+    common::{ObjectType, ResponseMeta, TagDirective, WithVersionId, MAX_COPY_OBJECT_SIZE},
     error::Error,
+    multipart::MultipartUploadsOperations,
+    multipart_common::{CompleteMultipartUploadRequest, UploadPartCopyOptions, UploadPartCopyRequest},
     object_common::{
-        build_copy_object_request, build_delete_multiple_objects_request, build_get_object_request, build_head_object_request, build_put_object_request,
-        build_restore_object_request, AppendObjectOptions, AppendObjectResult, CopyObjectOptions, CopyObjectResult, DeleteMultipleObjectsConfig,
-        DeleteMultipleObjectsResult, DeleteObjectOptions, DeleteObjectResult, GetObjectMetadataOptions, GetObjectOptions, GetObjectResult, HeadObjectOptions,
-        ObjectMetadata, PutObjectOptions, PutObjectResult, RestoreObjectRequest, RestoreObjectResult,
+        build_copy_multipart_options, build_copy_object_request, build_copy_part_ranges, build_create_folder_request, build_delete_folder_request,
+        build_delete_multiple_objects_request, build_delete_versions_requests, build_folder_exists_options, build_get_object_request,
+        build_head_object_request, build_put_object_request, build_rename_delete_options, build_restore_object_request, build_resume_get_object_options,
+        build_select_object_request, check_copy_region, check_rename_object_keys, check_resilient_download_options, check_verified_download_options,
+        is_restore_already_in_progress, need_restore, request_body_content_md5, request_body_crc64, response_crc64, verify_crc64, AppendObjectOptions,
+        AppendObjectResult, CopyObjectOptions, CopyObjectResult, CreateFolderResult, DeleteMultipleObjectsConfig, DeleteMultipleObjectsResult,
+        DeleteObjectOptions, DeleteObjectResult, GetObjectMetadataOptions, GetObjectOptions, GetObjectResult, HeadObjectOptions, ObjectAcl, ObjectMetadata,
+        PutObjectOptions, PutObjectResult, PutSource, RestoreObjectRequest, RestoreObjectResult, RestorePrefixResult, SelectFrame, SelectFrameDecoder,
+        SelectRequest,
     },
-    request::{OssRequest, RequestMethod},
-    util::{validate_bucket_name, validate_object_key, validate_path},
-    ByteStream, Client, RequestBody, Result,
+    request::{OssRequest, RequestBody, RequestMethod},
+    tagging::ObjectTagOperations,
+    tagging_common::GetObjectTagOptions,
+    util::{explain_invalid_object_key, validate_bucket_name, validate_object_key, validate_path, Crc64},
+    ByteStream, Client, Result, SelectObjectStream,
 };
 
 #[async_trait]
 pub trait ObjectOperations {
+    /// Put object from any supported source: local file, buffer, bytes or base64 string.
+    /// The `put_object_from_*` methods are shortcuts of this method.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
+    async fn put_object<S1, S2, T>(&self, bucket_name: S1, object_key: S2, source: T, options: Option<PutObjectOptions>) -> Result<PutObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        T: Into<PutSource> + Send;
+
+    /// Same as [`Self::put_object`], and returns the request id, status and elapsed time of the response
+    async fn put_object_with_meta<S1, S2, T>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        source: T,
+        options: Option<PutObjectOptions>,
+    ) -> Result<(PutObjectResult, ResponseMeta)>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        T: Into<PutSource> + Send;
+
+    /// Append object from any supported source: local file, buffer, bytes or base64 string.
+    /// When `position` is `0`, the appendable object is created with `mime_type`, `traffic_limit` etc. in `options`.
+    /// The `append_object_from_*` methods are shortcuts of this method.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/appendobject>
+    async fn append_object<S1, S2, T>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        source: T,
+        position: u64,
+        options: Option<AppendObjectOptions>,
+    ) -> Result<AppendObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        T: Into<PutSource> + Send;
+
     /// Uploads a file to a specified bucket and object key.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
@@ -46,6 +102,48 @@ pub trait ObjectOperations {
         S2: AsRef<str> + Send,
         B: Into<Vec<u8>> + Send;
 
+    /// Same as [`Self::put_object_from_file`], and returns the request id, status and elapsed time of the response
+    async fn put_object_from_file_with_meta<S1, S2, P>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        file_path: P,
+        options: Option<PutObjectOptions>,
+    ) -> Result<(PutObjectResult, ResponseMeta)>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        P: AsRef<Path> + Send;
+
+    /// Same as [`Self::put_object_from_buffer`], and returns the request id, status and elapsed time of the response
+    async fn put_object_from_buffer_with_meta<S1, S2, B>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        buffer: B,
+        options: Option<PutObjectOptions>,
+    ) -> Result<(PutObjectResult, ResponseMeta)>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        B: Into<Vec<u8>> + Send;
+
+    /// Create an object from several in-memory fragments. The fragments are sent in order without being concatenated,
+    /// so no extra allocation is needed when the data is already split.
+    /// And, it is recommended to set `mime_type` in `options`
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
+    async fn put_object_from_chunks<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        chunks: Vec<bytes::Bytes>,
+        options: Option<PutObjectOptions>,
+    ) -> Result<PutObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+
     /// Create an object from base64 string.
     /// And, it is recommended to set `mime_type` in `options`
     ///
@@ -63,6 +161,7 @@ pub trait ObjectOperations {
         S3: AsRef<str> + Send;
 
     /// Append object.
+    /// When `position` is `0`, the appendable object is created with `mime_type`, `traffic_limit` etc. in `options`
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/appendobject>
     async fn append_object_from_file<S1, S2, P>(
@@ -113,6 +212,7 @@ pub trait ObjectOperations {
         S3: AsRef<str> + Send;
 
     /// Download object to local file
+    /// The metadata parsed from the response headers is returned, e.g. to verify the server side encryption.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobject>
     async fn get_object_to_file<S1, S2, P>(&self, bucket_name: S1, object_key: S2, file_path: P, options: Option<GetObjectOptions>) -> Result<GetObjectResult>
@@ -121,6 +221,55 @@ pub trait ObjectOperations {
         S2: AsRef<str> + Send,
         P: AsRef<Path> + Send;
 
+    /// Download object to local file. If the connection is broken while downloading,
+    /// continue from the received bytes with a range request, at most `max_retries` times.
+    /// The etag of the first response is sent as `If-Match` on each retry,
+    /// so `412 Precondition Failed` is returned if the object is changed in the middle.
+    ///
+    /// `range` and `accept_encoding` of `options` are not supported.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobject>
+    async fn get_object_to_file_resilient<S1, S2, P>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        file_path: P,
+        options: Option<GetObjectOptions>,
+        max_retries: u32,
+    ) -> Result<GetObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        P: AsRef<Path> + Send;
+
+    /// Download object and write the content to `writer` chunk by chunk, e.g. an in-memory buffer,
+    /// a hashing writer or a compression encoder, without landing on disk.
+    /// The response body is fully drained and the writer is flushed before returning.
+    /// Errors while writing are returned as `Error::IoError`.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobject>
+    async fn get_object_to_writer<S1, S2, W>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        writer: &mut W,
+        options: Option<GetObjectOptions>,
+    ) -> Result<GetObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        W: AsyncWrite + Unpin + Send;
+
+    /// Get object and return the metadata parsed from the response headers together with the response body stream,
+    /// so the caller can consume a large object lazily and apply backpressure.
+    /// Errors while reading the stream are `reqwest::Error`.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobject>
+    async fn get_object_stream<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectOptions>) -> Result<(ObjectMetadata, ByteStream)>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+
     /// Get object content into memory (bytes array).
     ///
     /// Large files can consume significant memory, exercise caution when using this function.
@@ -129,10 +278,39 @@ pub trait ObjectOperations {
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send;
 
-    /// Create a "folder"
+    /// Get object content into memory and verify it with the `x-oss-hash-crc64ecma` response header.
+    /// Returns `Error::CrcMismatch` if the data is corrupted. Range request is not supported.
+    ///
+    /// Large files can consume significant memory, exercise caution when using this function.
+    async fn get_object_to_verified_buffer<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        options: Option<GetObjectOptions>,
+    ) -> Result<(ObjectMetadata, Vec<u8>)>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+
+    /// Create a "folder". `a/b` and `/a/b/` both create the `a/b/` marker object.
+    /// Keys containing `//` are rejected.
+    ///
+    /// If `fail_if_exists` is `true`, an error is returned when the folder already exists
+    /// (the marker object or any object under the folder exists, see `folder_exists`),
+    /// and the marker object is put with `x-oss-forbid-overwrite: true`.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
-    async fn create_folder<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<()>
+    async fn create_folder<S1, S2>(&self, bucket_name: S1, object_key: S2, fail_if_exists: bool) -> Result<CreateFolderResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+
+    /// Check if any object exists under the "folder", including the `a/b/` marker object itself.
+    /// `a/b` and `/a/b/` are both treated as `a/b/`.
+    /// Unlike `exists`, it returns `true` for a folder without marker object if any object is under it
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/listobjectsv2>
+    async fn folder_exists<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<bool>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send;
@@ -161,6 +339,16 @@ pub trait ObjectOperations {
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send;
 
+    /// Get the object type (`Normal`, `Appendable`, `Multipart` or `Symlink`) using head object.
+    /// Returns `None` if the object does not exist.
+    /// Useful to decide whether to append to the object or overwrite it
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/headobject>
+    async fn object_type<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<Option<ObjectType>>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+
     /// Head object
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/headobject>
@@ -170,6 +358,7 @@ pub trait ObjectOperations {
         S2: AsRef<str> + Send;
 
     /// Copy files (Objects) between the same or different Buckets within the same region.
+    /// If the copy fails because the buckets are in different regions, `Error::CrossRegionCopyUnsupported` is returned.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/copyobject>
     async fn copy_object<S1, S2, S3, S4>(
@@ -186,6 +375,48 @@ pub trait ObjectOperations {
         S3: AsRef<str> + Send,
         S4: AsRef<str> + Send;
 
+    /// Copy object no matter how large it is.
+    /// Objects smaller than 1GB are copied by `copy_object`, larger objects are copied by multipart upload with UploadPartCopy,
+    /// and the multipart upload is aborted if any part fails.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/uploadpartcopy>
+    async fn copy_object_auto<S1, S2, S3, S4>(
+        &self,
+        source_bucket_name: S1,
+        source_object_key: S2,
+        dest_bucket_name: S3,
+        dest_object_key: S4,
+        options: Option<CopyObjectOptions>,
+    ) -> Result<CopyObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        S3: AsRef<str> + Send,
+        S4: AsRef<str> + Send;
+
+    /// Rename an object by copying it to `to_object_key` (multipart copy for large objects, see `copy_object_auto`)
+    /// and deleting `from_object_key` only after the copy succeeds. It is not atomic:
+    /// both objects exist for a short while, and the new object is kept if deleting the source fails.
+    ///
+    /// If `source_version_id` is set in `options`, that version of the source object is permanently deleted
+    /// instead of adding a delete marker to the current version.
+    ///
+    /// With `forbid_overwrite` set in `options`, an error is returned if `to_object_key` already exists.
+    /// Returns the metadata of the new object.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/copyobject>
+    async fn rename_object<S1, S2, S3>(
+        &self,
+        bucket_name: S1,
+        from_object_key: S2,
+        to_object_key: S3,
+        options: Option<CopyObjectOptions>,
+    ) -> Result<ObjectMetadata>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        S3: AsRef<str> + Send;
+
     /// Delete an object
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deleteobject>
@@ -194,6 +425,17 @@ pub trait ObjectOperations {
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send;
 
+    /// Same as [`Self::delete_object`], and returns the request id, status and elapsed time of the response
+    async fn delete_object_with_meta<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        options: Option<DeleteObjectOptions>,
+    ) -> Result<(DeleteObjectResult, ResponseMeta)>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+
     /// Delete multiple objects
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletemultipleobjects>
@@ -202,6 +444,13 @@ pub trait ObjectOperations {
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send + Sync;
 
+    /// Permanently delete all versions and delete markers of an object in a versioning enabled bucket.
+    /// Returns the number of deleted versions (including delete markers)
+    async fn delete_all_versions<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<usize>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+
     /// Restore object
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/restoreobject>
@@ -210,6 +459,14 @@ pub trait ObjectOperations {
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send;
 
+    /// Query CSV or JSON object with SQL and stream the matching records, without downloading the whole object
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/selectobject>
+    async fn select_object<S1, S2>(&self, bucket_name: S1, object_key: S2, select_request: SelectRequest) -> Result<SelectObjectStream>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+
     /// Clean retored object
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/cleanrestoredobject>
@@ -217,55 +474,117 @@ pub trait ObjectOperations {
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send;
-}
 
-#[async_trait]
-impl ObjectOperations for Client {
-    /// The `object_key` constraints:
-    ///
-    /// - length between [1, 1023]
-    /// - must NOT starts or ends with `/` or `\`. e.g. `path/to/subfolder/some-file.txt`
-    /// - the `file_path` specify full path to the file to be uploaded
-    /// - the file must exist and must be readable
-    /// - file length less than 5GB
-    ///
-    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
-    async fn put_object_from_file<S1, S2, P>(&self, bucket_name: S1, object_key: S2, file_path: P, options: Option<PutObjectOptions>) -> Result<PutObjectResult>
+    /// Restore all `Archive`, `ColdArchive` and `DeepColdArchive` objects under the prefix.
+    /// At most `concurrency` restore requests are sent at the same time.
+    /// Objects which are already being restored are counted in `already_in_progress` rather than `failed`.
+    /// `version_id` in `config` is ignored, the current version of each object is restored.
+    async fn restore_prefix<S1, S2>(&self, bucket_name: S1, prefix: S2, config: RestoreObjectRequest, concurrency: usize) -> Result<RestorePrefixResult>
     where
         S1: AsRef<str> + Send,
-        S2: AsRef<str> + Send,
-        P: AsRef<Path> + Send,
-    {
-        let bucket_name = bucket_name.as_ref();
-        let object_key = object_key.as_ref();
+        S2: AsRef<str> + Send;
+}
 
-        let object_key = object_key.strip_prefix("/").unwrap_or(object_key);
-        let object_key = object_key.strip_suffix("/").unwrap_or(object_key);
+impl Client {
+    /// Read storage class and acl of the source object which are not set explicitly in `options`,
+    /// so the destination object matches the source object regardless of the destination bucket defaults
+    async fn resolve_preserved_copy_source(
+        &self,
+        source_bucket_name: &str,
+        source_object_key: &str,
+        dest_bucket_name: &str,
+        mut options: CopyObjectOptions,
+    ) -> Result<CopyObjectOptions> {
+        let source_version_id = options.source_version_id.clone();
+
+        if options.storage_class.is_none() {
+            let head_options = source_version_id.clone().map(HeadObjectOptions::with_version_id);
+            options.storage_class = self.head_object(source_bucket_name, source_object_key, head_options).await?.storage_class;
+        }
 
-        let file_path = file_path.as_ref();
+        if options.object_acl.is_none() {
+            let acl_options = source_version_id.map(GetObjectAclOptions::with_version_id);
+            let mut acl = self.get_object_acl(source_bucket_name, source_object_key, acl_options).await?.acl;
 
-        let with_callback = if let Some(opt) = &options { opt.callback.is_some() } else { false };
+            // `Default` 表示继承 Bucket 的权限，跨 Bucket 拷贝时需要显式设置为源 Bucket 的权限
+            if acl == ObjectAcl::Default && source_bucket_name != dest_bucket_name {
+                acl = self.get_bucket_acl(source_bucket_name).await?.effective_acl().into();
+            }
 
-        let request = build_put_object_request(bucket_name, object_key, RequestBody::File(file_path.to_path_buf(), None), &options)?;
+            options.object_acl = Some(acl);
+        }
 
-        let (headers, content) = self.do_request::<String>(request).await?;
+        Ok(options)
+    }
+}
 
-        if with_callback {
-            Ok(PutObjectResult::CallbackResponse(content))
-        } else {
-            Ok(PutObjectResult::ApiResponse(headers.into()))
+/// Read `PutSource::Reader` in the blocking thread pool, so it does not block the async runtime
+async fn put_source_into_request_body(source: PutSource) -> Result<RequestBody> {
+    if !source.is_reader() {
+        return source.into_request_body();
+    }
+
+    tokio::task::spawn_blocking(move || source.into_request_body())
+        .await
+        .map_err(|e| Error::Other(format!("reading put source failed: {}", e)))?
+}
+
+/// Hash the request body with `f`. Files are read in the blocking thread pool,
+/// so hashing a large file does not block the async runtime
+pub(crate) async fn hash_request_body<T, F>(body: &RequestBody, f: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce(&RequestBody) -> Result<T> + Send + 'static,
+{
+    if !matches!(body, RequestBody::File(..)) {
+        return f(body);
+    }
+
+    let body = body.clone();
+    tokio::task::spawn_blocking(move || f(&body))
+        .await
+        .map_err(|e| Error::Other(format!("hashing request body failed: {}", e)))?
+}
+
+/// Compute `content-md5` of a file body in the blocking thread pool if `compute_content_md5` is set,
+/// so that `build_put_object_request` does not read the file on the async runtime
+async fn fill_content_md5(options: &mut Option<PutObjectOptions>, body: &RequestBody) -> Result<()> {
+    if let Some(o) = options.as_mut() {
+        if o.content_md5.is_none() && o.compute_content_md5 {
+            o.content_md5 = Some(hash_request_body(body, request_body_content_md5).await?);
         }
     }
 
-    /// Create an object from buffer. If you are going to upload a large file, it is recommended to use `upload_file` instead.
-    /// And, it is recommended to set `mime_type` in `options`
+    Ok(())
+}
+
+#[async_trait]
+impl ObjectOperations for Client {
+    /// Put object from any supported source: local file, buffer, bytes or base64 string.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
-    async fn put_object_from_buffer<S1, S2, B>(&self, bucket_name: S1, object_key: S2, buffer: B, options: Option<PutObjectOptions>) -> Result<PutObjectResult>
+    async fn put_object<S1, S2, T>(&self, bucket_name: S1, object_key: S2, source: T, options: Option<PutObjectOptions>) -> Result<PutObjectResult>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send,
-        B: Into<Vec<u8>> + Send,
+        T: Into<PutSource> + Send,
+    {
+        let (result, _) = self.put_object_with_meta(bucket_name, object_key, source, options).await?;
+        Ok(result)
+    }
+
+    /// Same as [`Self::put_object`], and returns the request id, status and elapsed time of the response
+    async fn put_object_with_meta<S1, S2, T>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        source: T,
+        options: Option<PutObjectOptions>,
+    ) -> Result<(PutObjectResult, ResponseMeta)>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        T: Into<PutSource> + Send,
     {
         let bucket_name = bucket_name.as_ref();
         let object_key = object_key.as_ref();
@@ -274,68 +593,166 @@ impl ObjectOperations for Client {
         let object_key = object_key.strip_suffix("/").unwrap_or(object_key);
 
         let with_callback = if let Some(opt) = &options { opt.callback.is_some() } else { false };
+        let check_crc64 = options.as_ref().is_some_and(|o| o.verify_crc64);
+
+        let body = put_source_into_request_body(source.into()).await?;
+        let mut options = options;
+        fill_content_md5(&mut options, &body).await?;
 
-        let request = build_put_object_request(bucket_name, object_key, RequestBody::Bytes(buffer.into()), &options)?;
+        let request = build_put_object_request(bucket_name, object_key, body, &options)?;
 
-        let (headers, content) = self.do_request::<String>(request).await?;
+        let local_crc64 = if check_crc64 {
+            Some(hash_request_body(&request.body, request_body_crc64).await?)
+        } else {
+            None
+        };
+
+        let (headers, content, meta) = self.do_request_with_meta::<String>(request).await?;
+
+        if let Some(crc) = local_crc64 {
+            verify_crc64(response_crc64(&headers), crc)?;
+        }
 
         if with_callback {
-            Ok(PutObjectResult::CallbackResponse(content))
+            Ok((PutObjectResult::CallbackResponse(content, headers.into()), meta))
         } else {
-            Ok(PutObjectResult::ApiResponse(headers.into()))
+            Ok((PutObjectResult::ApiResponse(headers.into()), meta))
         }
     }
 
-    /// Create an object from base64 string.
-    /// And, it is recommended to set `mime_type` in `options`
+    /// The `object_key` constraints:
+    ///
+    /// - length between [1, 1023]
+    /// - must NOT starts or ends with `/` or `\`. e.g. `path/to/subfolder/some-file.txt`
+    /// - the `file_path` specify full path to the file to be uploaded
+    /// - the file must exist and must be readable
+    /// - file length less than 5GB
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
-    async fn put_object_from_base64<S1, S2, S3>(
-        &self,
-        bucket_name: S1,
-        object_key: S2,
-        base64_string: S3,
-        options: Option<PutObjectOptions>,
-    ) -> Result<PutObjectResult>
+    async fn put_object_from_file<S1, S2, P>(&self, bucket_name: S1, object_key: S2, file_path: P, options: Option<PutObjectOptions>) -> Result<PutObjectResult>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send,
-        S3: AsRef<str> + Send,
+        P: AsRef<Path> + Send,
     {
-        let data = if let Ok(d) = BASE64_STANDARD.decode(base64_string.as_ref()) {
-            d
-        } else {
-            return Err(Error::Other("Decoding base64 string failed".to_string()));
-        };
-
-        self.put_object_from_buffer(bucket_name, object_key, data, options).await
+        self.put_object(bucket_name, object_key, PutSource::File(file_path.as_ref().to_path_buf()), options)
+            .await
     }
 
-    /// Append object.
+    /// Create an object from buffer. If you are going to upload a large file, it is recommended to use `upload_file` instead.
+    /// And, it is recommended to set `mime_type` in `options`
     ///
-    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/appendobject>
-    async fn append_object_from_file<S1, S2, P>(
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
+    async fn put_object_from_buffer<S1, S2, B>(&self, bucket_name: S1, object_key: S2, buffer: B, options: Option<PutObjectOptions>) -> Result<PutObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        B: Into<Vec<u8>> + Send,
+    {
+        self.put_object(bucket_name, object_key, PutSource::Buffer(buffer.into()), options).await
+    }
+
+    /// Same as [`Self::put_object_from_file`], and returns the request id, status and elapsed time of the response
+    async fn put_object_from_file_with_meta<S1, S2, P>(
         &self,
         bucket_name: S1,
         object_key: S2,
         file_path: P,
-        position: u64,
-        options: Option<AppendObjectOptions>,
-    ) -> Result<AppendObjectResult>
+        options: Option<PutObjectOptions>,
+    ) -> Result<(PutObjectResult, ResponseMeta)>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send,
         P: AsRef<Path> + Send,
     {
-        let bucket_name = bucket_name.as_ref();
-        let object_key = object_key.as_ref();
+        self.put_object_with_meta(bucket_name, object_key, PutSource::File(file_path.as_ref().to_path_buf()), options)
+            .await
+    }
+
+    /// Same as [`Self::put_object_from_buffer`], and returns the request id, status and elapsed time of the response
+    async fn put_object_from_buffer_with_meta<S1, S2, B>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        buffer: B,
+        options: Option<PutObjectOptions>,
+    ) -> Result<(PutObjectResult, ResponseMeta)>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        B: Into<Vec<u8>> + Send,
+    {
+        self.put_object_with_meta(bucket_name, object_key, PutSource::Buffer(buffer.into()), options)
+            .await
+    }
+
+    /// Create an object from several in-memory fragments. The fragments are sent in order without being concatenated,
+    /// so no extra allocation is needed when the data is already split.
+    /// And, it is recommended to set `mime_type` in `options`
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
+    async fn put_object_from_chunks<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        chunks: Vec<bytes::Bytes>,
+        options: Option<PutObjectOptions>,
+    ) -> Result<PutObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        self.put_object(bucket_name, object_key, PutSource::Chunks(chunks), options).await
+    }
+
+    /// Create an object from base64 string.
+    /// And, it is recommended to set `mime_type` in `options`
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
+    async fn put_object_from_base64<S1, S2, S3>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        base64_string: S3,
+        options: Option<PutObjectOptions>,
+    ) -> Result<PutObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        S3: AsRef<str> + Send,
+    {
+        self.put_object(bucket_name, object_key, PutSource::Base64(base64_string.as_ref().to_string()), options)
+            .await
+    }
+
+    /// Append object from any supported source: local file, buffer, bytes or base64 string.
+    /// When `position` is `0`, the appendable object is created with `mime_type`, `traffic_limit` etc. in `options`
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/appendobject>
+    async fn append_object<S1, S2, T>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        source: T,
+        position: u64,
+        options: Option<AppendObjectOptions>,
+    ) -> Result<AppendObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        T: Into<PutSource> + Send,
+    {
+        let bucket_name = bucket_name.as_ref();
+        let object_key = object_key.as_ref();
 
         let object_key = object_key.strip_prefix("/").unwrap_or(object_key);
         let object_key = object_key.strip_suffix("/").unwrap_or(object_key);
 
-        let file_path = file_path.as_ref();
+        let body = put_source_into_request_body(source.into()).await?;
+        let mut options = options;
+        fill_content_md5(&mut options, &body).await?;
 
-        let mut request = build_put_object_request(bucket_name, object_key, RequestBody::File(file_path.to_path_buf(), None), &options)?;
+        let mut request = build_put_object_request(bucket_name, object_key, body, &options)?;
 
         // alter the request method and add append object query parameters
         request = request
@@ -348,6 +765,27 @@ impl ObjectOperations for Client {
         Ok(headers.into())
     }
 
+    /// Append object.
+    /// When `position` is `0`, the appendable object is created with `mime_type`, `traffic_limit` etc. in `options`
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/appendobject>
+    async fn append_object_from_file<S1, S2, P>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        file_path: P,
+        position: u64,
+        options: Option<AppendObjectOptions>,
+    ) -> Result<AppendObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        P: AsRef<Path> + Send,
+    {
+        self.append_object(bucket_name, object_key, PutSource::File(file_path.as_ref().to_path_buf()), position, options)
+            .await
+    }
+
     /// Append object from buffer. suitable for small size content
     /// And, it is recommended to set `mime_type` in `options`
     ///
@@ -365,23 +803,8 @@ impl ObjectOperations for Client {
         S2: AsRef<str> + Send,
         B: Into<Vec<u8>> + Send,
     {
-        let bucket_name = bucket_name.as_ref();
-        let object_key = object_key.as_ref();
-
-        let object_key = object_key.strip_prefix("/").unwrap_or(object_key);
-        let object_key = object_key.strip_suffix("/").unwrap_or(object_key);
-
-        let mut request = build_put_object_request(bucket_name, object_key, RequestBody::Bytes(buffer.into()), &options)?;
-
-        // alter the request method and add append object query parameters
-        request = request
-            .method(RequestMethod::Post)
-            .add_query("append", "")
-            .add_query("position", position.to_string());
-
-        let (headers, _) = self.do_request::<()>(request).await?;
-
-        Ok(headers.into())
+        self.append_object(bucket_name, object_key, PutSource::Buffer(buffer.into()), position, options)
+            .await
     }
 
     /// Append object from base64 string. suitable for small size content
@@ -401,13 +824,14 @@ impl ObjectOperations for Client {
         S2: AsRef<str> + Send,
         S3: AsRef<str> + Send,
     {
-        let data = if let Ok(d) = BASE64_STANDARD.decode(base64_string.as_ref()) {
-            d
-        } else {
-            return Err(Error::Other("Decoding base64 string failed".to_string()));
-        };
-
-        self.append_object_from_buffer(bucket_name, object_key, data, position, options).await
+        self.append_object(
+            bucket_name,
+            object_key,
+            PutSource::Base64(base64_string.as_ref().to_string()),
+            position,
+            options,
+        )
+        .await
     }
 
     /// Download oss object to local file.
@@ -425,6 +849,11 @@ impl ObjectOperations for Client {
         let object_key = object_key.as_ref();
         let file_path = file_path.as_ref();
 
+        let check_crc64 = options.as_ref().is_some_and(|o| o.verify_crc64);
+        if check_crc64 {
+            check_verified_download_options(&options)?;
+        }
+
         let file_path = if file_path.is_relative() {
             file_path.canonicalize()?
         } else {
@@ -444,17 +873,182 @@ impl ObjectOperations for Client {
 
         let request = build_get_object_request(bucket_name, object_key, &options)?;
 
-        let (_, mut stream) = self.do_request::<ByteStream>(request).await?;
+        let (headers, mut stream) = self.do_request::<ByteStream>(request).await?;
 
         let mut file = tokio::fs::File::create(&file_path).await?;
+        let mut crc = Crc64::new();
 
         while let Some(chunk) = stream.try_next().await? {
+            if check_crc64 {
+                crc.update(&chunk);
+            }
             file.write_all(&chunk).await?;
         }
 
         file.flush().await?;
 
-        Ok(GetObjectResult)
+        let metadata = ObjectMetadata::from(headers);
+
+        if check_crc64 {
+            verify_crc64(metadata.hash_crc64ecma, crc.finish())?;
+        }
+
+        Ok(GetObjectResult { metadata })
+    }
+
+    /// Download oss object to local file and resume from the received bytes if the connection is broken.
+    /// `file_path` is the full file path to save.
+    /// If the `file_path` parent path does not exist, it will be created
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobject>
+    async fn get_object_to_file_resilient<S1, S2, P>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        file_path: P,
+        options: Option<GetObjectOptions>,
+        max_retries: u32,
+    ) -> Result<GetObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        P: AsRef<Path> + Send,
+    {
+        check_resilient_download_options(&options)?;
+
+        let bucket_name = bucket_name.as_ref();
+        let object_key = object_key.as_ref();
+        let file_path = file_path.as_ref();
+
+        let file_path = if file_path.is_relative() {
+            file_path.canonicalize()?
+        } else {
+            file_path.to_path_buf()
+        };
+
+        if !validate_path(&file_path) {
+            return Err(Error::Other(format!("invalid file path: {:?}", file_path.as_os_str().to_str())));
+        }
+
+        // check parent path
+        if let Some(parent_path) = file_path.parent() {
+            if !parent_path.exists() {
+                std::fs::create_dir_all(parent_path)?;
+            }
+        }
+
+        let mut file = tokio::fs::File::create(&file_path).await?;
+        let mut written = 0u64;
+        let mut etag: Option<String> = None;
+        let mut metadata: Option<ObjectMetadata> = None;
+        let mut retries = 0u32;
+        let check_crc64 = options.as_ref().is_some_and(|o| o.verify_crc64);
+        let mut crc = Crc64::new();
+
+        loop {
+            let ret: Result<()> = async {
+                let opts = build_resume_get_object_options(&options, written, etag.as_deref())?;
+                let request = build_get_object_request(bucket_name, object_key, &opts)?;
+                let (headers, mut stream) = self.do_request::<ByteStream>(request).await?;
+
+                if metadata.is_none() {
+                    etag = headers.get("etag").cloned();
+                    metadata = Some(ObjectMetadata::from(headers));
+                }
+
+                while let Some(chunk) = stream.try_next().await? {
+                    file.write_all(&chunk).await?;
+                    written += chunk.len() as u64;
+                    // 写入成功之后再计算，避免续传时同一个分块被重复计算
+                    if check_crc64 {
+                        crc.update(&chunk);
+                    }
+                }
+
+                Ok(())
+            }
+            .await;
+
+            match ret {
+                Ok(_) => break,
+                // only network errors are worth retrying, api errors such as `412` are returned directly
+                Err(Error::ReqwestError(_)) if retries < max_retries => retries += 1,
+                Err(e) => return Err(e),
+            }
+        }
+
+        file.flush().await?;
+
+        let metadata = metadata.unwrap_or_default();
+
+        if check_crc64 {
+            verify_crc64(metadata.hash_crc64ecma, crc.finish())?;
+        }
+
+        Ok(GetObjectResult { metadata })
+    }
+
+    /// Download object and write the content to `writer` chunk by chunk, e.g. an in-memory buffer,
+    /// a hashing writer or a compression encoder, without landing on disk.
+    /// The response body is fully drained and the writer is flushed before returning.
+    /// Errors while writing are returned as `Error::IoError`.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobject>
+    async fn get_object_to_writer<S1, S2, W>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        writer: &mut W,
+        options: Option<GetObjectOptions>,
+    ) -> Result<GetObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        W: AsyncWrite + Unpin + Send,
+    {
+        let check_crc64 = options.as_ref().is_some_and(|o| o.verify_crc64);
+        if check_crc64 {
+            check_verified_download_options(&options)?;
+        }
+
+        let request = build_get_object_request(bucket_name.as_ref(), object_key.as_ref(), &options)?;
+
+        let (headers, mut stream) = self.do_request::<ByteStream>(request).await?;
+        let mut crc = Crc64::new();
+
+        while let Some(chunk) = stream.try_next().await? {
+            if check_crc64 {
+                crc.update(&chunk);
+            }
+            writer.write_all(&chunk).await?;
+        }
+
+        writer.flush().await?;
+
+        let metadata = ObjectMetadata::from(headers);
+
+        if check_crc64 {
+            verify_crc64(metadata.hash_crc64ecma, crc.finish())?;
+        }
+
+        Ok(GetObjectResult { metadata })
+    }
+
+    /// Get object and return the metadata parsed from the response headers together with the response body stream,
+    /// so the caller can consume a large object lazily and apply backpressure.
+    /// Errors while reading the stream are `reqwest::Error`.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobject>
+    async fn get_object_stream<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectOptions>) -> Result<(ObjectMetadata, ByteStream)>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let request = build_get_object_request(bucket_name.as_ref(), object_key.as_ref(), &options)?;
+
+        let (headers, stream) = self.do_request::<ByteStream>(request).await?;
+
+        Ok((ObjectMetadata::from(headers), stream))
     }
 
     /// Get object content into memory (bytes array).
@@ -483,38 +1077,74 @@ impl ObjectOperations for Client {
         Ok(buf)
     }
 
-    /// Create a "folder".
-    /// The `object_key` must ends with `/`
+    /// Get object content into memory and verify it with the `x-oss-hash-crc64ecma` response header.
+    /// Returns `Error::CrcMismatch` if the data is corrupted. Range request is not supported.
+    ///
+    /// Large files can consume significant memory, exercise caution when using this function.
+    async fn get_object_to_verified_buffer<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        options: Option<GetObjectOptions>,
+    ) -> Result<(ObjectMetadata, Vec<u8>)>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        check_verified_download_options(&options)?;
+
+        let request = build_get_object_request(bucket_name.as_ref(), object_key.as_ref(), &options)?;
+
+        let (headers, mut stream) = self.do_request::<ByteStream>(request).await?;
+
+        let mut crc = Crc64::new();
+        let mut buf = Vec::new();
+
+        while let Some(chunk) = stream.try_next().await? {
+            crc.update(&chunk);
+            buf.extend_from_slice(&chunk);
+        }
+
+        let metadata = ObjectMetadata::from(headers);
+        verify_crc64(metadata.hash_crc64ecma, crc.finish())?;
+
+        Ok((metadata, buf))
+    }
+
+    /// Create a "folder". The leading `/` of `object_key` is removed and a trailing `/` is appended if absent
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
-    async fn create_folder<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<()>
+    async fn create_folder<S1, S2>(&self, bucket_name: S1, object_key: S2, fail_if_exists: bool) -> Result<CreateFolderResult>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send,
     {
         let bucket_name = bucket_name.as_ref();
         let object_key = object_key.as_ref();
-        let object_key = object_key.strip_prefix("/").unwrap_or(object_key);
-        let object_key = if object_key.ends_with("/") {
-            object_key.to_string()
-        } else {
-            format!("{}/", object_key)
-        };
 
-        if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        let request = build_create_folder_request(bucket_name, object_key, fail_if_exists)?;
+
+        if fail_if_exists && self.folder_exists(bucket_name, object_key).await? {
+            return Err(Error::Other(format!("folder already exists: {}", object_key)));
         }
 
-        let request = OssRequest::new()
-            .method(RequestMethod::Put)
-            .bucket(bucket_name)
-            .object(object_key)
-            .body(RequestBody::Empty)
-            .content_length(0);
+        let (headers, _) = self.do_request::<()>(request).await?;
 
-        let _ = self.do_request::<()>(request).await?;
+        Ok(headers.into())
+    }
 
-        Ok(())
+    /// Check if any object exists under the "folder" by listing at most one object with the folder prefix
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/listobjectsv2>
+    async fn folder_exists<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<bool>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let options = build_folder_exists_options(object_key.as_ref())?;
+        let ret = self.list_objects(bucket_name, Some(options)).await?;
+
+        Ok(!ret.contents.is_empty())
     }
 
     /// Delete a "folder". if the folder contains any object, it will not be deleted
@@ -525,20 +1155,7 @@ impl ObjectOperations for Client {
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send,
     {
-        let bucket_name = bucket_name.as_ref();
-        let object_key = object_key.as_ref();
-        let object_key = object_key.strip_prefix("/").unwrap_or(object_key);
-        let object_key = if object_key.ends_with("/") {
-            object_key.to_string()
-        } else {
-            format!("{}/", object_key)
-        };
-
-        if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
-        }
-
-        let request = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).object(object_key);
+        let request = build_delete_folder_request(bucket_name.as_ref(), object_key.as_ref())?;
 
         let _ = self.do_request::<()>(request).await?;
 
@@ -561,7 +1178,7 @@ impl ObjectOperations for Client {
         }
 
         if !validate_object_key(object_key) {
-            return Err(Error::Other(format!("invalid object key: {}", object_key)));
+            return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
         }
 
         let mut request = OssRequest::new()
@@ -590,10 +1207,23 @@ impl ObjectOperations for Client {
     {
         match self.get_object_metadata(bucket_name, object_key, options).await {
             Ok(_) => Ok(true),
-            Err(e) => match e {
-                Error::StatusError(status) if status == StatusCode::NOT_FOUND => Ok(false),
-                _ => Err(e),
-            },
+            Err(e) if e.is_not_found() => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get the object type using head object. Returns `None` if the object does not exist.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/headobject>
+    async fn object_type<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<Option<ObjectType>>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        match self.head_object(bucket_name, object_key, None).await {
+            Ok(meta) => Ok(meta.object_type),
+            Err(e) if e.is_not_found() => Ok(None),
+            Err(e) => Err(e),
         }
     }
 
@@ -615,6 +1245,7 @@ impl ObjectOperations for Client {
     }
 
     /// Copy files (Objects) between the same or different Buckets within the same region.
+    /// If the copy fails because the buckets are in different regions, `Error::CrossRegionCopyUnsupported` is returned.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/copyobject>
     async fn copy_object<S1, S2, S3, S4>(
@@ -631,6 +1262,14 @@ impl ObjectOperations for Client {
         S3: AsRef<str> + Send,
         S4: AsRef<str> + Send,
     {
+        let options = match options {
+            Some(opt) if opt.preserve_source => Some(
+                self.resolve_preserved_copy_source(source_bucket_name.as_ref(), source_object_key.as_ref(), dest_bucket_name.as_ref(), opt)
+                    .await?,
+            ),
+            _ => options,
+        };
+
         let request = build_copy_object_request(
             source_bucket_name.as_ref(),
             source_object_key.as_ref(),
@@ -639,15 +1278,196 @@ impl ObjectOperations for Client {
             &options,
         )?;
 
-        let (_, _) = self.do_request::<()>(request).await?;
+        let (headers, content) = match self.do_request::<String>(request).await {
+            Ok(v) => v,
+            Err(e) => {
+                // CopyObject fails with a confusing error if the buckets are in different regions.
+                // Only check bucket locations after the request failed, so the successful path costs nothing extra
+                // A copy within the same bucket is never cross region
+                if matches!(e, Error::ApiError(_)) && source_bucket_name.as_ref() != dest_bucket_name.as_ref() {
+                    if let (Ok(source_location), Ok(dest_location)) = (
+                        self.get_bucket_location(source_bucket_name.as_ref()).await,
+                        self.get_bucket_location(dest_bucket_name.as_ref()).await,
+                    ) {
+                        check_copy_region(&source_location, &dest_location)?;
+                    }
+                }
+
+                return Err(e);
+            }
+        };
+
+        CopyObjectResult::from_response(headers, &content)
+    }
+
+    /// Copy object with CopyObject or UploadPartCopy according to the size of the source object
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/uploadpartcopy>
+    async fn copy_object_auto<S1, S2, S3, S4>(
+        &self,
+        source_bucket_name: S1,
+        source_object_key: S2,
+        dest_bucket_name: S3,
+        dest_object_key: S4,
+        options: Option<CopyObjectOptions>,
+    ) -> Result<CopyObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        S3: AsRef<str> + Send,
+        S4: AsRef<str> + Send,
+    {
+        let source_bucket_name = source_bucket_name.as_ref();
+        let source_object_key = source_object_key.as_ref();
+        let dest_bucket_name = dest_bucket_name.as_ref();
+        let dest_object_key = dest_object_key.as_ref();
+
+        let source_version_id = options.as_ref().and_then(|opt| opt.source_version_id.clone());
+        let head_options = source_version_id.clone().map(HeadObjectOptions::with_version_id);
+        let source_metadata = self.head_object(source_bucket_name, source_object_key, head_options).await?;
+
+        // 已经读取了源 Object 的元数据，保持存储类型时不需要再次 HeadObject
+        let mut options = options;
+        if let Some(opt) = options.as_mut().filter(|opt| opt.preserve_source && opt.storage_class.is_none()) {
+            opt.storage_class = source_metadata.storage_class;
+        }
+
+        if source_metadata.content_length < MAX_COPY_OBJECT_SIZE {
+            return self
+                .copy_object(source_bucket_name, source_object_key, dest_bucket_name, dest_object_key, options)
+                .await;
+        }
+
+        let need_source_tags = !matches!(options.as_ref().and_then(|opt| opt.tag_directive), Some(TagDirective::Replace));
+        let source_tags = if need_source_tags && source_metadata.tag_count.unwrap_or(0) > 0 {
+            self.get_object_tags(
+                source_bucket_name,
+                source_object_key,
+                source_version_id.clone().map(GetObjectTagOptions::with_version_id),
+            )
+            .await?
+        } else {
+            HashMap::new()
+        };
+
+        let options = match options {
+            Some(opt) if opt.preserve_source => Some(
+                self.resolve_preserved_copy_source(source_bucket_name, source_object_key, dest_bucket_name, opt)
+                    .await?,
+            ),
+            _ => options,
+        };
+
+        let (initiate_options, part_copy_options) = build_copy_multipart_options(&source_metadata, source_tags, &options);
+
+        let upload_id = self
+            .initiate_multipart_uploads(dest_bucket_name, dest_object_key, Some(initiate_options))
+            .await?
+            .upload_id;
+
+        let mut parts = vec![];
+        for (part_number, range) in build_copy_part_ranges(source_metadata.content_length) {
+            let data = UploadPartCopyRequest::new(part_number, &upload_id, source_object_key).source_bucket(source_bucket_name);
+            let part_options = UploadPartCopyOptions {
+                copy_source_range: Some(range),
+                ..part_copy_options.clone()
+            };
+
+            match self.upload_part_copy(dest_bucket_name, dest_object_key, data, Some(part_options)).await {
+                Ok(ret) => parts.push((part_number, ret.etag)),
+                Err(e) => {
+                    // 尽力清理已经拷贝的分片，清理失败时返回原始的错误
+                    let _ = self.abort_multipart_uploads(dest_bucket_name, dest_object_key, &upload_id).await;
+                    return Err(e);
+                }
+            }
+        }
+
+        let data = CompleteMultipartUploadRequest {
+            upload_id: upload_id.clone(),
+            parts,
+        };
+        let complete_result = match self.complete_multipart_uploads(dest_bucket_name, dest_object_key, data, None).await {
+            Ok(ret) => ret,
+            Err(e) => {
+                let _ = self.abort_multipart_uploads(dest_bucket_name, dest_object_key, &upload_id).await;
+                return Err(e);
+            }
+        };
+
+        let mut result = CopyObjectResult {
+            source_version_id,
+            ..Default::default()
+        };
+
+        let ret = complete_result.api_response();
+        result.etag = ret.etag.clone();
+        result.version_id = ret.version_id.clone();
+        result.request_id = ret.request_id.clone();
+
+        Ok(result)
+    }
+
+    /// Rename an object by copying it to `to_object_key` (multipart copy for large objects, see `copy_object_auto`)
+    /// and deleting `from_object_key` only after the copy succeeds. It is not atomic:
+    /// both objects exist for a short while, and the new object is kept if deleting the source fails.
+    ///
+    /// If `source_version_id` is set in `options`, that version of the source object is permanently deleted
+    /// instead of adding a delete marker to the current version.
+    ///
+    /// With `forbid_overwrite` set in `options`, an error is returned if `to_object_key` already exists.
+    /// Returns the metadata of the new object.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/copyobject>
+    async fn rename_object<S1, S2, S3>(
+        &self,
+        bucket_name: S1,
+        from_object_key: S2,
+        to_object_key: S3,
+        options: Option<CopyObjectOptions>,
+    ) -> Result<ObjectMetadata>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        S3: AsRef<str> + Send,
+    {
+        let bucket_name = bucket_name.as_ref();
+        let from_object_key = from_object_key.as_ref();
+        let to_object_key = to_object_key.as_ref();
+
+        check_rename_object_keys(from_object_key, to_object_key)?;
+
+        // 服务端也会校验 `x-oss-forbid-overwrite`，这里提前检查，避免大文件在分片拷贝完成之后才失败
+        if options.as_ref().and_then(|opt| opt.forbid_overwrite).unwrap_or(false) && self.exists(bucket_name, to_object_key, None).await? {
+            return Err(Error::Other(format!("object already exists: {}", to_object_key)));
+        }
 
-        Ok(CopyObjectResult)
+        let delete_options = build_rename_delete_options(&options);
+        self.copy_object_auto(bucket_name, from_object_key, bucket_name, to_object_key, options).await?;
+        self.delete_object(bucket_name, from_object_key, delete_options).await?;
+
+        self.head_object(bucket_name, to_object_key, None).await
     }
 
     /// Delete an object
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deleteobject>
     async fn delete_object<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<DeleteObjectOptions>) -> Result<DeleteObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let (result, _) = self.delete_object_with_meta(bucket_name, object_key, options).await?;
+        Ok(result)
+    }
+
+    /// Same as [`Self::delete_object`], and returns the request id, status and elapsed time of the response
+    async fn delete_object_with_meta<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        options: Option<DeleteObjectOptions>,
+    ) -> Result<(DeleteObjectResult, ResponseMeta)>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send,
@@ -660,7 +1480,7 @@ impl ObjectOperations for Client {
         }
 
         if !validate_object_key(object_key) {
-            return Err(Error::Other(format!("invalid object key: {}", object_key)));
+            return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
         }
 
         let mut request = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).object(object_key);
@@ -671,26 +1491,77 @@ impl ObjectOperations for Client {
             }
         }
 
-        let _ = self.do_request::<()>(request).await?;
+        let (_, _, meta) = self.do_request_with_meta::<()>(request).await?;
+
+        Ok((DeleteObjectResult, meta))
+    }
+
+    /// Delete multiple objects
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletemultipleobjects>
+    async fn delete_multiple_objects<'c, S1, S2>(&self, bucket_name: S1, config: DeleteMultipleObjectsConfig<'c, S2>) -> Result<DeleteMultipleObjectsResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send + Sync,
+    {
+        let bucket_name = bucket_name.as_ref();
+
+        let request = build_delete_multiple_objects_request(bucket_name, config)?;
+
+        let (_, content) = self.do_request::<String>(request).await?;
+
+        DeleteMultipleObjectsResult::from_xml(&content)
+    }
+
+    /// Permanently delete all versions and delete markers of an object in a versioning enabled bucket.
+    /// Returns the number of deleted versions (including delete markers)
+    async fn delete_all_versions<S1, S2>(&self, bucket_name: S1, object_key: S2) -> Result<usize>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let bucket_name = bucket_name.as_ref();
+        let object_key = object_key.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        }
+
+        if !validate_object_key(object_key) {
+            return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
+        }
+
+        let mut version_ids = vec![];
+        let mut options = ListObjectVersionsOptions {
+            prefix: Some(object_key.to_string()),
+            ..Default::default()
+        };
+
+        loop {
+            let page = self.list_object_versions(bucket_name, Some(options.clone())).await?;
+
+            version_ids.extend(page.versions.into_iter().filter(|v| v.key == object_key).map(|v| v.version_id));
+            version_ids.extend(page.delete_markers.into_iter().filter(|m| m.key == object_key).map(|m| m.version_id));
 
-        Ok(DeleteObjectResult)
-    }
+            // 结果按 key 排序，下一页的起点已经越过了目标 key，不会再有它的版本
+            if !page.is_truncated || page.next_key_marker.as_deref() != Some(object_key) {
+                break;
+            }
 
-    /// Delete multiple objects
-    ///
-    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletemultipleobjects>
-    async fn delete_multiple_objects<'c, S1, S2>(&self, bucket_name: S1, config: DeleteMultipleObjectsConfig<'c, S2>) -> Result<DeleteMultipleObjectsResult>
-    where
-        S1: AsRef<str> + Send,
-        S2: AsRef<str> + Send + Sync,
-    {
-        let bucket_name = bucket_name.as_ref();
+            options.key_marker = page.next_key_marker;
+            options.version_id_marker = page.next_version_id_marker;
+        }
 
-        let request = build_delete_multiple_objects_request(bucket_name, config)?;
+        let mut deleted = 0;
 
-        let (_, content) = self.do_request::<String>(request).await?;
+        for request in build_delete_versions_requests(object_key, version_ids) {
+            let result = self
+                .delete_multiple_objects::<_, &str>(bucket_name, DeleteMultipleObjectsConfig::FullRequest(request))
+                .await?;
+            deleted += result.items.len();
+        }
 
-        DeleteMultipleObjectsResult::from_xml(&content)
+        Ok(deleted)
     }
 
     /// Restore object
@@ -706,6 +1577,42 @@ impl ObjectOperations for Client {
         Ok(headers.into())
     }
 
+    /// Query CSV or JSON object with SQL and stream the matching records, without downloading the whole object
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/selectobject>
+    async fn select_object<S1, S2>(&self, bucket_name: S1, object_key: S2, select_request: SelectRequest) -> Result<SelectObjectStream>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let request = build_select_object_request(bucket_name.as_ref(), object_key.as_ref(), &select_request)?;
+        let (_, stream) = self.do_request::<ByteStream>(request).await?;
+
+        let decoder = SelectFrameDecoder::new(select_request.output_serialization.enable_payload_crc);
+        let scanned = Arc::new(OnceLock::new());
+
+        let records = futures::stream::try_unfold((stream, decoder, scanned.clone()), |(mut stream, mut decoder, scanned)| async move {
+            loop {
+                match decoder.next_frame()? {
+                    Some(SelectFrame::Data(data)) if !data.is_empty() => return Ok(Some((data, (stream, decoder, scanned)))),
+                    Some(SelectFrame::End { scanned_bytes }) => {
+                        oss_log!(debug, { scanned_bytes }, "select object finished, scanned bytes: {}", scanned_bytes);
+                        let _ = scanned.set(scanned_bytes);
+                        return Ok(None);
+                    }
+                    Some(_) => {}
+                    None if decoder.is_finished() => return Ok(None),
+                    None => match stream.next().await {
+                        Some(chunk) => decoder.push(&chunk?),
+                        None => return Err(Error::Other("select object response ended without end frame".to_string())),
+                    },
+                }
+            }
+        });
+
+        Ok(SelectObjectStream::new(Box::pin(records), scanned))
+    }
+
     /// Clean retored object
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/cleanrestoredobject>
@@ -722,7 +1629,7 @@ impl ObjectOperations for Client {
         }
 
         if !validate_object_key(object_key) {
-            return Err(Error::Other(format!("invalid object key: {}", object_key)));
+            return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
         }
 
         let request = OssRequest::new()
@@ -735,6 +1642,57 @@ impl ObjectOperations for Client {
 
         Ok(())
     }
+
+    /// Restore all `Archive`, `ColdArchive` and `DeepColdArchive` objects under the prefix.
+    /// At most `concurrency` restore requests are sent at the same time.
+    async fn restore_prefix<S1, S2>(&self, bucket_name: S1, prefix: S2, config: RestoreObjectRequest, concurrency: usize) -> Result<RestorePrefixResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let bucket_name = bucket_name.as_ref();
+
+        let options = ListObjectsOptions {
+            prefix: Some(prefix.as_ref().to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+
+        let keys = list_objects_pages(self, bucket_name.to_string(), options)
+            .map_ok(|page| futures::stream::iter(page.contents.into_iter().map(Ok::<_, Error>)))
+            .try_flatten()
+            .try_filter(|obj| futures::future::ready(need_restore(&obj.storage_class)))
+            .map_ok(|obj| obj.key)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let config = RestoreObjectRequest { version_id: None, ..config };
+
+        let mut results = futures::stream::iter(keys)
+            .map(|key| {
+                let config = config.clone();
+                async move {
+                    let ret = self.restore_object(bucket_name, key.as_str(), config).await;
+                    (key, ret)
+                }
+            })
+            .buffer_unordered(concurrency.max(1));
+
+        let mut ret = RestorePrefixResult::default();
+
+        while let Some((key, r)) = results.next().await {
+            match r {
+                Ok(_) => ret.submitted += 1,
+                Err(e) if is_restore_already_in_progress(&e) => ret.already_in_progress += 1,
+                Err(e) => {
+                    oss_log!(error, "restore object {} failed: {}", key, e);
+                    ret.failed += 1;
+                    ret.failed_keys.push(key);
+                }
+            }
+        }
+
+        Ok(ret)
+    }
 }
 
 #[cfg(test)]
@@ -745,11 +1703,14 @@ mod test_object_async {
     use uuid::Uuid;
 
     use crate::{
-        common::{ObjectType, StorageClass},
+        acl::ObjectAclOperations,
+        common::{ObjectType, StorageClass, WithVersionId},
+        error::Error,
         object::ObjectOperations,
         object_common::{
-            CallbackBodyParameter, CallbackBuilder, DeleteMultipleObjectsConfig, GetObjectOptionsBuilder, PutObjectApiResponse, PutObjectOptions,
-            PutObjectOptionsBuilder, PutObjectResult, RestoreObjectRequest,
+            CallbackBodyParameter, CallbackBuilder, CopyObjectOptionsBuilder, DeleteMultipleObjectsConfig, DeleteObjectOptions, GetObjectMetadataOptions,
+            GetObjectOptions, GetObjectOptionsBuilder, HeadObjectOptions, PutObjectApiResponse, PutObjectOptions, PutObjectOptionsBuilder, PutObjectResult,
+            RestoreObjectRequest,
         },
         util, Client,
     };
@@ -902,13 +1863,41 @@ mod test_object_async {
 
         let client = Client::from_env();
 
-        let result = client.create_folder("yuanyq", "rust-sdk-test/test-folder/").await;
+        let result = client.create_folder("yuanyq", "rust-sdk-test/test-folder/", false).await;
 
         log::debug!("{:?}", result);
 
         assert!(result.is_ok())
     }
 
+    #[tokio::test]
+    async fn test_create_folder_fail_if_exists_async() {
+        setup();
+
+        let client = Client::from_env();
+
+        let bucket = "yuanyq";
+        let folder = format!("rust-sdk-test/{}", Uuid::new_v4());
+
+        assert!(!client.folder_exists(bucket, &folder).await.unwrap());
+
+        // 没有目录标记，但是目录下有文件
+        let object = format!("{}/a.txt", folder);
+        client.put_object_from_buffer(bucket, &object, b"hello".to_vec(), None).await.unwrap();
+        assert!(client.folder_exists(bucket, &folder).await.unwrap());
+        assert!(!client.exists(bucket, format!("{}/", folder), None).await.unwrap());
+        assert!(client.create_folder(bucket, &folder, true).await.is_err());
+
+        client.delete_object(bucket, &object, None).await.unwrap();
+
+        client.create_folder(bucket, &folder, true).await.unwrap();
+        assert!(client.folder_exists(bucket, &folder).await.unwrap());
+        assert!(client.create_folder(bucket, &folder, true).await.is_err());
+        assert!(client.create_folder(bucket, &folder, false).await.is_ok());
+
+        client.delete_folder(bucket, &folder).await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_delete_folder_async() {
         log::debug!("test delete folder");
@@ -919,7 +1908,7 @@ mod test_object_async {
         let bucket = "yuanyq";
         let object = format!("rust-sdk-test/{}/", Uuid::new_v4());
 
-        client.create_folder(bucket, &object).await.unwrap();
+        client.create_folder(bucket, &object, false).await.unwrap();
 
         let response = client.delete_folder(bucket, &object).await;
         assert!(response.is_ok());
@@ -942,10 +1931,47 @@ mod test_object_async {
         let md5_hash = util::file_md5(&output_file);
         assert_eq!("pIPky6/KtraaoNqF76ia8Q==", md5_hash);
 
+        let metadata = result.unwrap().metadata;
+        assert_eq!(std::fs::metadata(&output_file).unwrap().len(), metadata.content_length);
+        log::debug!("server side encryption: {:?}", metadata.server_side_encryption);
+
         std::fs::remove_file(&output_file).unwrap();
         log::debug!("local file {} is deleted", output_file);
     }
 
+    #[tokio::test]
+    async fn test_get_object_to_writer_async() {
+        setup();
+        let client = Client::from_env();
+
+        let mut buf: Vec<u8> = vec![];
+        let result = client.get_object_to_writer("yuanyq", "rust-sdk-test/katex.zip", &mut buf, None).await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().metadata.content_length, buf.len() as u64);
+        assert_eq!("pIPky6/KtraaoNqF76ia8Q==", util::content_md5_base64(&buf));
+    }
+
+    #[tokio::test]
+    async fn test_get_object_stream_async() {
+        setup();
+        let client = Client::from_env();
+
+        let (metadata, mut stream) = client.get_object_stream("yuanyq", "rust-sdk-test/katex.zip", None).await.unwrap();
+
+        use futures::TryStreamExt;
+
+        let mut len = 0u64;
+        let mut hasher = md5::Context::new();
+        while let Some(chunk) = stream.try_next().await.unwrap() {
+            len += chunk.len() as u64;
+            hasher.consume(&chunk);
+        }
+
+        assert_eq!(metadata.content_length, len);
+        assert_eq!("pIPky6/KtraaoNqF76ia8Q==", BASE64_STANDARD.encode(hasher.compute().0));
+    }
+
     /// Download range of file
     #[tokio::test]
     async fn test_download_file_2_async() {
@@ -970,6 +1996,25 @@ mod test_object_async {
         std::fs::remove_file(&output_file).unwrap();
     }
 
+    #[tokio::test]
+    async fn test_get_object_to_file_resilient_async() {
+        setup();
+        let client = Client::from_env();
+
+        let output_file = "/home/yuanyq/Downloads/ali-oss-rs-test/katex.resilient.zip";
+
+        let result = client
+            .get_object_to_file_resilient("yuanyq", "rust-sdk-test/katex.zip", output_file, None, 3)
+            .await;
+
+        assert!(result.is_ok());
+
+        let meta = client.head_object("yuanyq", "rust-sdk-test/katex.zip", None).await.unwrap();
+        assert_eq!(meta.content_length, std::fs::metadata(output_file).unwrap().len());
+
+        std::fs::remove_file(output_file).unwrap();
+    }
+
     /// Test invalid output file name
     #[tokio::test]
     async fn test_download_file_3_async() {
@@ -1024,6 +2069,39 @@ mod test_object_async {
         assert_eq!(Some("Tue, 18 Feb 2025 15:03:23 GMT".to_string()), meta.last_modified);
     }
 
+    #[tokio::test]
+    async fn test_get_object_to_verified_buffer_async() {
+        setup();
+        let client = Client::from_env();
+
+        let (meta, buf) = client
+            .get_object_to_verified_buffer("yuanyq", "rust-sdk-test/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack", None)
+            .await
+            .unwrap();
+
+        assert_eq!(22966826, buf.len());
+        assert_eq!(Some(7873641174252289613u64), meta.hash_crc64ecma);
+    }
+
+    #[tokio::test]
+    async fn test_verify_crc64_async() {
+        setup();
+        let client = Client::from_env();
+
+        let object_key = format!("rust-sdk-test/{}.txt", Uuid::new_v4());
+        let options = PutObjectOptionsBuilder::new().verify_crc64(true).build();
+        let ret = client.put_object_from_buffer("yuanyq", &object_key, b"hello world".to_vec(), Some(options)).await;
+        assert!(ret.is_ok());
+
+        let mut buf: Vec<u8> = vec![];
+        let options = GetObjectOptionsBuilder::new().verify_crc64(true).build();
+        let ret = client.get_object_to_writer("yuanyq", &object_key, &mut buf, Some(options)).await;
+        assert!(ret.is_ok());
+        assert_eq!(b"hello world".to_vec(), buf);
+
+        client.delete_object("yuanyq", &object_key, None).await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_head_object_async() {
         setup();
@@ -1045,6 +2123,104 @@ mod test_object_async {
         assert_eq!(Some(StorageClass::Standard), meta.storage_class);
     }
 
+    #[tokio::test]
+    async fn test_versioned_object_round_trip_async() {
+        setup();
+        let client = Client::from_env();
+
+        let bucket_name = "yuanyq-2";
+        let object_key = format!("versioning-test/{}.txt", Uuid::new_v4());
+
+        let v1 = client
+            .put_object_from_buffer(bucket_name, &object_key, b"version 1".to_vec(), None)
+            .await
+            .unwrap()
+            .version_id()
+            .map(|s| s.to_string())
+            .unwrap();
+
+        let v2 = client
+            .put_object_from_buffer(bucket_name, &object_key, b"version 2".to_vec(), None)
+            .await
+            .unwrap()
+            .version_id()
+            .map(|s| s.to_string())
+            .unwrap();
+
+        assert_ne!(v1, v2);
+
+        let buf = client
+            .get_object_to_buffer(bucket_name, &object_key, Some(GetObjectOptions::with_version_id(&v1)))
+            .await
+            .unwrap();
+        assert_eq!(b"version 1".to_vec(), buf);
+
+        let meta = client
+            .get_object_metadata(bucket_name, &object_key, Some(GetObjectMetadataOptions::with_version_id(&v1)))
+            .await
+            .unwrap();
+        assert_eq!(Some(v1.clone()), meta.version_id);
+
+        let meta = client
+            .head_object(bucket_name, &object_key, Some(HeadObjectOptions::with_version_id(&v2)))
+            .await
+            .unwrap();
+        assert_eq!(Some(v2.clone()), meta.version_id);
+
+        for v in [v1, v2] {
+            client
+                .delete_object(bucket_name, &object_key, Some(DeleteObjectOptions::with_version_id(v)))
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_object_type_async() {
+        setup();
+        let client = Client::from_env();
+
+        let ret = client
+            .object_type("yuanyq", "rust-sdk-test/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack")
+            .await;
+        assert_eq!(Some(ObjectType::Normal), ret.unwrap());
+
+        let ret = client.object_type("yuanyq", format!("rust-sdk-test/{}.txt", Uuid::new_v4())).await;
+        assert_eq!(None, ret.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_get_object_not_modified_async() {
+        setup();
+        let client = Client::from_env();
+
+        let object = "rust-sdk-test/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack";
+
+        let options = GetObjectOptionsBuilder::new().if_non_match("\"B752E1A13502E231AC4AA0E1D91F887C\"").build();
+        let result = client.get_object_to_buffer("yuanyq", object, Some(options)).await;
+        assert!(matches!(result, Err(Error::NotModified)));
+
+        let options = GetObjectOptionsBuilder::new().if_modified_since("Tue, 18 Feb 2025 15:03:23 GMT").build();
+        let result = client.get_object_to_buffer("yuanyq", object, Some(options)).await;
+        assert!(matches!(result, Err(Error::NotModified)));
+    }
+
+    #[tokio::test]
+    async fn test_restore_prefix_async() {
+        setup();
+        let client = Client::from_env();
+
+        let config = RestoreObjectRequest { days: 1, ..Default::default() };
+
+        let result = client.restore_prefix("yuanyq", "rust-sdk-test/archive/", config, 4).await;
+        log::debug!("{:?}", result);
+        assert!(result.is_ok());
+
+        let ret = result.unwrap();
+        assert_eq!(0, ret.failed);
+        assert!(ret.submitted + ret.already_in_progress > 0);
+    }
+
     /// Copy object in same bucket
     #[tokio::test]
     async fn test_copy_object_1_async() {
@@ -1070,6 +2246,35 @@ mod test_object_async {
         client.delete_object("yuanyq", &dest_object, None).await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_rename_object_async() {
+        setup();
+        let client = Client::from_env();
+
+        let bucket = "yuanyq";
+        let from_object = format!("rust-sdk-test/katex-{}.zip", Uuid::new_v4());
+        let to_object = format!("rust-sdk-test/katex-{}.zip", Uuid::new_v4());
+
+        client.copy_object(bucket, "rust-sdk-test/katex.zip", bucket, &from_object, None).await.unwrap();
+
+        let ret = client.rename_object(bucket, &from_object, &from_object, None).await;
+        assert!(ret.is_err());
+
+        let meta = client.rename_object(bucket, &from_object, &to_object, None).await.unwrap();
+        assert!(!client.exists(bucket, &from_object, None).await.unwrap());
+        assert!(meta.content_length > 0);
+
+        // 目标已经存在，禁止覆盖
+        client.copy_object(bucket, "rust-sdk-test/katex.zip", bucket, &from_object, None).await.unwrap();
+        let options = CopyObjectOptionsBuilder::new().forbid_overwrite(true).build();
+        let ret = client.rename_object(bucket, &from_object, &to_object, Some(options)).await;
+        assert!(ret.is_err());
+        assert!(client.exists(bucket, &from_object, None).await.unwrap());
+
+        client.delete_object(bucket, &from_object, None).await.unwrap();
+        client.delete_object(bucket, &to_object, None).await.unwrap();
+    }
+
     /// Copy object across buckets
     #[tokio::test]
     async fn test_copy_object_2_async() {
@@ -1095,6 +2300,94 @@ mod test_object_async {
         client.delete_object(dest_bucket, &dest_object, None).await.unwrap();
     }
 
+    /// Copy small object with `copy_object_auto`, it falls back to `copy_object`
+    #[tokio::test]
+    async fn test_copy_object_auto_async() {
+        log::debug!("test copy object auto");
+        setup();
+        let client = Client::from_env();
+
+        let bucket = "yuanyq";
+        let source_object = "rust-sdk-test/katex.zip";
+        let dest_object = format!("rust-sdk-test/katex-{}.zip", Uuid::new_v4());
+
+        let ret = client.copy_object_auto(bucket, source_object, bucket, &dest_object, None).await;
+        assert!(ret.is_ok());
+
+        let source_meta = client.get_object_metadata(bucket, source_object, None).await.unwrap();
+        let dest_meta = client.get_object_metadata(bucket, &dest_object, None).await.unwrap();
+        assert_eq!(source_meta.content_length, dest_meta.content_length);
+
+        client.delete_object(bucket, &dest_object, None).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_copy_object_preserve_source_async() {
+        log::debug!("test copy object preserving source storage class and acl");
+        setup();
+        let client = Client::from_env();
+
+        let bucket = "yuanyq";
+        let source_object = "rust-sdk-test/katex.zip";
+        let dest_object = format!("rust-sdk-test/katex-{}.zip", Uuid::new_v4());
+
+        let options = CopyObjectOptionsBuilder::new().preserve_source(true).build();
+        client.copy_object(bucket, source_object, bucket, &dest_object, Some(options)).await.unwrap();
+
+        let source_meta = client.head_object(bucket, source_object, None).await.unwrap();
+        let dest_meta = client.head_object(bucket, &dest_object, None).await.unwrap();
+        assert_eq!(source_meta.storage_class, dest_meta.storage_class);
+
+        let source_acl = client.get_object_acl(bucket, source_object, None).await.unwrap();
+        let dest_acl = client.get_object_acl(bucket, &dest_object, None).await.unwrap();
+        assert_eq!(source_acl.acl, dest_acl.acl);
+
+        client.delete_object(bucket, &dest_object, None).await.unwrap();
+    }
+
+    /// The bucket should be versioning enabled
+    #[tokio::test]
+    async fn test_copy_object_versioned_async() {
+        log::debug!("test copy object to versioning enabled bucket");
+        setup();
+        let client = Client::from_env();
+
+        let bucket = "yuanyq";
+        let source_object = "rust-sdk-test/katex.zip";
+        let dest_object = format!("versioning-test/katex-{}.zip", Uuid::new_v4());
+
+        let ret = client.copy_object(bucket, source_object, bucket, &dest_object, None).await.unwrap();
+        log::debug!("{:?}", ret);
+        assert!(!ret.etag.is_empty());
+
+        let version_id = ret.version_id.expect("version id should be returned on versioning enabled bucket");
+
+        let dest_meta = client.head_object(bucket, &dest_object, None).await.unwrap();
+        assert_eq!(Some(version_id), dest_meta.version_id);
+
+        client.delete_all_versions(bucket, &dest_object).await.unwrap();
+    }
+
+    /// The bucket should be versioning enabled
+    #[tokio::test]
+    async fn test_delete_all_versions_async() {
+        log::debug!("test delete all versions");
+        setup();
+        let client = Client::from_env();
+
+        let bucket = "yuanyq";
+        let object = format!("rust-sdk-test/{}.txt", Uuid::new_v4());
+
+        client.put_object(bucket, &object, bytes::Bytes::from_static(b"v1"), None).await.unwrap();
+        client.put_object(bucket, &object, bytes::Bytes::from_static(b"v2"), None).await.unwrap();
+        client.delete_object(bucket, &object, None).await.unwrap();
+
+        let deleted = client.delete_all_versions(bucket, &object).await.unwrap();
+        assert_eq!(3, deleted);
+
+        assert!(!client.exists(bucket, &object, None).await.unwrap());
+    }
+
     #[tokio::test]
     async fn test_create_object_from_buffer_async() {
         log::debug!("test create object from buffer");
@@ -1123,6 +2416,26 @@ mod test_object_async {
         client.delete_object(bucket, &object, None).await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_create_object_from_chunks_async() {
+        log::debug!("test create object from chunks");
+        setup();
+        let client = Client::from_env();
+
+        let bucket = "yuanyq";
+        let object = format!("rust-sdk-test/{}.txt", Uuid::new_v4());
+
+        let chunks = vec![bytes::Bytes::from_static(b"hello "), bytes::Bytes::new(), bytes::Bytes::from_static(b"world")];
+        let options = PutObjectOptionsBuilder::new().mime_type("text/plain").build();
+
+        client.put_object_from_chunks(bucket, &object, chunks, Some(options)).await.unwrap();
+
+        let content = client.get_object_to_buffer(bucket, &object, None).await.unwrap();
+        assert_eq!(b"hello world".to_vec(), content);
+
+        client.delete_object(bucket, &object, None).await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_create_object_from_base64_async() {
         log::debug!("test create object from base64 string");
@@ -1222,6 +2535,51 @@ mod test_object_async {
         client.delete_object(bucket, &object, None).await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_put_object_from_bytes_async() {
+        setup();
+        let client = Client::from_env();
+
+        let bucket = "yuanyq";
+        let object = format!("rust-sdk-test/{}.txt", Uuid::new_v4());
+
+        let options = PutObjectOptionsBuilder::new().mime_type("text/plain").build();
+        let ret = client
+            .put_object(bucket, &object, bytes::Bytes::from_static(b"hello world"), Some(options))
+            .await;
+        assert!(ret.is_ok());
+
+        let buf = client.get_object_to_buffer(bucket, &object, None).await.unwrap();
+        assert_eq!(b"hello world".to_vec(), buf);
+
+        client.delete_object(bucket, &object, None).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_append_object_with_content_type_async() {
+        setup();
+        let client = Client::from_env();
+
+        let bucket = "yuanyq";
+        let object = format!("rust-sdk-test/{}.txt", Uuid::new_v4());
+
+        let options = PutObjectOptionsBuilder::new().mime_type("text/plain").traffic_limit(819_200).build();
+
+        let ret = client.append_object_from_buffer(bucket, &object, b"hello ".to_vec(), 0, Some(options)).await;
+        assert!(ret.is_ok());
+
+        let next_pos = ret.unwrap().next_append_position;
+        let ret = client.append_object_from_buffer(bucket, &object, b"world".to_vec(), next_pos, None).await;
+        assert!(ret.is_ok());
+
+        let meta = client.head_object(bucket, &object, None).await.unwrap();
+        assert_eq!(Some("text/plain".to_string()), meta.content_type);
+        assert_eq!(Some(ObjectType::Appendable), meta.object_type);
+        assert_eq!(11, meta.content_length);
+
+        client.delete_object(bucket, &object, None).await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_append_object_from_base64_async() {
         log::debug!("test append object from base64 string");
@@ -1444,4 +2802,43 @@ mod test_object_async {
 
         client.delete_object(&bucket, &object, None).await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_hash_request_body_async() {
+        use crate::{object::hash_request_body, object_common::request_body_crc64, request::RequestBody};
+
+        let file = std::env::temp_dir().join("ali-oss-rs-test-hash-request-body.txt");
+        std::fs::write(&file, b"abc123456789xyz").unwrap();
+
+        let body = RequestBody::File(file.clone(), Some(3..12));
+        assert_eq!(0x995DC9BBDF1939FA, hash_request_body(&body, request_body_crc64).await.unwrap());
+
+        let body = RequestBody::Text("123456789".to_string());
+        assert_eq!(0x995DC9BBDF1939FA, hash_request_body(&body, request_body_crc64).await.unwrap());
+
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_fill_content_md5_async() {
+        use crate::{object::fill_content_md5, object_common::request_body_content_md5, request::RequestBody};
+
+        let file = std::env::temp_dir().join("ali-oss-rs-test-fill-content-md5.txt");
+        std::fs::write(&file, b"hello world").unwrap();
+        let body = RequestBody::File(file.clone(), None);
+
+        let mut options = Some(PutObjectOptionsBuilder::new().compute_content_md5(true).build());
+        fill_content_md5(&mut options, &body).await.unwrap();
+        assert_eq!(Some(request_body_content_md5(&body).unwrap()), options.unwrap().content_md5);
+
+        let mut options = Some(PutObjectOptionsBuilder::new().content_md5("abc").compute_content_md5(true).build());
+        fill_content_md5(&mut options, &body).await.unwrap();
+        assert_eq!(Some("abc".to_string()), options.unwrap().content_md5);
+
+        let mut options = None;
+        fill_content_md5(&mut options, &body).await.unwrap();
+        assert!(options.is_none());
+
+        std::fs::remove_file(&file).unwrap();
+    }
 }
@@ -1,13 +1,27 @@
-use std::{collections::HashMap, fmt::Display};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    io::{Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
 use base64::prelude::{Engine, BASE64_STANDARD};
 use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
 
 use crate::{
-    common::{self, build_tag_string, MetadataDirective, ObjectType, ServerSideEncryptionAlgorithm, StorageClass, TagDirective, MIME_TYPE_XML},
-    error::Error,
+    bucket_common::{write_text_element, BucketAcl, ListObjectsOptions},
+    common::{
+        self, build_tag_string, MetadataDirective, ObjectRetention, ObjectRetentionMode, ObjectType, ServerSideEncryptionAlgorithm, StorageClass, TagDirective,
+        WithVersionId, MIME_TYPE_XML,
+    },
+    error::{Error, OssErrorCode},
+    multipart_common::{InitiateMultipartUploadOptions, UploadPartCopyOptions},
     request::{OssRequest, RequestMethod},
-    util::{sanitize_etag, validate_bucket_name, validate_meta_key, validate_object_key, validate_tag_key, validate_tag_value},
+    util::{
+        content_md5_base64, decode_header_value, explain_invalid_object_key, part_ranges, sanitize_etag, split_http_date_list, validate_bucket_name,
+        validate_meta_key, validate_object_key, validate_sha256_hex, validate_tag_key, validate_tag_value, Crc64Writer,
+    },
     RequestBody, Result,
 };
 
@@ -88,6 +102,18 @@ impl TryFrom<&String> for ObjectAcl {
     }
 }
 
+/// An object inherits bucket acl by `ObjectAcl::Default`,
+/// convert bucket acl to object acl to set it explicitly
+impl From<BucketAcl> for ObjectAcl {
+    fn from(acl: BucketAcl) -> Self {
+        match acl {
+            BucketAcl::PublicReadWrite => ObjectAcl::PublicReadWrite,
+            BucketAcl::PublicRead => ObjectAcl::PublicRead,
+            BucketAcl::Private => ObjectAcl::Private,
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
 pub enum ContentEncoding {
@@ -197,6 +223,17 @@ pub struct PutObjectOptions {
     /// 上传内容的 MD5 摘要算法结果的 base64 字符串。用于检查消息内容是否与发送时一致。Content-MD5 是由 MD5 算法生成的值。上传了 Content-MD5 请求头后，OSS 会计算消息体的 Content-MD5 并检查一致性。
     pub content_md5: Option<String>,
 
+    /// 如果为 `true` 并且没有指定 `content_md5`，则在发送请求前根据上传内容计算 `content-md5`。
+    /// 上传文件时需要额外读取一次文件（只读取指定的范围）。默认为 `false`
+    pub compute_content_md5: bool,
+
+    /// 上传内容的 SHA-256 摘要的十六进制（小写）字符串。
+    ///
+    /// 默认情况下 `x-oss-content-sha256` 请求头的值为 `UNSIGNED-PAYLOAD`，即不校验请求体。
+    /// 指定此选项后，会使用该值替换 `x-oss-content-sha256` 并参与 V4 签名，OSS 会计算请求体的 SHA-256 并检查一致性，
+    /// 不一致时返回 `400`。仅适用于使用 V4 签名的 `PutObject`、`AppendObject` 和 `UploadPart` 等带有请求体的接口。
+    pub content_sha256: Option<String>,
+
     /// 过期时间。例如：`Wed, 08 Jul 2015 16:57:01 GMT`
     pub expires: Option<String>,
 
@@ -227,6 +264,15 @@ pub struct PutObjectOptions {
     /// 如果不指定，则默认采用 Bucket 的存储类型。
     pub storage_class: Option<StorageClass>,
 
+    /// 单链接限速，单位为 bit/s，取值范围为 `819200`（100 KB/s）~ `838860800`（100 MB/s）。
+    /// 对 `append_object_*` 同样有效。
+    pub traffic_limit: Option<u64>,
+
+    /// 上传时设置 Object 级别的保留策略，对应请求头 `x-oss-object-retention-mode` 和 `x-oss-object-retention-retain-until-date`。
+    ///
+    /// **注意** 需要账号以及 Bucket 已经开通并启用了 Object 级别的保留策略，否则请求会失败。详见 [`ObjectRetention`]
+    pub object_retention: Option<ObjectRetention>,
+
     /// 使用 PutObject 接口时，如果配置以 `x-oss-meta-` 为前缀的参数，则该参数视为元数据，例如 `x-oss-meta-location`。
     /// 一个 Object 可以有多个类似的参数，但所有的元数据总大小不能超过 8 KB。
     /// 元数据支持短划线（`-`）、数字、英文字母（`a~z`）。英文字符的大写字母会被转成小写字母，不支持下划线（`_`）在内的其他字符。
@@ -247,6 +293,13 @@ pub struct PutObjectOptions {
     /// 主要是初始化分片上传的时候，有一个额外参数 `sequential`，
     /// 但是这个参数在官方文档上都没有说明，所以就放到这里吧，有需要的话就使用
     pub parameters: HashMap<String, String>,
+
+    /// 本次请求的超时时间，覆盖客户端的 `timeout`。例如：客户端使用较短的超时时间，上传大文件时单独设置较长的超时时间
+    pub timeout: Option<Duration>,
+
+    /// 上传完成后计算上传内容的 CRC64，并和响应头 `x-oss-hash-crc64ecma` 比较，不一致时返回 `Error::CrcMismatch`。
+    /// 上传文件时需要额外读取一次文件。仅用于 PutObject，初始化分片上传时忽略
+    pub verify_crc64: bool,
 }
 
 pub struct PutObjectOptionsBuilder {
@@ -255,6 +308,8 @@ pub struct PutObjectOptionsBuilder {
     content_disposition: Option<String>,
     content_encoding: Option<ContentEncoding>,
     content_md5: Option<String>,
+    compute_content_md5: bool,
+    content_sha256: Option<String>,
     expires: Option<String>,
     forbid_overwrite: Option<bool>,
     server_side_encryption: Option<ServerSideEncryptionAlgorithm>,
@@ -262,10 +317,14 @@ pub struct PutObjectOptionsBuilder {
     server_side_encryption_key_id: Option<String>,
     object_acl: Option<ObjectAcl>,
     storage_class: Option<StorageClass>,
+    traffic_limit: Option<u64>,
+    object_retention: Option<ObjectRetention>,
     metadata: HashMap<String, String>,
     tags: HashMap<String, String>,
     callback: Option<Callback>,
     parameters: HashMap<String, String>,
+    timeout: Option<Duration>,
+    verify_crc64: bool,
 }
 
 impl PutObjectOptionsBuilder {
@@ -276,6 +335,8 @@ impl PutObjectOptionsBuilder {
             content_disposition: None,
             content_encoding: None,
             content_md5: None,
+            compute_content_md5: false,
+            content_sha256: None,
             expires: None,
             forbid_overwrite: None,
             server_side_encryption: None,
@@ -283,10 +344,14 @@ impl PutObjectOptionsBuilder {
             server_side_encryption_key_id: None,
             object_acl: None,
             storage_class: None,
+            traffic_limit: None,
+            object_retention: None,
             metadata: HashMap::new(),
             tags: HashMap::new(),
             callback: None,
             parameters: HashMap::new(),
+            timeout: None,
+            verify_crc64: false,
         }
     }
 
@@ -315,11 +380,30 @@ impl PutObjectOptionsBuilder {
         self
     }
 
+    /// Compute `content-md5` from the content to be uploaded if `content_md5` is not specified
+    pub fn compute_content_md5(mut self, compute_content_md5: bool) -> Self {
+        self.compute_content_md5 = compute_content_md5;
+        self
+    }
+
+    pub fn content_sha256(mut self, content_sha256: impl Into<String>) -> Self {
+        self.content_sha256 = Some(content_sha256.into());
+        self
+    }
+
     pub fn expires(mut self, expires: impl Into<String>) -> Self {
         self.expires = Some(expires.into());
         self
     }
 
+    /// Set `Expires` header from a timestamp. It is formatted as GMT string like `Wed, 08 Jul 2015 16:57:01 GMT`,
+    /// OSS ignores the header silently if the format is wrong
+    #[cfg(feature = "time")]
+    pub fn expires_at(mut self, expires: time::OffsetDateTime) -> Self {
+        self.expires = Some(crate::util::format_http_date(expires));
+        self
+    }
+
     pub fn forbid_overwrite(mut self, forbid_overwrite: bool) -> Self {
         self.forbid_overwrite = Some(forbid_overwrite);
         self
@@ -350,6 +434,17 @@ impl PutObjectOptionsBuilder {
         self
     }
 
+    pub fn traffic_limit(mut self, traffic_limit: u64) -> Self {
+        self.traffic_limit = Some(traffic_limit);
+        self
+    }
+
+    /// Requires object level retention enabled. See [`ObjectRetention`]
+    pub fn object_retention(mut self, mode: ObjectRetentionMode, retain_until_date: impl Into<String>) -> Self {
+        self.object_retention = Some(ObjectRetention::new(mode, retain_until_date));
+        self
+    }
+
     pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.metadata.insert(key.into(), value.into());
         self
@@ -370,6 +465,18 @@ impl PutObjectOptionsBuilder {
         self
     }
 
+    /// Total timeout of this request, overriding the timeout of the client
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Verify the uploaded data with the `x-oss-hash-crc64ecma` response header
+    pub fn verify_crc64(mut self, verify_crc64: bool) -> Self {
+        self.verify_crc64 = verify_crc64;
+        self
+    }
+
     pub fn build(self) -> PutObjectOptions {
         PutObjectOptions {
             mime_type: self.mime_type,
@@ -377,6 +484,8 @@ impl PutObjectOptionsBuilder {
             content_disposition: self.content_disposition,
             content_encoding: self.content_encoding,
             content_md5: self.content_md5,
+            compute_content_md5: self.compute_content_md5,
+            content_sha256: self.content_sha256,
             expires: self.expires,
             forbid_overwrite: self.forbid_overwrite,
             server_side_encryption: self.server_side_encryption,
@@ -384,10 +493,14 @@ impl PutObjectOptionsBuilder {
             server_side_encryption_key_id: self.server_side_encryption_key_id,
             object_acl: self.object_acl,
             storage_class: self.storage_class,
+            traffic_limit: self.traffic_limit,
+            object_retention: self.object_retention,
             metadata: self.metadata,
             tags: self.tags,
             callback: self.callback,
             parameters: self.parameters,
+            timeout: self.timeout,
+            verify_crc64: self.verify_crc64,
         }
     }
 }
@@ -398,9 +511,42 @@ impl Default for PutObjectOptionsBuilder {
     }
 }
 
+/// 字节范围，用于下载 Object 时的 `Range` 请求头以及分片拷贝时的 `x-oss-copy-source-range` 请求头。
+/// 下标从 `0` 开始
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum ByteRange {
+    /// 从 `start` 到 `end` 的字节，两端都是闭区间，即 `bytes=start-end`
+    FromTo(u64, u64),
+
+    /// 从 `start` 开始直到结尾的字节，即 `bytes=start-`
+    From(u64),
+
+    /// 最后 `n` 个字节，即 `bytes=-n`
+    Suffix(u64),
+}
+
+impl ByteRange {
+    /// Render as the `bytes=` form used in `Range` header
+    pub fn to_header_value(&self) -> String {
+        match self {
+            ByteRange::FromTo(start, end) => format!("bytes={}-{}", start, end),
+            ByteRange::From(start) => format!("bytes={}-", start),
+            ByteRange::Suffix(n) => format!("bytes=-{}", n),
+        }
+    }
+}
+
+impl Display for ByteRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_header_value())
+    }
+}
+
 /// Options for getting object
 ///
 /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobject>
+#[derive(Debug, Clone, Default)]
 pub struct GetObjectOptions {
     // The following fields are header items
     /// 指定文件传输的范围。
@@ -409,10 +555,17 @@ pub struct GetObjectOptions {
     /// - 如果指定的范围不符合规范，则传送整个 Object，并且结果中不包含 `Content-Range`。
     pub range: Option<String>,
 
+    /// 是否使用标准的 Range 行为。设置为 `true` 时，会添加请求头 `x-oss-range-behavior: standard`。
+    ///
+    /// 默认情况下，如果指定的范围不符合规范，OSS 会传送整个 Object。
+    /// 使用标准行为时，如果指定的范围超出 Object 大小，OSS 会返回 `416 Requested Range Not Satisfiable`（错误码 `InvalidRange`），
+    /// 避免在范围错误时静默下载整个文件。
+    pub standard_range_behavior: bool,
+
     /// GMT 日期时间字符串，例如：`Fri, 13 Nov 2015 14:47:53 GMT`
     ///
     /// 如果指定的时间早于实际修改时间或指定的时间不符合规范，则直接返回 Object，并返回 `200 OK`；
-    /// 如果指定的时间等于或者晚于实际修改时间，则返回 `304 Not Modified`。
+    /// 如果指定的时间等于或者晚于实际修改时间，则返回 `304 Not Modified`，此时 SDK 返回 `Error::NotModified`。
     pub if_modified_since: Option<String>,
 
     /// GMT 日期时间字符串，例如：`Fri, 13 Nov 2015 14:47:53 GMT`
@@ -431,7 +584,7 @@ pub struct GetObjectOptions {
     /// ETag 值
     ///
     /// 如果传入的 `ETag` 值和 `Object` 的 `ETag` 不匹配，则正常传输 Object，并返回 `200 OK`；
-    /// 如果传入的 `ETag` 和 `Object` 的 `ETag` 匹配，则返回 `304 Not Modified`。
+    /// 如果传入的 `ETag` 和 `Object` 的 `ETag` 匹配，则返回 `304 Not Modified`，此时 SDK 返回 `Error::NotModified`。
     ///
     /// `If-Match` 和 `If-None-Match` 可以同时使用。
     pub if_none_match: Option<String>,
@@ -476,10 +629,18 @@ pub struct GetObjectOptions {
 
     /// The version to retreive
     pub version_id: Option<String>,
+
+    /// 本次请求的超时时间，覆盖客户端的 `timeout`。包括读取响应体的时间，下载大文件时可以单独设置较长的超时时间
+    pub timeout: Option<Duration>,
+
+    /// 下载时计算数据的 CRC64，并和响应头 `x-oss-hash-crc64ecma` 比较，不一致时返回 `Error::CrcMismatch`。
+    /// 不支持和 `range` 一起使用。仅用于下载到文件或者 writer
+    pub verify_crc64: bool,
 }
 
 pub struct GetObjectOptionsBuilder {
     range: Option<String>,
+    standard_range_behavior: bool,
     if_modified_since: Option<String>,
     if_unmodified_since: Option<String>,
     if_match: Option<String>,
@@ -491,12 +652,15 @@ pub struct GetObjectOptionsBuilder {
     response_content_disposition: Option<String>,
     response_content_encoding: Option<ContentEncoding>,
     version_id: Option<String>,
+    timeout: Option<Duration>,
+    verify_crc64: bool,
 }
 
 impl GetObjectOptionsBuilder {
     pub fn new() -> Self {
         Self {
             range: None,
+            standard_range_behavior: false,
             if_modified_since: None,
             if_unmodified_since: None,
             if_match: None,
@@ -508,6 +672,8 @@ impl GetObjectOptionsBuilder {
             response_content_disposition: None,
             response_content_encoding: None,
             version_id: None,
+            timeout: None,
+            verify_crc64: false,
         }
     }
 
@@ -516,6 +682,17 @@ impl GetObjectOptionsBuilder {
         self
     }
 
+    /// Set the range by [`ByteRange`] instead of the raw `bytes=` string
+    pub fn byte_range(mut self, range: ByteRange) -> Self {
+        self.range = Some(range.to_header_value());
+        self
+    }
+
+    pub fn standard_range_behavior(mut self, standard: bool) -> Self {
+        self.standard_range_behavior = standard;
+        self
+    }
+
     pub fn if_modified_since(mut self, if_modified_since: impl Into<String>) -> Self {
         self.if_modified_since = Some(if_modified_since.into());
         self
@@ -571,9 +748,22 @@ impl GetObjectOptionsBuilder {
         self
     }
 
+    /// Total timeout of this request including reading the response body, overriding the timeout of the client
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Verify the downloaded data with the `x-oss-hash-crc64ecma` response header
+    pub fn verify_crc64(mut self, verify_crc64: bool) -> Self {
+        self.verify_crc64 = verify_crc64;
+        self
+    }
+
     pub fn build(self) -> GetObjectOptions {
         GetObjectOptions {
             range: self.range,
+            standard_range_behavior: self.standard_range_behavior,
             if_modified_since: self.if_modified_since,
             if_unmodified_since: self.if_unmodified_since,
             if_match: self.if_match,
@@ -585,6 +775,8 @@ impl GetObjectOptionsBuilder {
             response_content_disposition: self.response_content_disposition,
             response_content_encoding: self.response_content_encoding,
             version_id: self.version_id,
+            timeout: self.timeout,
+            verify_crc64: self.verify_crc64,
         }
     }
 }
@@ -595,9 +787,105 @@ impl Default for GetObjectOptionsBuilder {
     }
 }
 
-/// A "placeholder" struct for adding more fields in the future
-#[derive(Debug)]
-pub struct GetObjectResult;
+/// Result of downloading object to local file
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct GetObjectResult {
+    /// 从下载响应头中解析出的元数据，例如可以通过 `server_side_encryption` 确认 object 在服务端的加密方式，不需要再发起 HEAD 请求。
+    /// 对于断点续传的下载，为第一次请求的响应头
+    pub metadata: ObjectMetadata,
+}
+
+/// Source of the content for putting or appending object.
+/// See `put_object` and `append_object` in `ObjectOperations`
+pub enum PutSource {
+    /// Local file. The `mime_type` is guessed from the file name if it is not set in options
+    File(PathBuf),
+    Buffer(Vec<u8>),
+
+    /// Sent without copying the data
+    Bytes(bytes::Bytes),
+
+    /// Base64 encoded content. It is decoded before sending
+    Base64(String),
+
+    /// In-memory fragments which are sent in order without being concatenated
+    Chunks(Vec<bytes::Bytes>),
+
+    /// Any reader. It is read to the end into memory before sending,
+    /// because the request needs the content length and the body is re-sent on retries.
+    /// Use `File` for large local files
+    Reader(Box<dyn Read + Send>),
+}
+
+impl std::fmt::Debug for PutSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PutSource::File(path) => f.debug_tuple("File").field(path).finish(),
+            PutSource::Buffer(buffer) => f.debug_tuple("Buffer").field(&buffer.len()).finish(),
+            PutSource::Bytes(bytes) => f.debug_tuple("Bytes").field(&bytes.len()).finish(),
+            PutSource::Base64(s) => f.debug_tuple("Base64").field(&s.len()).finish(),
+            PutSource::Chunks(chunks) => f.debug_tuple("Chunks").field(&chunks.len()).finish(),
+            PutSource::Reader(_) => f.write_str("Reader(..)"),
+        }
+    }
+}
+
+impl PutSource {
+    /// Reading `PutSource::Reader` is blocking I/O, the async client calls this in `spawn_blocking` for it
+    pub(crate) fn into_request_body(self) -> Result<RequestBody> {
+        match self {
+            PutSource::File(path) => Ok(RequestBody::File(path, None)),
+            PutSource::Buffer(buffer) => Ok(RequestBody::Bytes(buffer.into())),
+            PutSource::Bytes(bytes) => Ok(RequestBody::Bytes(bytes)),
+            PutSource::Chunks(chunks) => Ok(RequestBody::Chunks(chunks)),
+            PutSource::Base64(s) => match BASE64_STANDARD.decode(s) {
+                Ok(data) => Ok(RequestBody::Bytes(data.into())),
+                Err(_) => Err(Error::Other("Decoding base64 string failed".to_string())),
+            },
+            PutSource::Reader(mut reader) => {
+                let mut data = vec![];
+                reader.read_to_end(&mut data)?;
+                Ok(RequestBody::Bytes(data.into()))
+            }
+        }
+    }
+
+    pub(crate) fn is_reader(&self) -> bool {
+        matches!(self, PutSource::Reader(_))
+    }
+}
+
+impl From<Vec<u8>> for PutSource {
+    fn from(value: Vec<u8>) -> Self {
+        PutSource::Buffer(value)
+    }
+}
+
+impl From<&[u8]> for PutSource {
+    fn from(value: &[u8]) -> Self {
+        PutSource::Buffer(value.to_vec())
+    }
+}
+
+impl From<bytes::Bytes> for PutSource {
+    fn from(value: bytes::Bytes) -> Self {
+        PutSource::Bytes(value)
+    }
+}
+
+impl From<PathBuf> for PutSource {
+    fn from(value: PathBuf) -> Self {
+        PutSource::File(value)
+    }
+}
+
+impl From<&Path> for PutSource {
+    fn from(value: &Path) -> Self {
+        PutSource::File(value.to_path_buf())
+    }
+}
 
 pub(crate) fn build_put_object_request(
     bucket_name: &str,
@@ -610,7 +898,7 @@ pub(crate) fn build_put_object_request(
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
     }
 
     // check for metadata and tags
@@ -621,6 +909,14 @@ pub(crate) fn build_put_object_request(
             }
         }
 
+        if options.tags.len() > common::MAX_OBJECT_TAGS {
+            return Err(Error::Other(format!(
+                "too many tags: {}. an object can have at most {} tags",
+                options.tags.len(),
+                common::MAX_OBJECT_TAGS
+            )));
+        }
+
         for (k, v) in &options.tags {
             if k.is_empty() || !validate_tag_key(k) || (!v.is_empty() && !validate_tag_value(v)) {
                 return Err(Error::Other(format!(
@@ -629,6 +925,18 @@ pub(crate) fn build_put_object_request(
                 )));
             }
         }
+
+        if let Some(s) = &options.content_sha256 {
+            if !validate_sha256_hex(s) {
+                return Err(Error::Other(format!("invalid content sha256: {}. must be 64 hex characters", s)));
+            }
+        }
+
+        if let Some(n) = options.traffic_limit {
+            if !(819_200..=838_860_800).contains(&n) {
+                return Err(Error::Other(format!("invalid traffic limit: {}. must between 819200 and 838860800 (bit/s)", n)));
+            }
+        }
     }
 
     let mut request = OssRequest::new().method(RequestMethod::Put).bucket(bucket_name).object(object_key);
@@ -637,12 +945,17 @@ pub(crate) fn build_put_object_request(
         for (k, v) in &options.parameters {
             request = request.add_query(k.clone(), v.clone());
         }
+
+        if let Some(t) = options.timeout {
+            request = request.timeout(t);
+        }
     }
 
     let content_length = match &request_body {
         RequestBody::Empty => 0u64,
         RequestBody::Text(s) => s.len() as u64,
         RequestBody::Bytes(bytes) => bytes.len() as u64,
+        RequestBody::Chunks(chunks) => chunks.iter().map(|c| c.len() as u64).sum(),
         RequestBody::File(file_path, range) => {
             if let Some(r) = range {
                 r.end - r.start
@@ -673,6 +986,11 @@ pub(crate) fn build_put_object_request(
         request = request.content_type(mime_guess::from_path(file_path).first_or_octet_stream().as_ref());
     }
 
+    let computed_content_md5 = match options {
+        Some(o) if o.content_md5.is_none() && o.compute_content_md5 => Some(request_body_content_md5(&request_body)?),
+        _ => None,
+    };
+
     // move the body to request
     request = request.body(request_body);
 
@@ -698,6 +1016,14 @@ pub(crate) fn build_put_object_request(
             request = request.add_header("expires", s);
         }
 
+        if let Some(s) = options.content_md5.as_ref().or(computed_content_md5.as_ref()) {
+            request = request.add_header("content-md5", s);
+        }
+
+        if let Some(s) = &options.content_sha256 {
+            request = request.add_header("x-oss-content-sha256", s.to_lowercase());
+        }
+
         if let Some(b) = &options.forbid_overwrite {
             if *b {
                 request = request.add_header("x-oss-forbid-overwrite", "true");
@@ -724,6 +1050,16 @@ pub(crate) fn build_put_object_request(
             request = request.add_header("x-oss-storage-class", store.as_str());
         }
 
+        if let Some(n) = options.traffic_limit {
+            request = request.add_header("x-oss-traffic-limit", n.to_string());
+        }
+
+        if let Some(r) = &options.object_retention {
+            request = request
+                .add_header("x-oss-object-retention-mode", r.mode.as_str())
+                .add_header("x-oss-object-retention-retain-until-date", &r.retain_until_date);
+        }
+
         for (k, v) in &options.metadata {
             request = request.add_header(k, v);
         }
@@ -738,10 +1074,8 @@ pub(crate) fn build_put_object_request(
             let callback_base64 = BASE64_STANDARD.encode(&callback_json);
             request = request.add_header("x-oss-callback", callback_base64);
 
-            if !cb.custom_variables.is_empty() {
-                let callback_vars_json = serde_json::to_string(&cb.custom_variables)?;
-                let callback_vars_base64 = BASE64_STANDARD.encode(&callback_vars_json);
-                request = request.add_header("x-oss-callback-var", callback_vars_base64);
+            if let Some(s) = cb.custom_variables_header() {
+                request = request.add_header("x-oss-callback-var", s);
             }
         }
     }
@@ -841,13 +1175,31 @@ pub struct ObjectMetadata {
     pub request_id: String,
     pub content_length: u64,
 
-    /// 已经移除了首尾双引号（`"`）之后的字符串
+    /// Object 存储的 MIME 类型，取自响应头 `Content-Type`
+    pub content_type: Option<String>,
+
+    /// 取自响应头 `Cache-Control`
+    pub cache_control: Option<String>,
+
+    /// 取自响应头 `Content-Disposition`
+    pub content_disposition: Option<String>,
+
+    /// 取自响应头 `Content-Encoding`，无法识别的值会被忽略
+    pub content_encoding: Option<ContentEncoding>,
+
+    /// 已经移除了首尾双引号（`"`）之后的字符串。
+    /// 只有单次上传的 Object 的 ETag 才是内容的 MD5，分片上传的 Object 参见 [`crate::is_multipart_etag`]
     pub etag: String,
     pub hash_crc64ecma: Option<u64>,
 
     /// Object 通过生命周期规则转储为冷归档或者深度冷归档存储类型的时间。
+    /// 为响应头 `x-oss-transition-time` 的原始值
     pub transition_time: Option<String>,
 
+    /// 从响应头 `x-oss-transition-time` 中解析出的所有转储时间。
+    /// 目前 OSS 只返回最近一次的转储时间，如果返回了多个以逗号分隔的值，这里会全部保留
+    pub transition_times: Vec<String>,
+
     /// Object 的最后一次访问时间。时间格式为 HTTP 1.1 协议中规定的 GMT 时间。
     /// 开启访问跟踪时，该字段的值会随着文件被访问的时间持续更新。
     /// 如果开启后关闭了访问跟踪，该字段的值保留为上一次最后更新的值。
@@ -876,6 +1228,8 @@ pub struct ObjectMetadata {
     /// - 如果没有提交 Restore 或者 Restore 已经超时，则不返回该字段。
     /// - 如果已经提交 Restore，且 Restore 没有完成，则返回的 `x-oss-restore` 值为 `ongoing-request="true"`。
     /// - 如果已经提交 Restore，且 Restore 已经完成，则返回的 `x-oss-restore` 值为 `ongoing-request="false", expiry-date="Sun, 16 Apr 2017 08:12:33 GMT"`，其中 `expiry-date` 是 Restore 完成后 Object 进入可读状态的过期时间。
+    ///
+    /// 可以使用 [`Self::restore_status`] 获取解析后的状态
     pub restore: Option<String>,
 
     /// 当用户通过轻量消息队列 SMQ 创建 OSS 事件通知后，
@@ -891,6 +1245,14 @@ pub struct ObjectMetadata {
     /// - `Multipart` 和 `Appendable` 类型的文件不会返回这个 Header。
     pub content_md5: Option<String>,
 
+    /// 对象内容的 SHA-256 摘要（十六进制字符串）。
+    /// 取自响应头 `x-oss-hash-sha256` 或者 `content-sha256`，仅部分地域和接口会返回，不返回时为 `None`。
+    pub hash_sha256: Option<String>,
+
+    /// Object 级别的保留策略，取自响应头 `x-oss-object-retention-mode` 和 `x-oss-object-retention-retain-until-date`。
+    /// 未设置保留策略时为 `None`
+    pub object_retention: Option<ObjectRetention>,
+
     /// 当 Object 所在的 Bucket 配置了 CORS 规则，且请求的 Origin 满足指定的 CORS 规则时会在响应中包含这个 Origin。
     pub access_control_allow_origin: Option<String>,
 
@@ -919,8 +1281,13 @@ impl From<HashMap<String, String>> for ObjectMetadata {
         Self {
             request_id: headers.remove("x-oss-request-id").unwrap_or("".to_string()),
             content_length: headers.remove("content-length").unwrap_or("0".to_string()).parse().unwrap_or(0),
+            content_type: headers.remove("content-type"),
+            cache_control: headers.remove("cache-control"),
+            content_disposition: headers.remove("content-disposition"),
+            content_encoding: headers.remove("content-encoding").and_then(|s| s.try_into().ok()),
             etag: sanitize_etag(headers.remove("etag").unwrap_or_default()),
             hash_crc64ecma: headers.remove("x-oss-hash-crc64ecma").map(|s| s.parse::<u64>().unwrap_or(0)),
+            transition_times: headers.get("x-oss-transition-time").map(|s| split_http_date_list(s)).unwrap_or_default(),
             transition_time: headers.remove("x-oss-transition-time"),
             last_access_time: headers.remove("x-oss-last-access-time"),
             last_modified: headers.remove("last-modified"),
@@ -960,6 +1327,8 @@ impl From<HashMap<String, String>> for ObjectMetadata {
             process_status: headers.remove("x-oss-process-status"),
             request_charged: headers.remove("x-oss-request-charged"),
             content_md5: headers.remove("content-md5"),
+            hash_sha256: headers.remove("x-oss-hash-sha256").or_else(|| headers.remove("content-sha256")),
+            object_retention: ObjectRetention::from_headers(&mut headers),
             access_control_allow_origin: headers.remove("access-control-allow-origin"),
             access_control_allow_methods: headers.remove("access-control-allow-methods"),
             access_control_allow_headers: headers.remove("access-control-allow-headers"),
@@ -968,11 +1337,59 @@ impl From<HashMap<String, String>> for ObjectMetadata {
             tag_count: headers.remove("x-oss-tagging-count").map(|s| s.parse().unwrap_or(0)),
 
             // CAUTION!! must be the last field to handle because `drain` consumes all the entries left in the map
-            metadata: headers.drain().filter(|(k, _)| k.starts_with("x-oss-meta-")).collect(),
+            metadata: headers
+                .drain()
+                .filter(|(k, _)| k.starts_with("x-oss-meta-"))
+                .map(|(k, v)| (k, decode_header_value(v)))
+                .collect(),
         }
     }
 }
 
+impl ObjectMetadata {
+    /// 解析 [`Self::restore`]，没有返回 `x-oss-restore` 或者格式无法识别时返回 `None`
+    pub fn restore_status(&self) -> Option<RestoreStatus> {
+        self.restore.as_deref().and_then(RestoreStatus::from_header)
+    }
+}
+
+/// 从响应头 `x-oss-restore` 解析出的 Restore 状态
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct RestoreStatus {
+    /// Restore 是否仍在进行中。为 `false` 时表示 Object 已经解冻完成，可以读取
+    pub ongoing: bool,
+
+    /// Restore 完成后 Object 进入可读状态的过期时间，GMT 格式，例如：`Sun, 16 Apr 2017 08:12:33 GMT`。
+    /// Restore 进行中时为 `None`
+    pub expiry_date: Option<String>,
+}
+
+impl RestoreStatus {
+    /// 解析 `ongoing-request="true"` 或者 `ongoing-request="false", expiry-date="Sun, 16 Apr 2017 08:12:33 GMT"`。
+    /// 注意 `expiry-date` 的值里面包含逗号，所以不能简单地按照逗号切分
+    pub(crate) fn from_header(s: &str) -> Option<Self> {
+        let ongoing = match quoted_header_param(s, "ongoing-request")?.to_lowercase().as_str() {
+            "true" => true,
+            "false" => false,
+            _ => return None,
+        };
+
+        let expiry_date = quoted_header_param(s, "expiry-date").filter(|s| !s.is_empty()).map(|s| s.to_string());
+
+        Some(Self { ongoing, expiry_date })
+    }
+}
+
+/// 从 `key1="value1", key2="value2"` 格式的字符串中取出指定 key 对应的值（不含双引号）
+fn quoted_header_param<'a>(s: &'a str, key: &str) -> Option<&'a str> {
+    let prefix = format!("{}=\"", key);
+    let start = s.find(&prefix)? + prefix.len();
+    let len = s[start..].find('"')?;
+    Some(s[start..start + len].trim())
+}
+
 /// Options for copying objects
 ///
 /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/copyobject>
@@ -1024,6 +1441,11 @@ pub struct CopyObjectOptions {
 
     pub tags: HashMap<String, String>,
     pub tag_directive: Option<TagDirective>,
+
+    /// 让目标 Object 的存储类型和访问权限与源 Object 保持一致，而不是使用目标 Bucket 的默认设置。
+    /// 未显式设置的 `storage_class` 和 `object_acl` 会从源 Object 读取，因此拷贝前会多一次 HeadObject 和一次 GetObjectAcl 请求；
+    /// 跨 Bucket 拷贝且源 Object 继承 Bucket 权限时，还会多一次 GetBucketAcl 请求
+    pub preserve_source: bool,
 }
 
 pub struct CopyObjectOptionsBuilder {
@@ -1041,6 +1463,7 @@ pub struct CopyObjectOptionsBuilder {
     storage_class: Option<StorageClass>,
     tags: HashMap<String, String>,
     tag_directive: Option<TagDirective>,
+    preserve_source: bool,
 }
 
 impl CopyObjectOptionsBuilder {
@@ -1060,6 +1483,7 @@ impl CopyObjectOptionsBuilder {
             storage_class: None,
             tags: HashMap::new(),
             tag_directive: None,
+            preserve_source: false,
         }
     }
 
@@ -1133,6 +1557,13 @@ impl CopyObjectOptionsBuilder {
         self
     }
 
+    /// Copy storage class and acl of the source object to the destination object.
+    /// It costs extra requests to read them, see [`CopyObjectOptions::preserve_source`]
+    pub fn preserve_source(mut self, preserve_source: bool) -> Self {
+        self.preserve_source = preserve_source;
+        self
+    }
+
     pub fn build(self) -> CopyObjectOptions {
         CopyObjectOptions {
             forbid_overwrite: self.forbid_overwrite,
@@ -1149,6 +1580,7 @@ impl CopyObjectOptionsBuilder {
             storage_class: self.storage_class,
             tags: self.tags,
             tag_directive: self.tag_directive,
+            preserve_source: self.preserve_source,
         }
     }
 }
@@ -1167,101 +1599,460 @@ pub struct DeleteObjectOptions {
     pub version_id: Option<String>,
 }
 
-/// A "placeholder" struct for adding more fields in the future
-pub struct DeleteObjectResult;
-
-/// A "placeholder" struct for adding more fields in the future
-pub struct CopyObjectResult;
+impl WithVersionId for GetObjectOptions {
+    fn version_id(&self) -> Option<&str> {
+        self.version_id.as_deref()
+    }
 
-pub(crate) fn build_copy_object_request(
-    source_bucket_name: &str,
-    source_object_key: &str,
-    dest_bucket_name: &str,
-    dest_object_key: &str,
-    options: &Option<CopyObjectOptions>,
-) -> Result<OssRequest> {
-    if !validate_bucket_name(source_bucket_name) {
-        return Err(Error::Other(format!("invalid source bucket name: {}", source_bucket_name)));
+    fn set_version_id(&mut self, version_id: Option<String>) {
+        self.version_id = version_id;
     }
+}
 
-    if !validate_object_key(source_object_key) {
-        return Err(Error::Other(format!("invalid source object key: {}", source_object_key)));
+impl WithVersionId for GetObjectMetadataOptions {
+    fn version_id(&self) -> Option<&str> {
+        self.version_id.as_deref()
     }
 
-    if !validate_bucket_name(dest_bucket_name) {
-        return Err(Error::Other(format!("invalid destination bucket name: {}", dest_bucket_name)));
+    fn set_version_id(&mut self, version_id: Option<String>) {
+        self.version_id = version_id;
     }
+}
 
-    if !validate_object_key(dest_object_key) {
-        return Err(Error::Other(format!("invalid destination object key: {}", dest_object_key)));
+impl WithVersionId for HeadObjectOptions {
+    fn version_id(&self) -> Option<&str> {
+        self.version_id.as_deref()
     }
 
-    let mut request = OssRequest::new()
-        .method(RequestMethod::Put)
-        .bucket(dest_bucket_name)
-        .object(dest_object_key)
-        .add_header(
-            "x-oss-copy-source",
-            format!("/{}/{}", urlencoding::encode(source_bucket_name), urlencoding::encode(source_object_key)),
-        );
+    fn set_version_id(&mut self, version_id: Option<String>) {
+        self.version_id = version_id;
+    }
+}
 
-    if let Some(options) = options {
-        // validate metadata key and taggings
-        for (k, _) in options.metadata.iter() {
-            if !validate_meta_key(k) {
-                return Err(Error::Other(format!("invalid metadata key: {}", k)));
-            }
-        }
+impl WithVersionId for DeleteObjectOptions {
+    fn version_id(&self) -> Option<&str> {
+        self.version_id.as_deref()
+    }
 
-        for (k, v) in options.tags.iter() {
-            if !validate_tag_key(k) || !validate_tag_value(v) {
-                return Err(Error::Other(format!("invalid tagging data: {}={}", k, v)));
-            }
-        }
+    fn set_version_id(&mut self, version_id: Option<String>) {
+        self.version_id = version_id;
+    }
+}
 
-        if let Some(s) = &options.source_version_id {
-            request = request.add_query("versionId", s);
-        }
+/// Result of creating a "folder"
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct CreateFolderResult {
+    pub request_id: String,
+}
 
-        if let Some(b) = options.forbid_overwrite {
-            request = request.add_header("x-oss-forbid-overwrite", b.to_string())
+impl From<HashMap<String, String>> for CreateFolderResult {
+    fn from(mut headers: HashMap<String, String>) -> Self {
+        Self {
+            request_id: headers.remove("x-oss-request-id").unwrap_or_default(),
         }
+    }
+}
 
-        if let Some(s) = &options.copy_source_if_match {
-            request = request.add_header("x-oss-copy-source-if-match", s);
-        }
+/// A "placeholder" struct for adding more fields in the future
+pub struct DeleteObjectResult;
 
-        if let Some(s) = &options.copy_source_if_none_match {
-            request = request.add_header("x-oss-copy-source-if-none-match", s);
-        }
+/// Result of copying object
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct CopyObjectResult {
+    pub request_id: String,
 
-        if let Some(s) = &options.copy_source_if_modified_since {
-            request = request.add_header("x-oss-copy-source-if-modified-since", s);
-        }
+    /// 目标 Object 的 ETag，已经移除了首尾双引号（`"`）
+    pub etag: String,
 
-        if let Some(s) = &options.copy_source_if_unmodified_since {
-            request = request.add_header("x-oss-copy-source-if-unmodified-since", s);
-        }
+    /// 目标 Object 的最后修改时间。通过分片拷贝时没有该值
+    pub last_modified: Option<String>,
 
-        if let Some(md) = options.metadata_directive {
-            request = request.add_header("x-oss-metadata-directive", md);
-        }
+    /// 目标 Bucket 开启了版本控制时，新生成的目标 Object 的版本 ID
+    pub version_id: Option<String>,
 
-        if let Some(a) = &options.server_side_encryption {
-            request = request.add_header("x-oss-server-side-encryption", a);
-        }
+    /// 源 Bucket 开启了版本控制时，被拷贝的源 Object 的版本 ID
+    pub source_version_id: Option<String>,
+}
 
-        if let Some(s) = &options.server_side_encryption_key_id {
-            request = request.add_header("x-oss-server-side-encryption-key-id", s);
-        }
+impl CopyObjectResult {
+    pub(crate) fn from_response(mut headers: HashMap<String, String>, xml: &str) -> Result<Self> {
+        let mut data = Self {
+            request_id: headers.remove("x-oss-request-id").unwrap_or_default(),
+            version_id: headers.remove("x-oss-version-id"),
+            source_version_id: headers.remove("x-oss-copy-source-version-id"),
+            ..Default::default()
+        };
 
-        if let Some(acl) = options.object_acl {
-            request = request.add_header("x-oss-object-acl", acl);
-        }
+        let mut reader = quick_xml::Reader::from_str(xml);
+        let mut tag = String::new();
 
-        if let Some(sc) = options.storage_class {
-            request = request.add_header("x-oss-storage-class", sc);
-        }
+        loop {
+            match reader.read_event()? {
+                Event::Eof => break,
+                Event::Start(t) => tag = String::from_utf8_lossy(t.local_name().as_ref()).to_string(),
+                Event::Text(s) => {
+                    let text = s.unescape()?.trim().to_string();
+                    match tag.as_str() {
+                        "ETag" => data.etag = sanitize_etag(text),
+                        "LastModified" => data.last_modified = Some(text),
+                        _ => {}
+                    }
+                }
+                Event::End(_) => tag.clear(),
+                _ => {}
+            }
+        }
+
+        Ok(data)
+    }
+}
+
+/// Renaming an object to itself would delete it after copying, so the source and destination keys must differ
+pub(crate) fn check_rename_object_keys(from_object_key: &str, to_object_key: &str) -> Result<()> {
+    if from_object_key == to_object_key {
+        return Err(Error::Other(format!("can not rename object to itself: {}", from_object_key)));
+    }
+
+    Ok(())
+}
+
+/// When renaming a specific version of the source object, the delete must target that version too.
+/// Otherwise a delete marker is added to the current version, which may not be the one copied
+pub(crate) fn build_rename_delete_options(options: &Option<CopyObjectOptions>) -> Option<DeleteObjectOptions> {
+    options
+        .as_ref()
+        .and_then(|opt| opt.source_version_id.clone())
+        .map(|version_id| DeleteObjectOptions { version_id: Some(version_id) })
+}
+
+/// Check the bucket locations (e.g. `oss-cn-hangzhou`) returned by get bucket location.
+/// Returns `Error::CrossRegionCopyUnsupported` if the source and destination buckets are in different regions
+pub(crate) fn check_copy_region(source_location: &str, dest_location: &str) -> Result<()> {
+    let source_region = source_location.strip_prefix("oss-").unwrap_or(source_location);
+    let dest_region = dest_location.strip_prefix("oss-").unwrap_or(dest_location);
+
+    if source_region != dest_region {
+        return Err(Error::CrossRegionCopyUnsupported {
+            source_region: source_region.to_string(),
+            dest_region: dest_region.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// 分片拷贝时默认的分片大小
+const DEFAULT_COPY_PART_SIZE: u64 = 100 * 1024 * 1024;
+
+/// Verified download needs the crc64 of the whole object, so range request is not allowed
+pub(crate) fn check_verified_download_options(options: &Option<GetObjectOptions>) -> Result<()> {
+    if options.as_ref().is_some_and(|o| o.range.is_some()) {
+        return Err(Error::Other("range is not supported while verifying crc64 of the downloaded data".to_string()));
+    }
+
+    Ok(())
+}
+
+/// Compare crc64 of downloaded or uploaded data with the `x-oss-hash-crc64ecma` response header
+pub(crate) fn verify_crc64(expected: Option<u64>, actual: u64) -> Result<()> {
+    match expected {
+        None => Err(Error::Other(
+            "x-oss-hash-crc64ecma is missing in response headers, can not verify the data".to_string(),
+        )),
+        Some(expected) if expected != actual => Err(Error::CrcMismatch { expected, actual }),
+        _ => Ok(()),
+    }
+}
+
+/// Write the request body to be uploaded into `writer`, e.g. a hasher.
+/// Files are copied by chunks and only the range of file is read if range is specified
+fn write_request_body<W: std::io::Write>(body: &RequestBody, writer: &mut W) -> Result<()> {
+    match body {
+        RequestBody::Empty => {}
+        RequestBody::Text(s) => writer.write_all(s.as_bytes())?,
+        RequestBody::Bytes(bytes) => writer.write_all(bytes)?,
+        RequestBody::Chunks(chunks) => {
+            for c in chunks {
+                writer.write_all(c)?;
+            }
+        }
+        RequestBody::File(file_path, range) => {
+            let mut file = std::fs::File::open(file_path)?;
+
+            if let Some(r) = range {
+                file.seek(SeekFrom::Start(r.start))?;
+                std::io::copy(&mut file.take(r.end - r.start), writer)?;
+            } else {
+                std::io::copy(&mut file, writer)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Calculate `content-md5` of the request body to be uploaded. Only the range of file is read if range is specified.
+/// Reading files is blocking I/O, the async client calls this in `spawn_blocking` for them
+pub(crate) fn request_body_content_md5(body: &RequestBody) -> Result<String> {
+    let mut hasher = md5::Context::new();
+    write_request_body(body, &mut hasher)?;
+    Ok(BASE64_STANDARD.encode(hasher.compute().0))
+}
+
+/// Parse the `x-oss-hash-crc64ecma` response header
+pub(crate) fn response_crc64(headers: &HashMap<String, String>) -> Option<u64> {
+    headers.get("x-oss-hash-crc64ecma").and_then(|s| s.parse().ok())
+}
+
+/// Calculate crc64 of the request body to be uploaded. Files are read by chunks, so it works for large files.
+/// Reading files is blocking I/O, the async client calls this in `spawn_blocking` for them
+pub(crate) fn request_body_crc64(body: &RequestBody) -> Result<u64> {
+    let mut writer = Crc64Writer::new(std::io::sink(), true);
+    write_request_body(body, &mut writer)?;
+    Ok(writer.crc64())
+}
+
+/// Resilient download resumes by byte offset, so the initial request must cover the whole object in its raw form:
+/// neither `range` nor `accept_encoding` is allowed
+pub(crate) fn check_resilient_download_options(options: &Option<GetObjectOptions>) -> Result<()> {
+    if let Some(o) = options {
+        if o.range.is_some() {
+            return Err(Error::Other("range is not supported while downloading with resuming".to_string()));
+        }
+
+        if o.accept_encoding.is_some() {
+            return Err(Error::Other("accept encoding is not supported while downloading with resuming".to_string()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Build the options to continue a broken download from `written` bytes.
+/// `If-Match` is set to the etag of the first response so that the object is not changed between attempts
+pub(crate) fn build_resume_get_object_options(options: &Option<GetObjectOptions>, written: u64, etag: Option<&str>) -> Result<Option<GetObjectOptions>> {
+    let etag = match etag {
+        Some(s) => s,
+        None if written == 0 => return Ok(options.clone()),
+        None => return Err(Error::Other("can not resume downloading: etag is missing in the response headers".to_string())),
+    };
+
+    let mut options = options.clone().unwrap_or_default();
+    options.range = Some(ByteRange::From(written).to_header_value());
+    options.standard_range_behavior = true;
+    options.if_match = Some(etag.to_string());
+
+    Ok(Some(options))
+}
+
+/// Build delete multiple objects requests for all versions of the given object key.
+/// Each request contains at most `DELETE_MULTIPLE_OBJECTS_LIMIT` items
+pub(crate) fn build_delete_versions_requests(object_key: &str, version_ids: Vec<String>) -> Vec<DeleteMultipleObjectsRequest> {
+    version_ids
+        .chunks(common::DELETE_MULTIPLE_OBJECTS_LIMIT)
+        .map(|chunk| DeleteMultipleObjectsRequest {
+            quiet: Some(false),
+            objects: chunk
+                .iter()
+                .map(|id| DeleteMultipleObjectsItem {
+                    key: object_key.to_string(),
+                    version_id: Some(id.clone()),
+                })
+                .collect(),
+            skip_content_md5: false,
+        })
+        .collect()
+}
+
+/// Split the source object into `bytes=start-end` ranges for upload part copy.
+/// The part size grows if the object can not be copied in 10000 parts
+pub(crate) fn build_copy_part_ranges(size: u64) -> Vec<(u32, String)> {
+    let part_size = DEFAULT_COPY_PART_SIZE.max(size.div_ceil(10000));
+
+    part_ranges(size, part_size)
+        .into_iter()
+        .map(|(part_number, start, end)| (part_number, ByteRange::FromTo(start, end).to_header_value()))
+        .collect()
+}
+
+/// Build the options for initiating multipart upload and upload part copy while copying object larger than 1GB.
+/// Metadata and tags are taken from the source object unless the directives are `Replace`, which is the same as CopyObject
+pub(crate) fn build_copy_multipart_options(
+    source_metadata: &ObjectMetadata,
+    source_tags: HashMap<String, String>,
+    options: &Option<CopyObjectOptions>,
+) -> (InitiateMultipartUploadOptions, UploadPartCopyOptions) {
+    let default_options = CopyObjectOptions::default();
+    let options = options.as_ref().unwrap_or(&default_options);
+
+    let (mime_type, metadata) = match options.metadata_directive {
+        Some(MetadataDirective::Replace) => (None, options.metadata.clone()),
+        _ => (source_metadata.content_type.clone(), source_metadata.metadata.clone()),
+    };
+
+    let tags = match options.tag_directive {
+        Some(TagDirective::Replace) => options.tags.clone(),
+        _ => source_tags,
+    };
+
+    // 与 CopyObject 一致：保留源 Object 的元数据时，`Cache-Control` 等标准 HTTP 头也一并保留
+    let (cache_control, content_disposition, content_encoding) = match options.metadata_directive {
+        Some(MetadataDirective::Replace) => (None, None, None),
+        _ => (
+            source_metadata.cache_control.clone(),
+            source_metadata.content_disposition.clone(),
+            source_metadata.content_encoding,
+        ),
+    };
+
+    let initiate_options = InitiateMultipartUploadOptions {
+        mime_type,
+        cache_control,
+        content_disposition,
+        content_encoding,
+        metadata,
+        tags,
+        forbid_overwrite: options.forbid_overwrite,
+        server_side_encryption: options.server_side_encryption.clone(),
+        server_side_encryption_key_id: options.server_side_encryption_key_id.clone(),
+        object_acl: options.object_acl,
+        storage_class: options.storage_class,
+        ..Default::default()
+    };
+
+    let part_copy_options = UploadPartCopyOptions {
+        source_object_version_id: options.source_version_id.clone(),
+        copy_source_range: None,
+        source_size: Some(source_metadata.content_length),
+        copy_source_if_match: options.copy_source_if_match.clone(),
+        copy_source_if_none_match: options.copy_source_if_none_match.clone(),
+        copy_source_if_unmodified_since: options.copy_source_if_unmodified_since.clone(),
+        copy_source_if_modified_since: options.copy_source_if_modified_since.clone(),
+    };
+
+    (initiate_options, part_copy_options)
+}
+
+/// Build `x-oss-copy-source` header value.
+/// The source version is part of the copy source, not a query parameter of the destination object
+fn build_copy_source(source_bucket_name: &str, source_object_key: &str, options: &Option<CopyObjectOptions>) -> String {
+    encode_copy_source(
+        source_bucket_name,
+        source_object_key,
+        options.as_ref().and_then(|opt| opt.source_version_id.as_deref()),
+    )
+}
+
+/// The value of `x-oss-copy-source` header: `/{bucket}/{key}` and optional `?versionId={version_id}`, all parts are url encoded
+pub(crate) fn encode_copy_source(source_bucket_name: &str, source_object_key: &str, source_version_id: Option<&str>) -> String {
+    let copy_source = format!("/{}/{}", urlencoding::encode(source_bucket_name), urlencoding::encode(source_object_key));
+
+    match source_version_id {
+        Some(v) => format!("{}?versionId={}", copy_source, urlencoding::encode(v)),
+        None => copy_source,
+    }
+}
+
+pub(crate) fn build_copy_object_request(
+    source_bucket_name: &str,
+    source_object_key: &str,
+    dest_bucket_name: &str,
+    dest_object_key: &str,
+    options: &Option<CopyObjectOptions>,
+) -> Result<OssRequest> {
+    if !validate_bucket_name(source_bucket_name) {
+        return Err(Error::Other(format!("invalid source bucket name: {}", source_bucket_name)));
+    }
+
+    if !validate_object_key(source_object_key) {
+        return Err(Error::Other(format!(
+            "invalid source object key: {}",
+            explain_invalid_object_key(source_object_key)
+        )));
+    }
+
+    if !validate_bucket_name(dest_bucket_name) {
+        return Err(Error::Other(format!("invalid destination bucket name: {}", dest_bucket_name)));
+    }
+
+    if !validate_object_key(dest_object_key) {
+        return Err(Error::Other(format!(
+            "invalid destination object key: {}",
+            explain_invalid_object_key(dest_object_key)
+        )));
+    }
+
+    let mut request = OssRequest::new()
+        .method(RequestMethod::Put)
+        .bucket(dest_bucket_name)
+        .object(dest_object_key)
+        .add_header("x-oss-copy-source", build_copy_source(source_bucket_name, source_object_key, options));
+
+    if let Some(options) = options {
+        // validate metadata key and taggings
+        for (k, _) in options.metadata.iter() {
+            if !validate_meta_key(k) {
+                return Err(Error::Other(format!("invalid metadata key: {}", k)));
+            }
+        }
+
+        if options.tags.len() > common::MAX_OBJECT_TAGS {
+            return Err(Error::Other(format!(
+                "too many tags: {}. an object can have at most {} tags",
+                options.tags.len(),
+                common::MAX_OBJECT_TAGS
+            )));
+        }
+
+        for (k, v) in options.tags.iter() {
+            if !validate_tag_key(k) || !validate_tag_value(v) {
+                return Err(Error::Other(format!("invalid tagging data: {}={}", k, v)));
+            }
+        }
+
+        if let Some(b) = options.forbid_overwrite {
+            request = request.add_header("x-oss-forbid-overwrite", b.to_string())
+        }
+
+        if let Some(s) = &options.copy_source_if_match {
+            request = request.add_header("x-oss-copy-source-if-match", s);
+        }
+
+        if let Some(s) = &options.copy_source_if_none_match {
+            request = request.add_header("x-oss-copy-source-if-none-match", s);
+        }
+
+        if let Some(s) = &options.copy_source_if_modified_since {
+            request = request.add_header("x-oss-copy-source-if-modified-since", s);
+        }
+
+        if let Some(s) = &options.copy_source_if_unmodified_since {
+            request = request.add_header("x-oss-copy-source-if-unmodified-since", s);
+        }
+
+        if let Some(md) = options.metadata_directive {
+            request = request.add_header("x-oss-metadata-directive", md);
+        }
+
+        if let Some(a) = &options.server_side_encryption {
+            request = request.add_header("x-oss-server-side-encryption", a);
+        }
+
+        if let Some(s) = &options.server_side_encryption_key_id {
+            request = request.add_header("x-oss-server-side-encryption-key-id", s);
+        }
+
+        if let Some(acl) = options.object_acl {
+            request = request.add_header("x-oss-object-acl", acl);
+        }
+
+        if let Some(sc) = options.storage_class {
+            request = request.add_header("x-oss-storage-class", sc);
+        }
 
         if let Some(td) = options.tag_directive {
             request = request.add_header("x-oss-tag-directive", td);
@@ -1285,7 +2076,7 @@ pub(crate) fn build_get_object_request(bucket_name: &str, object_key: &str, opti
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
     }
 
     let mut request = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).object(object_key);
@@ -1295,6 +2086,10 @@ pub(crate) fn build_get_object_request(bucket_name: &str, object_key: &str, opti
             request = request.add_header("range", s);
         }
 
+        if options.standard_range_behavior {
+            request = request.add_header("x-oss-range-behavior", "standard");
+        }
+
         if let Some(s) = &options.if_modified_since {
             request = request.add_header("if-modified-since", s);
         }
@@ -1338,18 +2133,83 @@ pub(crate) fn build_get_object_request(bucket_name: &str, object_key: &str, opti
         if let Some(s) = &options.version_id {
             request = request.add_query("versionId", s);
         }
+
+        if let Some(t) = options.timeout {
+            request = request.timeout(t);
+        }
+    }
+
+    Ok(request)
+}
+
+/// Normalize the "folder" key: strip leading `/` and make sure it ends with a single `/`.
+/// e.g. `a/b`, `/a/b/` are both normalized to `a/b/`
+pub(crate) fn normalize_folder_key(object_key: &str) -> Result<String> {
+    let key = object_key.strip_prefix("/").unwrap_or(object_key);
+
+    if key.contains("//") {
+        return Err(Error::Other(format!("invalid folder key: {}. must not contain `//`", object_key)));
+    }
+
+    // 去掉末尾的 `/` 之后需要是一个合法的 object key
+    let name = key.strip_suffix("/").unwrap_or(key);
+
+    if !validate_object_key(name) {
+        return Err(Error::Other(format!("invalid folder key: {}", explain_invalid_object_key(name))));
+    }
+
+    Ok(format!("{}/", name))
+}
+
+/// `forbid_overwrite` sends `x-oss-forbid-overwrite: true`, so an existing marker object is not put again
+pub(crate) fn build_create_folder_request(bucket_name: &str, object_key: &str, forbid_overwrite: bool) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+    }
+
+    let object_key = normalize_folder_key(object_key)?;
+
+    let mut request = OssRequest::new()
+        .method(RequestMethod::Put)
+        .bucket(bucket_name)
+        .object(object_key)
+        .body(RequestBody::Empty)
+        .content_length(0);
+
+    if forbid_overwrite {
+        request = request.add_header("x-oss-forbid-overwrite", "true");
     }
 
     Ok(request)
 }
 
+/// Delete the "folder" marker object. The key is normalized the same way as `build_create_folder_request`
+pub(crate) fn build_delete_folder_request(bucket_name: &str, object_key: &str) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+    }
+
+    let object_key = normalize_folder_key(object_key)?;
+
+    Ok(OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).object(object_key))
+}
+
+/// List at most one object under the folder to check whether the folder exists
+pub(crate) fn build_folder_exists_options(object_key: &str) -> Result<ListObjectsOptions> {
+    Ok(ListObjectsOptions {
+        prefix: Some(normalize_folder_key(object_key)?),
+        max_keys: Some(1),
+        ..Default::default()
+    })
+}
+
 pub(crate) fn build_head_object_request(bucket_name: &str, object_key: &str, options: &Option<HeadObjectOptions>) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
         return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
     }
 
     let mut request = OssRequest::new().method(RequestMethod::Head).bucket(bucket_name).object(object_key);
@@ -1390,8 +2250,35 @@ pub enum PutObjectResult {
 
     /// This is your callback response content string when you put object with callback specified.
     /// `.0` should be a valid JSON string.
+    /// If OSS failed to call your callback server, it responds with status `203` and `.0` is the `CallbackFailed` error XML.
+    /// The object is stored anyway. Use `callback_failed()` to check this case.
+    /// `.1` is the response headers from aliyun oss api, e.g. the version id of the new object.
     #[cfg_attr(feature = "serde-camelcase", serde(rename = "callbackResponse"))]
-    CallbackResponse(String),
+    CallbackResponse(String, PutObjectApiResponse),
+}
+
+impl PutObjectResult {
+    /// The response headers from aliyun oss api, no matter callback is specified or not.
+    pub fn api_response(&self) -> &PutObjectApiResponse {
+        match self {
+            PutObjectResult::ApiResponse(r) => r,
+            PutObjectResult::CallbackResponse(_, r) => r,
+        }
+    }
+
+    /// The version id of the new object.
+    /// Only available when the object is put to a bucket with versioning enabled.
+    pub fn version_id(&self) -> Option<&str> {
+        self.api_response().version_id.as_deref()
+    }
+
+    /// Whether the object is stored but OSS failed to call your callback server (HTTP status `203`).
+    pub fn callback_failed(&self) -> bool {
+        match self {
+            PutObjectResult::ApiResponse(_) => false,
+            PutObjectResult::CallbackResponse(s, _) => common::is_callback_failed(s),
+        }
+    }
 }
 
 /// The response headers from aliyun oss put object api
@@ -1414,7 +2301,7 @@ pub struct PutObjectApiResponse {
     pub version_id: Option<String>,
 }
 
-/// If you put object without callback, parse aliyun oss api headers into `PutObjectResult::WithoutCallback` enum variant
+/// Parse aliyun oss api headers into `PutObjectApiResponse`, which is held by both `PutObjectResult::ApiResponse` and `PutObjectResult::CallbackResponse`
 impl From<HashMap<String, String>> for PutObjectApiResponse {
     fn from(mut headers: HashMap<String, String>) -> Self {
         Self {
@@ -1464,6 +2351,12 @@ pub struct DeleteMultipleObjectsRequest {
 
     /// Object keys to delete
     pub objects: Vec<DeleteMultipleObjectsItem>,
+
+    /// 不计算并发送 `Content-MD5` 请求头，默认为 `false`。
+    ///
+    /// 删除的 object 很多时，计算请求体的 MD5 会带来额外的开销。在可信的网络环境中，批量任务可以设置为 `true` 以提高吞吐量。
+    /// 代价是 OSS 无法校验请求体在传输过程中是否被篡改或者损坏，请求体出错时可能会删除非预期的 object。
+    pub skip_content_md5: bool,
 }
 
 impl DeleteMultipleObjectsRequest {
@@ -1624,13 +2517,15 @@ where
         DeleteMultipleObjectsConfig::FullRequest(delete_multiple_objects_request) => delete_multiple_objects_request,
     };
 
+    let skip_content_md5 = payload.skip_content_md5;
     let xml_content = payload.into_xml()?;
-    let content_md5 = BASE64_STANDARD.encode(*md5::compute(xml_content.as_bytes()));
 
-    request = request
-        .content_length(xml_content.len() as u64)
-        .add_header("content-md5", content_md5)
-        .text_body(xml_content);
+    if !skip_content_md5 {
+        let content_md5 = BASE64_STANDARD.encode(*md5::compute(xml_content.as_bytes()));
+        request = request.add_header("content-md5", content_md5);
+    }
+
+    request = request.content_length(xml_content.len() as u64).text_body(xml_content);
 
     Ok(request)
 }
@@ -1724,6 +2619,34 @@ pub struct Callback {
     pub custom_variables: HashMap<String, String>,
 }
 
+impl Callback {
+    /// Value of the `x-oss-callback-var` header: base64 encoded JSON of the custom variables, e.g. `{"x:my_var":"value"}`.
+    /// The `x:` prefix is added to the variable names if missing. Returns `None` if there are no custom variables
+    pub fn custom_variables_header(&self) -> Option<String> {
+        if self.custom_variables.is_empty() {
+            return None;
+        }
+
+        let vars = self
+            .custom_variables
+            .iter()
+            .map(|(k, v)| (format!("x:{}", k.strip_prefix("x:").unwrap_or(k)), v))
+            .collect::<HashMap<_, _>>();
+
+        serde_json::to_string(&vars).ok().map(|s| BASE64_STANDARD.encode(s))
+    }
+
+    /// Decode the value of `x-oss-callback-var` header generated by [`Self::custom_variables_header`].
+    /// Keys of the returned map have the `x:` prefix
+    pub fn decode_custom_variables_header(value: &str) -> Result<HashMap<String, String>> {
+        let data = BASE64_STANDARD
+            .decode(value)
+            .map_err(|e| Error::Other(format!("decoding callback variables failed: {}", e)))?;
+
+        Ok(serde_json::from_slice(&data)?)
+    }
+}
+
 /// 回调请求数据枚举值
 ///
 /// `Oss` 开头的，其中 `.0` 是此参数值对应的参数名。
@@ -2025,13 +2948,41 @@ impl From<HashMap<String, String>> for RestoreObjectResult {
     }
 }
 
+/// Result of restoring all archived objects under a prefix
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct RestorePrefixResult {
+    /// 成功提交解冻请求的 Object 数量
+    pub submitted: u64,
+
+    /// 已经在解冻中的 Object 数量（错误码 `RestoreAlreadyInProgress`）
+    pub already_in_progress: u64,
+
+    /// 提交解冻请求失败的 Object 数量
+    pub failed: u64,
+
+    /// 提交解冻请求失败的 Object Key
+    pub failed_keys: Vec<String>,
+}
+
+/// Only objects with `Archive`, `ColdArchive` or `DeepColdArchive` storage class need to be restored
+pub(crate) fn need_restore(storage_class: &StorageClass) -> bool {
+    matches!(storage_class, StorageClass::Archive | StorageClass::ColdArchive | StorageClass::DeepColdArchive)
+}
+
+/// Check if the error is responded with `RestoreAlreadyInProgress` code
+pub(crate) fn is_restore_already_in_progress(e: &Error) -> bool {
+    e.oss_error_code() == Some(OssErrorCode::RestoreAlreadyInProgress)
+}
+
 pub(crate) fn build_restore_object_request(bucket_name: &str, object_key: &str, config: RestoreObjectRequest) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
         return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
     }
 
     let mut request = OssRequest::new()
@@ -2050,12 +3001,440 @@ pub(crate) fn build_restore_object_request(bucket_name: &str, object_key: &str,
     Ok(request)
 }
 
-#[cfg(test)]
-mod test_object_common {
-    use crate::object_common::CallbackBodyParameter;
+/// Compression type of the object to be selected
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum SelectCompressionType {
+    #[default]
+    #[cfg_attr(feature = "serde-support", serde(rename = "None"))]
+    None,
 
-    #[cfg(feature = "serde-support")]
-    use super::PutObjectResult;
+    #[cfg_attr(feature = "serde-support", serde(rename = "GZIP"))]
+    Gzip,
+}
+
+impl SelectCompressionType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SelectCompressionType::None => "None",
+            SelectCompressionType::Gzip => "GZIP",
+        }
+    }
+}
+
+/// How to treat the first line of the CSV object
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum CsvFileHeaderInfo {
+    /// 第一行不是表头
+    #[default]
+    #[cfg_attr(feature = "serde-support", serde(rename = "NONE"))]
+    None,
+
+    /// 第一行是表头，但是在 SQL 中不使用列名
+    #[cfg_attr(feature = "serde-support", serde(rename = "IGNORE"))]
+    Ignore,
+
+    /// 第一行是表头，可以在 SQL 中使用列名
+    #[cfg_attr(feature = "serde-support", serde(rename = "USE"))]
+    Use,
+}
+
+impl CsvFileHeaderInfo {
+    pub fn as_str(&self) -> &str {
+        match self {
+            CsvFileHeaderInfo::None => "NONE",
+            CsvFileHeaderInfo::Ignore => "IGNORE",
+            CsvFileHeaderInfo::Use => "USE",
+        }
+    }
+}
+
+/// Input format of CSV object. The delimiters and characters are sent base64 encoded, leave `None` to use the default value
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct CsvInput {
+    pub file_header_info: Option<CsvFileHeaderInfo>,
+
+    /// 行分隔符，默认为 `\n`
+    pub record_delimiter: Option<String>,
+
+    /// 列分隔符，默认为 `,`
+    pub field_delimiter: Option<String>,
+
+    /// 引号字符，默认为 `"`
+    pub quote_character: Option<String>,
+
+    /// 注释字符，默认为 `#`
+    pub comment_character: Option<String>,
+
+    /// 查询的范围，例如：`line-range=10-20` 或者 `split-range=0-9`。需要先为 Object 创建 select meta
+    pub range: Option<String>,
+
+    /// 列值中是否可以包含行分隔符
+    pub allow_quoted_record_delimiter: Option<bool>,
+}
+
+/// Type of JSON object
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum JsonInputType {
+    /// 整个 Object 是一个 JSON 文档
+    #[default]
+    #[cfg_attr(feature = "serde-support", serde(rename = "DOCUMENT"))]
+    Document,
+
+    /// 每行是一个 JSON 对象
+    #[cfg_attr(feature = "serde-support", serde(rename = "LINES"))]
+    Lines,
+}
+
+impl JsonInputType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            JsonInputType::Document => "DOCUMENT",
+            JsonInputType::Lines => "LINES",
+        }
+    }
+}
+
+/// Input format of JSON object
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct JsonInput {
+    pub json_type: JsonInputType,
+
+    /// 是否将 JSON 中的数字作为字符串处理
+    pub parse_json_number_as_string: Option<bool>,
+
+    /// 查询的范围，仅 `LINES` 类型支持。需要先为 Object 创建 select meta
+    pub range: Option<String>,
+}
+
+/// Input serialization of select object. The output has the same format as the input
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum SelectInputSerialization {
+    Csv(CsvInput),
+    Json(JsonInput),
+}
+
+impl Default for SelectInputSerialization {
+    fn default() -> Self {
+        Self::Csv(CsvInput::default())
+    }
+}
+
+/// Output serialization of select object
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct SelectOutputSerialization {
+    /// 输出的行分隔符，默认为 `\n`
+    pub record_delimiter: Option<String>,
+
+    /// 输出的列分隔符，默认为 `,`。仅 CSV 格式有效
+    pub field_delimiter: Option<String>,
+
+    /// 是否输出所有的列，未在 SQL 中选择的列输出为空
+    pub keep_all_columns: Option<bool>,
+
+    /// 是否在结果中输出 CSV 的表头。仅 CSV 格式有效
+    pub output_header: Option<bool>,
+
+    /// 是否在每一帧中返回负载的 CRC32 校验值，开启后会校验每一帧的数据
+    pub enable_payload_crc: bool,
+}
+
+/// Request of select object. Query CSV or JSON object using SQL and returns only the matching records.
+///
+/// Official document: <https://help.aliyun.com/zh/oss/developer-reference/selectobject>
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct SelectRequest {
+    /// SQL 语句，例如：`select * from ossobject where _1 > 100`。发送时会进行 base64 编码
+    pub expression: String,
+    pub compression_type: Option<SelectCompressionType>,
+    pub input_serialization: SelectInputSerialization,
+    pub output_serialization: SelectOutputSerialization,
+
+    /// 是否忽略列数不足的行
+    pub skip_partial_data_record: Option<bool>,
+
+    /// 最多允许跳过的格式错误的行数，超过之后查询失败
+    pub max_skipped_records_allowed: Option<u64>,
+}
+
+impl SelectRequest {
+    /// `csv/select` or `json/select`
+    pub(crate) fn process(&self) -> &str {
+        match &self.input_serialization {
+            SelectInputSerialization::Csv(_) => "csv/select",
+            SelectInputSerialization::Json(_) => "json/select",
+        }
+    }
+
+    pub(crate) fn to_xml(&self) -> Result<String> {
+        let b64 = |s: &str| BASE64_STANDARD.encode(s);
+
+        let mut writer = quick_xml::Writer::new(Vec::new());
+        writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+        writer.write_event(Event::Start(BytesStart::new("SelectRequest")))?;
+
+        write_text_element(&mut writer, "Expression", &b64(&self.expression))?;
+
+        writer.write_event(Event::Start(BytesStart::new("InputSerialization")))?;
+        if let Some(c) = &self.compression_type {
+            write_text_element(&mut writer, "CompressionType", c.as_str())?;
+        }
+
+        match &self.input_serialization {
+            SelectInputSerialization::Csv(csv) => {
+                writer.write_event(Event::Start(BytesStart::new("CSV")))?;
+                if let Some(h) = &csv.file_header_info {
+                    write_text_element(&mut writer, "FileHeaderInfo", h.as_str())?;
+                }
+                if let Some(s) = &csv.record_delimiter {
+                    write_text_element(&mut writer, "RecordDelimiter", &b64(s))?;
+                }
+                if let Some(s) = &csv.field_delimiter {
+                    write_text_element(&mut writer, "FieldDelimiter", &b64(s))?;
+                }
+                if let Some(s) = &csv.quote_character {
+                    write_text_element(&mut writer, "QuoteCharacter", &b64(s))?;
+                }
+                if let Some(s) = &csv.comment_character {
+                    write_text_element(&mut writer, "CommentCharacter", &b64(s))?;
+                }
+                if let Some(s) = &csv.range {
+                    write_text_element(&mut writer, "Range", s)?;
+                }
+                if let Some(b) = csv.allow_quoted_record_delimiter {
+                    write_text_element(&mut writer, "AllowQuotedRecordDelimiter", &b.to_string())?;
+                }
+                writer.write_event(Event::End(BytesEnd::new("CSV")))?;
+            }
+            SelectInputSerialization::Json(json) => {
+                writer.write_event(Event::Start(BytesStart::new("JSON")))?;
+                write_text_element(&mut writer, "Type", json.json_type.as_str())?;
+                if let Some(b) = json.parse_json_number_as_string {
+                    write_text_element(&mut writer, "ParseJsonNumberAsString", &b.to_string())?;
+                }
+                if let Some(s) = &json.range {
+                    write_text_element(&mut writer, "Range", s)?;
+                }
+                writer.write_event(Event::End(BytesEnd::new("JSON")))?;
+            }
+        }
+        writer.write_event(Event::End(BytesEnd::new("InputSerialization")))?;
+
+        let output = &self.output_serialization;
+        writer.write_event(Event::Start(BytesStart::new("OutputSerialization")))?;
+        match &self.input_serialization {
+            SelectInputSerialization::Csv(_) => {
+                writer.write_event(Event::Start(BytesStart::new("CSV")))?;
+                if let Some(s) = &output.record_delimiter {
+                    write_text_element(&mut writer, "RecordDelimiter", &b64(s))?;
+                }
+                if let Some(s) = &output.field_delimiter {
+                    write_text_element(&mut writer, "FieldDelimiter", &b64(s))?;
+                }
+                writer.write_event(Event::End(BytesEnd::new("CSV")))?;
+            }
+            SelectInputSerialization::Json(_) => {
+                writer.write_event(Event::Start(BytesStart::new("JSON")))?;
+                if let Some(s) = &output.record_delimiter {
+                    write_text_element(&mut writer, "RecordDelimiter", &b64(s))?;
+                }
+                writer.write_event(Event::End(BytesEnd::new("JSON")))?;
+            }
+        }
+        if let Some(b) = output.keep_all_columns {
+            write_text_element(&mut writer, "KeepAllColumns", &b.to_string())?;
+        }
+        // 总是按帧返回，由 `SelectFrameDecoder` 解析
+        write_text_element(&mut writer, "OutputRawData", "false")?;
+        write_text_element(&mut writer, "EnablePayloadCrc", &output.enable_payload_crc.to_string())?;
+        if let Some(b) = output.output_header {
+            write_text_element(&mut writer, "OutputHeader", &b.to_string())?;
+        }
+        writer.write_event(Event::End(BytesEnd::new("OutputSerialization")))?;
+
+        if self.skip_partial_data_record.is_some() || self.max_skipped_records_allowed.is_some() {
+            writer.write_event(Event::Start(BytesStart::new("Options")))?;
+            if let Some(b) = self.skip_partial_data_record {
+                write_text_element(&mut writer, "SkipPartialDataRecord", &b.to_string())?;
+            }
+            if let Some(n) = self.max_skipped_records_allowed {
+                write_text_element(&mut writer, "MaxSkippedRecordsAllowed", &n.to_string())?;
+            }
+            writer.write_event(Event::End(BytesEnd::new("Options")))?;
+        }
+
+        writer.write_event(Event::End(BytesEnd::new("SelectRequest")))?;
+
+        Ok(String::from_utf8(writer.into_inner())?)
+    }
+}
+
+pub(crate) fn build_select_object_request(bucket_name: &str, object_key: &str, select_request: &SelectRequest) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+    }
+
+    if !validate_object_key(object_key) {
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
+    }
+
+    if select_request.expression.trim().is_empty() {
+        return Err(Error::Other("select expression cannot be empty".to_string()));
+    }
+
+    let xml = select_request.to_xml()?;
+    let md5 = content_md5_base64(xml.as_bytes());
+
+    let request = OssRequest::new()
+        .method(RequestMethod::Post)
+        .bucket(bucket_name)
+        .object(object_key)
+        .add_query("x-oss-process", select_request.process())
+        .add_header("content-md5", &md5)
+        .content_type(MIME_TYPE_XML)
+        .text_body(xml);
+
+    Ok(request)
+}
+
+const SELECT_FRAME_DATA: u32 = 8388609;
+const SELECT_FRAME_CONTINUOUS: u32 = 8388612;
+const SELECT_FRAME_END: u32 = 8388613;
+
+/// Version | Frame Type (4 bytes) + Payload Length (4 bytes) + Header Checksum (4 bytes)
+const SELECT_FRAME_HEADER_LEN: usize = 12;
+
+/// Payload Checksum (4 bytes)
+const SELECT_FRAME_CHECKSUM_LEN: usize = 4;
+
+/// Frame of the select object response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum SelectFrame {
+    /// Records matching the expression
+    Data(bytes::Bytes),
+
+    /// Keep-alive frame sent while scanning without matching records
+    Continuous,
+
+    /// The last frame. Failed queries are returned as error by the decoder
+    End { scanned_bytes: u64 },
+}
+
+/// Incremental decoder of the select object response body.
+///
+/// Each frame is: Version | Frame Type (4 bytes), Payload Length (4 bytes), Header Checksum (4 bytes),
+/// Payload, Payload Checksum (4 bytes). All integers are big endian.
+/// The payload of data frame is the scanned offset (8 bytes) followed by the records,
+/// and the payload of end frame is the offset (8 bytes), total scanned bytes (8 bytes), HTTP status code (4 bytes) and error message
+#[derive(Debug, Default)]
+pub(crate) struct SelectFrameDecoder {
+    buf: bytes::BytesMut,
+    verify_crc: bool,
+    finished: bool,
+}
+
+impl SelectFrameDecoder {
+    pub(crate) fn new(verify_crc: bool) -> Self {
+        Self {
+            verify_crc,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn push(&mut self, data: &[u8]) {
+        self.buf.extend_from_slice(data);
+    }
+
+    /// Whether the end frame has been decoded
+    pub(crate) fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Returns `None` if more data is needed to decode the next frame
+    pub(crate) fn next_frame(&mut self) -> Result<Option<SelectFrame>> {
+        use bytes::Buf;
+
+        if self.finished || self.buf.len() < SELECT_FRAME_HEADER_LEN {
+            return Ok(None);
+        }
+
+        let frame_type = u32::from_be_bytes([0, self.buf[1], self.buf[2], self.buf[3]]);
+        let payload_len = u32::from_be_bytes([self.buf[4], self.buf[5], self.buf[6], self.buf[7]]) as usize;
+
+        if self.buf.len() < SELECT_FRAME_HEADER_LEN + payload_len + SELECT_FRAME_CHECKSUM_LEN {
+            return Ok(None);
+        }
+
+        self.buf.advance(SELECT_FRAME_HEADER_LEN);
+        let mut payload = self.buf.split_to(payload_len).freeze();
+        let checksum = self.buf.get_u32();
+
+        // 未开启校验时服务端返回 0
+        if self.verify_crc && checksum != 0 {
+            let actual = crate::util::crc32(&payload);
+            if actual != checksum {
+                return Err(Error::Other(format!(
+                    "select object frame crc32 mismatch: expected {}, actual {}",
+                    checksum, actual
+                )));
+            }
+        }
+
+        if payload.len() < 8 {
+            return Err(Error::Other(format!(
+                "invalid select object frame, type: {}, payload length: {}",
+                frame_type, payload_len
+            )));
+        }
+
+        // 已经扫描的字节数
+        let _offset = payload.get_u64();
+
+        match frame_type {
+            SELECT_FRAME_DATA => Ok(Some(SelectFrame::Data(payload))),
+            SELECT_FRAME_CONTINUOUS => Ok(Some(SelectFrame::Continuous)),
+            SELECT_FRAME_END => {
+                if payload.len() < 12 {
+                    return Err(Error::Other(format!("invalid select object end frame, payload length: {}", payload_len)));
+                }
+
+                self.finished = true;
+
+                let scanned_bytes = payload.get_u64();
+                let status = payload.get_u32();
+
+                if !(200..300).contains(&status) {
+                    return Err(Error::Other(format!(
+                        "select object failed with status {}: {}",
+                        status,
+                        String::from_utf8_lossy(&payload)
+                    )));
+                }
+
+                Ok(Some(SelectFrame::End { scanned_bytes }))
+            }
+            _ => Err(Error::Other(format!("unknown select object frame type: {}", frame_type))),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_object_common {
+    use crate::object_common::CallbackBodyParameter;
+
+    #[cfg(feature = "serde-support")]
+    use super::PutObjectResult;
 
     #[test]
     fn test_callback_body_parameter() {
@@ -2072,6 +3451,743 @@ mod test_object_common {
         );
     }
 
+    #[test]
+    fn test_callback_custom_variables_header() {
+        use std::collections::HashMap;
+
+        use crate::{
+            object_common::{build_put_object_request, Callback, CallbackBuilder, PutObjectOptionsBuilder},
+            request::RequestBody,
+        };
+
+        let cb = CallbackBuilder::new("https://example.com/callback")
+            .body_parameter(CallbackBodyParameter::OssBucket("bucket"))
+            .body_parameter(CallbackBodyParameter::Custom("v1", "v1", "你好 world".to_string()))
+            .custom_variable("x:v2", "b")
+            .build();
+
+        let options = PutObjectOptionsBuilder::new().callback(cb).build();
+        let request = build_put_object_request("yuanyq", "a.txt", RequestBody::Empty, &Some(options)).unwrap();
+
+        let header = request.headers.get("x-oss-callback-var").unwrap();
+        let vars = Callback::decode_custom_variables_header(header).unwrap();
+        assert_eq!(
+            HashMap::from([("x:v1".to_string(), "你好 world".to_string()), ("x:v2".to_string(), "b".to_string())]),
+            vars
+        );
+
+        // 直接构造时缺少 `x:` 前缀也能正确传递
+        let cb = Callback {
+            custom_variables: HashMap::from([("v1".to_string(), "a".to_string())]),
+            ..CallbackBuilder::new("https://example.com/callback").build()
+        };
+        let vars = Callback::decode_custom_variables_header(&cb.custom_variables_header().unwrap()).unwrap();
+        assert_eq!(HashMap::from([("x:v1".to_string(), "a".to_string())]), vars);
+
+        let cb = CallbackBuilder::new("https://example.com/callback").build();
+        assert!(cb.custom_variables_header().is_none());
+
+        assert!(Callback::decode_custom_variables_header("not base64!").is_err());
+    }
+
+    #[test]
+    fn test_content_sha256() {
+        use std::collections::HashMap;
+
+        use crate::{
+            object_common::{build_put_object_request, ObjectMetadata, PutObjectOptionsBuilder},
+            request::RequestBody,
+        };
+
+        let digest = "E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855";
+        let options = PutObjectOptionsBuilder::new().content_sha256(digest).build();
+        let request = build_put_object_request("yuanyq", "test.txt", RequestBody::Empty, &Some(options)).unwrap();
+        assert_eq!(Some(&digest.to_lowercase()), request.headers.get("x-oss-content-sha256"));
+        assert!(request.build_canonical_request().ends_with(&digest.to_lowercase()));
+
+        let options = PutObjectOptionsBuilder::new().content_sha256("abc").build();
+        assert!(build_put_object_request("yuanyq", "test.txt", RequestBody::Empty, &Some(options)).is_err());
+
+        let request = build_put_object_request("yuanyq", "test.txt", RequestBody::Empty, &None).unwrap();
+        assert!(request.build_canonical_request().ends_with("UNSIGNED-PAYLOAD"));
+
+        let headers = HashMap::from([("content-sha256".to_string(), digest.to_string())]);
+        assert_eq!(Some(digest.to_string()), ObjectMetadata::from(headers).hash_sha256);
+
+        let headers = HashMap::from([("x-oss-hash-sha256".to_string(), digest.to_string())]);
+        assert_eq!(Some(digest.to_string()), ObjectMetadata::from(headers).hash_sha256);
+    }
+
+    #[test]
+    fn test_put_object_result_version_id() {
+        use std::collections::HashMap;
+
+        use crate::object_common::PutObjectResult;
+
+        let headers = HashMap::from([
+            ("x-oss-request-id".to_string(), "abc".to_string()),
+            (
+                "x-oss-version-id".to_string(),
+                "CAEQNhiBgMDJgZCA0BYiIDc4MGZjZGI2OTBjOTRmNTE5NmU5NmFhZjhjYmY0****".to_string(),
+            ),
+        ]);
+
+        let ret = PutObjectResult::ApiResponse(headers.clone().into());
+        assert_eq!(Some("CAEQNhiBgMDJgZCA0BYiIDc4MGZjZGI2OTBjOTRmNTE5NmU5NmFhZjhjYmY0****"), ret.version_id());
+
+        let ret = PutObjectResult::CallbackResponse("{\"status\":\"ok\"}".to_string(), headers.into());
+        assert_eq!(Some("CAEQNhiBgMDJgZCA0BYiIDc4MGZjZGI2OTBjOTRmNTE5NmU5NmFhZjhjYmY0****"), ret.version_id());
+        assert_eq!("abc", ret.api_response().request_id);
+
+        let ret = PutObjectResult::ApiResponse(HashMap::new().into());
+        assert!(ret.version_id().is_none());
+        assert!(!ret.callback_failed());
+
+        let ret = PutObjectResult::CallbackResponse(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><Error><Code>CallbackFailed</Code><Message>Error status : 502.</Message></Error>".to_string(),
+            HashMap::new().into(),
+        );
+        assert!(ret.callback_failed());
+    }
+
+    #[test]
+    fn test_standard_range_behavior() {
+        use crate::object_common::{build_get_object_request, GetObjectOptionsBuilder};
+
+        let options = GetObjectOptionsBuilder::new().range("bytes=0-9").standard_range_behavior(true).build();
+        let request = build_get_object_request("yuanyq", "test.txt", &Some(options)).unwrap();
+        assert_eq!(Some(&"standard".to_string()), request.headers.get("x-oss-range-behavior"));
+        assert_eq!(Some(&"bytes=0-9".to_string()), request.headers.get("range"));
+
+        let options = GetObjectOptionsBuilder::new().range("bytes=0-9").build();
+        let request = build_get_object_request("yuanyq", "test.txt", &Some(options)).unwrap();
+        assert!(!request.headers.contains_key("x-oss-range-behavior"));
+    }
+
+    #[test]
+    fn test_byte_range() {
+        use crate::object_common::{build_get_object_request, ByteRange, GetObjectOptionsBuilder};
+
+        assert_eq!("bytes=0-9", ByteRange::FromTo(0, 9).to_header_value());
+        assert_eq!("bytes=100-", ByteRange::From(100).to_header_value());
+        assert_eq!("bytes=-500", ByteRange::Suffix(500).to_header_value());
+        assert_eq!("bytes=-500", ByteRange::Suffix(500).to_string());
+
+        let options = GetObjectOptionsBuilder::new().byte_range(ByteRange::From(100)).build();
+        let request = build_get_object_request("yuanyq", "test.txt", &Some(options)).unwrap();
+        assert_eq!(Some(&"bytes=100-".to_string()), request.headers.get("range"));
+
+        // the later setter wins
+        let options = GetObjectOptionsBuilder::new().byte_range(ByteRange::Suffix(10)).range("bytes=0-9").build();
+        assert_eq!(Some("bytes=0-9"), options.range.as_deref());
+    }
+
+    #[test]
+    fn test_version_id_requests() {
+        use crate::{
+            common::WithVersionId,
+            object_common::{
+                build_copy_object_request, build_get_object_request, build_head_object_request, CopyObjectOptionsBuilder, GetObjectOptions, HeadObjectOptions,
+            },
+        };
+
+        let version_id = "CAEQNhiBgMDJgZCA0BYiIDc4MGZjZGI2OTBjOTRmNTE5NmU5NmFhZjhjYmY0****";
+
+        let request = build_get_object_request("yuanyq", "test.txt", &Some(GetObjectOptions::with_version_id(version_id))).unwrap();
+        assert_eq!(Some(&version_id.to_string()), request.query.get("versionId"));
+
+        let request = build_head_object_request("yuanyq", "test.txt", &Some(HeadObjectOptions::with_version_id(version_id))).unwrap();
+        assert_eq!(Some(&version_id.to_string()), request.query.get("versionId"));
+
+        let options = CopyObjectOptionsBuilder::new().source_version_id(version_id).build();
+        let request = build_copy_object_request("yuanyq", "source.txt", "yuanyq-2", "dest.txt", &Some(options)).unwrap();
+        assert_eq!(
+            Some(&format!("/yuanyq/source.txt?versionId={}", urlencoding::encode(version_id))),
+            request.headers.get("x-oss-copy-source")
+        );
+        assert!(!request.query.contains_key("versionId"));
+
+        // version id 中的 `+`、`/`、`=` 等字符需要编码
+        let options = CopyObjectOptionsBuilder::new().source_version_id("a+b/c=").build();
+        let request = build_copy_object_request("yuanyq", "source.txt", "yuanyq-2", "dest.txt", &Some(options)).unwrap();
+        assert_eq!(
+            Some(&"/yuanyq/source.txt?versionId=a%2Bb%2Fc%3D".to_string()),
+            request.headers.get("x-oss-copy-source")
+        );
+    }
+
+    #[test]
+    fn test_check_copy_region() {
+        use crate::{error::Error, object_common::check_copy_region};
+
+        assert!(check_copy_region("oss-cn-hangzhou", "oss-cn-hangzhou").is_ok());
+
+        // 两个 bucket 在同一个地域，即使不是客户端所在的地域，也不是跨地域拷贝
+        assert!(check_copy_region("oss-cn-beijing", "oss-cn-beijing").is_ok());
+
+        let ret = check_copy_region("oss-cn-beijing", "oss-cn-hangzhou");
+        assert!(matches!(
+            ret,
+            Err(Error::CrossRegionCopyUnsupported { source_region, dest_region }) if source_region == "cn-beijing" && dest_region == "cn-hangzhou"
+        ));
+    }
+
+    #[test]
+    fn test_copy_multipart_helpers() {
+        use std::collections::HashMap;
+
+        use crate::{
+            common::{MetadataDirective, TagDirective},
+            object_common::{build_copy_multipart_options, build_copy_part_ranges, ContentEncoding, CopyObjectOptionsBuilder, ObjectMetadata},
+        };
+
+        assert!(build_copy_part_ranges(0).is_empty());
+        assert_eq!(vec![(1, "bytes=0-99".to_string())], build_copy_part_ranges(100));
+
+        let gb = 1024 * 1024 * 1024;
+        let ranges = build_copy_part_ranges(gb);
+        assert_eq!(11, ranges.len());
+        assert_eq!((1, "bytes=0-104857599".to_string()), ranges[0]);
+        assert_eq!((11, format!("bytes={}-{}", 100 * 1024 * 1024 * 10, gb - 1)), ranges[10]);
+
+        // 非常大的文件也不能超过 10000 个分片
+        assert!(build_copy_part_ranges(2 * 1024 * gb).len() <= 10000);
+
+        let source_metadata = ObjectMetadata {
+            content_type: Some("image/png".to_string()),
+            cache_control: Some("no-cache".to_string()),
+            content_disposition: Some("attachment".to_string()),
+            content_encoding: Some(ContentEncoding::Gzip),
+            metadata: HashMap::from([("x-oss-meta-a".to_string(), "b".to_string())]),
+            ..Default::default()
+        };
+        let source_tags = HashMap::from([("tag-a".to_string(), "a".to_string())]);
+
+        let options = CopyObjectOptionsBuilder::new().source_version_id("v1").build();
+        let (initiate_options, part_copy_options) = build_copy_multipart_options(&source_metadata, source_tags.clone(), &Some(options));
+        assert_eq!(Some("image/png"), initiate_options.mime_type.as_deref());
+        assert_eq!(Some("no-cache"), initiate_options.cache_control.as_deref());
+        assert_eq!(Some("attachment"), initiate_options.content_disposition.as_deref());
+        assert_eq!(Some(ContentEncoding::Gzip), initiate_options.content_encoding);
+        assert_eq!(Some(&"b".to_string()), initiate_options.metadata.get("x-oss-meta-a"));
+        assert_eq!(source_tags, initiate_options.tags);
+        assert_eq!(Some("v1"), part_copy_options.source_object_version_id.as_deref());
+
+        let options = CopyObjectOptionsBuilder::new()
+            .metadata_directive(MetadataDirective::Replace)
+            .metadata("x-oss-meta-c", "d")
+            .tag_directive(TagDirective::Replace)
+            .build();
+        let (initiate_options, _) = build_copy_multipart_options(&source_metadata, source_tags, &Some(options));
+        assert!(initiate_options.mime_type.is_none());
+        assert!(initiate_options.cache_control.is_none());
+        assert!(initiate_options.content_disposition.is_none());
+        assert!(initiate_options.content_encoding.is_none());
+        assert_eq!(1, initiate_options.metadata.len());
+        assert_eq!(Some(&"d".to_string()), initiate_options.metadata.get("x-oss-meta-c"));
+        assert!(initiate_options.tags.is_empty());
+    }
+
+    #[test]
+    fn test_create_folder_key() {
+        use crate::object_common::{build_create_folder_request, build_delete_folder_request, build_folder_exists_options, normalize_folder_key};
+
+        assert_eq!("a/b/", normalize_folder_key("a/b").unwrap());
+        assert_eq!("a/b/", normalize_folder_key("/a/b/").unwrap());
+        assert!(normalize_folder_key("a//b").is_err());
+        assert!(normalize_folder_key("/").is_err());
+        assert!(normalize_folder_key("").is_err());
+
+        let request = build_create_folder_request("yuanyq", "/a/b", false).unwrap();
+        assert_eq!("a/b/", request.object_key);
+        assert!(!request.headers.contains_key("x-oss-forbid-overwrite"));
+
+        let request = build_create_folder_request("yuanyq", "a/b/", true).unwrap();
+        assert_eq!(Some(&"true".to_string()), request.headers.get("x-oss-forbid-overwrite"));
+
+        let options = build_folder_exists_options("/a/b").unwrap();
+        assert_eq!(Some("a/b/".to_string()), options.prefix);
+        assert_eq!(Some(1), options.max_keys);
+        assert!(build_folder_exists_options("a//b").is_err());
+
+        let request = build_delete_folder_request("yuanyq", "/a/b").unwrap();
+        assert_eq!(crate::request::RequestMethod::Delete, request.method);
+        assert_eq!("a/b/", request.object_key);
+        assert!(build_delete_folder_request("yuanyq", "a//b").is_err());
+        assert!(build_delete_folder_request("yuanyq", "/").is_err());
+    }
+
+    #[test]
+    fn test_put_object_request_no_duplicated_headers() {
+        use crate::{
+            object_common::{build_put_object_request, PutObjectOptionsBuilder},
+            request::RequestBody,
+        };
+
+        let options = PutObjectOptionsBuilder::new()
+            .mime_type("text/plain")
+            .cache_control("no-cache")
+            .metadata("x-oss-meta-a", "b")
+            .build();
+
+        let request = build_put_object_request("yuanyq", "a.txt", RequestBody::Text("hello".to_string()), &Some(options))
+            .unwrap()
+            .add_header("Content-Type", "text/markdown")
+            .add_header("Cache-Control", "max-age=60");
+
+        assert_eq!(1, request.headers.keys().filter(|k| k.eq_ignore_ascii_case("content-type")).count());
+        assert_eq!(1, request.headers.keys().filter(|k| k.eq_ignore_ascii_case("cache-control")).count());
+        assert_eq!(Some("text/markdown"), request.headers.get("content-type").map(|s| s.as_str()));
+        assert_eq!(Some("max-age=60"), request.headers.get("cache-control").map(|s| s.as_str()));
+
+        let canonical_headers = request.build_canonical_headers();
+        assert_eq!(1, canonical_headers.matches("content-type:").count());
+    }
+
+    #[test]
+    fn test_object_metadata_content_type() {
+        use std::collections::HashMap;
+
+        use crate::object_common::ObjectMetadata;
+
+        let headers = HashMap::from([
+            ("content-type".to_string(), "image/jpeg".to_string()),
+            ("content-length".to_string(), "1024".to_string()),
+            ("x-oss-meta-a".to_string(), "b".to_string()),
+        ]);
+
+        let meta = ObjectMetadata::from(headers);
+        assert_eq!(Some("image/jpeg"), meta.content_type.as_deref());
+        assert_eq!(1024, meta.content_length);
+        assert_eq!(1, meta.metadata.len());
+
+        let meta = ObjectMetadata::from(HashMap::new());
+        assert!(meta.content_type.is_none());
+    }
+
+    #[test]
+    fn test_copy_object_result() {
+        use std::collections::HashMap;
+
+        use crate::object_common::CopyObjectResult;
+
+        let headers = HashMap::from([
+            ("x-oss-request-id".to_string(), "534B371674E88A4D8906****".to_string()),
+            (
+                "x-oss-version-id".to_string(),
+                "CAEQHxiBgICDvseg3hgiIGZmOGNjNWJiZDUzNjQxNDM4MWM2NDVlZjRkYzI****".to_string(),
+            ),
+            (
+                "x-oss-copy-source-version-id".to_string(),
+                "CAEQHxiBgICDvseg3hgiIGZmOGNjNWJiZDUzNjQxNDM4MWM2NDVlZjRkYzIx****".to_string(),
+            ),
+        ]);
+
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <CopyObjectResult>
+            <ETag>"F2064A169EE92E9775EE5324D0B1****"</ETag>
+            <LastModified>2023-02-24T09:41:56.000Z</LastModified>
+        </CopyObjectResult>"#;
+
+        let ret = CopyObjectResult::from_response(headers, xml).unwrap();
+        assert_eq!("534B371674E88A4D8906****", ret.request_id);
+        assert_eq!("F2064A169EE92E9775EE5324D0B1****", ret.etag);
+        assert_eq!(Some("2023-02-24T09:41:56.000Z"), ret.last_modified.as_deref());
+        assert_eq!(
+            Some("CAEQHxiBgICDvseg3hgiIGZmOGNjNWJiZDUzNjQxNDM4MWM2NDVlZjRkYzI****"),
+            ret.version_id.as_deref()
+        );
+        assert!(ret.source_version_id.is_some());
+
+        let ret = CopyObjectResult::from_response(HashMap::new(), xml).unwrap();
+        assert!(ret.version_id.is_none());
+        assert!(ret.source_version_id.is_none());
+    }
+
+    #[test]
+    fn test_object_retention() {
+        use std::collections::HashMap;
+
+        use crate::{
+            common::{ObjectRetention, ObjectRetentionMode},
+            object_common::{build_put_object_request, ObjectMetadata, PutObjectOptionsBuilder},
+            request::RequestBody,
+        };
+
+        let options = PutObjectOptionsBuilder::new()
+            .object_retention(ObjectRetentionMode::Governance, "2030-01-01T00:00:00.000Z")
+            .build();
+        let request = build_put_object_request("yuanyq", "a.txt", RequestBody::Empty, &Some(options)).unwrap();
+        assert_eq!(Some("GOVERNANCE"), request.headers.get("x-oss-object-retention-mode").map(|s| s.as_str()));
+        assert_eq!(
+            Some("2030-01-01T00:00:00.000Z"),
+            request.headers.get("x-oss-object-retention-retain-until-date").map(|s| s.as_str())
+        );
+
+        let request = build_put_object_request("yuanyq", "a.txt", RequestBody::Empty, &None).unwrap();
+        assert!(!request.headers.contains_key("x-oss-object-retention-mode"));
+
+        let headers = HashMap::from([
+            ("x-oss-object-retention-mode".to_string(), "COMPLIANCE".to_string()),
+            ("x-oss-object-retention-retain-until-date".to_string(), "2030-01-01T00:00:00.000Z".to_string()),
+        ]);
+        let meta = ObjectMetadata::from(headers);
+        assert_eq!(
+            Some(ObjectRetention::new(ObjectRetentionMode::Compliance, "2030-01-01T00:00:00.000Z")),
+            meta.object_retention
+        );
+
+        // 只有模式没有截止时间时不认为设置了保留策略
+        let headers = HashMap::from([("x-oss-object-retention-mode".to_string(), "COMPLIANCE".to_string())]);
+        assert!(ObjectMetadata::from(headers).object_retention.is_none());
+    }
+
+    #[test]
+    fn test_object_metadata_transition_times() {
+        use std::collections::HashMap;
+
+        use crate::object_common::ObjectMetadata;
+
+        let headers = HashMap::from([("x-oss-transition-time".to_string(), "Tue, 30 Mar 2021 06:07:48 GMT".to_string())]);
+        let meta = ObjectMetadata::from(headers);
+        assert_eq!(Some("Tue, 30 Mar 2021 06:07:48 GMT"), meta.transition_time.as_deref());
+        assert_eq!(vec!["Tue, 30 Mar 2021 06:07:48 GMT"], meta.transition_times);
+
+        let headers = HashMap::from([(
+            "x-oss-transition-time".to_string(),
+            "Tue, 30 Mar 2021 06:07:48 GMT, Fri, 30 Apr 2021 06:07:48 GMT".to_string(),
+        )]);
+        let meta = ObjectMetadata::from(headers);
+        assert_eq!(vec!["Tue, 30 Mar 2021 06:07:48 GMT", "Fri, 30 Apr 2021 06:07:48 GMT"], meta.transition_times);
+
+        let meta = ObjectMetadata::from(HashMap::new());
+        assert!(meta.transition_times.is_empty());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_expires_at() {
+        use crate::{
+            object_common::{build_put_object_request, PutObjectOptionsBuilder},
+            RequestBody,
+        };
+
+        let t = time::OffsetDateTime::from_unix_timestamp(1436374621)
+            .unwrap()
+            .to_offset(time::UtcOffset::from_hms(8, 0, 0).unwrap());
+        let options = PutObjectOptionsBuilder::new().expires_at(t).build();
+        assert_eq!(Some("Wed, 08 Jul 2015 16:57:01 GMT"), options.expires.as_deref());
+
+        let request = build_put_object_request("yuanyq", "test.txt", RequestBody::Bytes(b"hello".to_vec().into()), &Some(options)).unwrap();
+        assert_eq!(Some(&"Wed, 08 Jul 2015 16:57:01 GMT".to_string()), request.headers.get("expires"));
+    }
+
+    #[test]
+    fn test_traffic_limit() {
+        use crate::{
+            object_common::{build_put_object_request, PutObjectOptionsBuilder},
+            RequestBody,
+        };
+
+        let options = PutObjectOptionsBuilder::new().mime_type("text/plain").traffic_limit(819_200).build();
+        let request = build_put_object_request("yuanyq", "test.txt", RequestBody::Bytes(b"hello".to_vec().into()), &Some(options)).unwrap();
+        assert_eq!(Some(&"819200".to_string()), request.headers.get("x-oss-traffic-limit"));
+        assert_eq!(Some(&"text/plain".to_string()), request.headers.get("content-type"));
+
+        let options = PutObjectOptionsBuilder::new().traffic_limit(1024).build();
+        assert!(build_put_object_request("yuanyq", "test.txt", RequestBody::Bytes(b"hello".to_vec().into()), &Some(options)).is_err());
+    }
+
+    #[test]
+    fn test_request_timeout() {
+        use std::time::Duration;
+
+        use crate::{
+            object_common::{build_get_object_request, build_put_object_request, GetObjectOptionsBuilder, PutObjectOptionsBuilder},
+            RequestBody,
+        };
+
+        let request = build_get_object_request("yuanyq", "test.txt", &None).unwrap();
+        assert!(request.timeout.is_none());
+
+        let options = GetObjectOptionsBuilder::new().timeout(Duration::from_secs(600)).build();
+        let request = build_get_object_request("yuanyq", "test.txt", &Some(options)).unwrap();
+        assert_eq!(Some(Duration::from_secs(600)), request.timeout);
+
+        let options = PutObjectOptionsBuilder::new().timeout(Duration::from_secs(300)).build();
+        let request = build_put_object_request("yuanyq", "test.txt", RequestBody::Bytes(b"hello".to_vec().into()), &Some(options)).unwrap();
+        assert_eq!(Some(Duration::from_secs(300)), request.timeout);
+    }
+
+    #[test]
+    fn test_tags_limit() {
+        use crate::{
+            error::Error,
+            object_common::{build_copy_object_request, build_put_object_request, CopyObjectOptionsBuilder, PutObjectOptionsBuilder},
+            RequestBody,
+        };
+
+        let mut put_builder = PutObjectOptionsBuilder::new();
+        let mut copy_builder = CopyObjectOptionsBuilder::new();
+        for i in 0..10 {
+            put_builder = put_builder.tag(format!("k{}", i), "v");
+            copy_builder = copy_builder.tag(format!("k{}", i), "v");
+        }
+
+        let mut put_options = put_builder.build();
+        let mut copy_options = copy_builder.build();
+        assert!(build_put_object_request("yuanyq", "test.txt", RequestBody::Empty, &Some(put_options.clone())).is_ok());
+        assert!(build_copy_object_request("yuanyq", "source.txt", "yuanyq", "dest.txt", &Some(copy_options.clone())).is_ok());
+
+        put_options.tags.insert("k10".to_string(), "v".to_string());
+        copy_options.tags.insert("k10".to_string(), "v".to_string());
+
+        let ret = build_put_object_request("yuanyq", "test.txt", RequestBody::Empty, &Some(put_options));
+        assert!(matches!(ret, Err(Error::Other(s)) if s.contains("too many tags")));
+
+        let ret = build_copy_object_request("yuanyq", "source.txt", "yuanyq", "dest.txt", &Some(copy_options));
+        assert!(matches!(ret, Err(Error::Other(s)) if s.contains("too many tags")));
+    }
+
+    #[test]
+    fn test_put_source() {
+        use std::path::Path;
+
+        use crate::{
+            object_common::{build_put_object_request, PutSource},
+            RequestBody,
+        };
+
+        let body = PutSource::Base64("aGVsbG8=".to_string()).into_request_body().unwrap();
+        assert!(matches!(body, RequestBody::Bytes(data) if data == b"hello"[..]));
+
+        let data = bytes::Bytes::from_static(b"hello");
+        let body = PutSource::from(data.clone()).into_request_body().unwrap();
+        assert!(matches!(&body, RequestBody::Bytes(b) if b.as_ptr() == data.as_ptr()));
+
+        let body = PutSource::Reader(Box::new(std::io::Cursor::new(b"hello".to_vec())))
+            .into_request_body()
+            .unwrap();
+        assert!(matches!(body, RequestBody::Bytes(data) if data == b"hello"[..]));
+
+        let body = PutSource::from(Path::new("/tmp/a.txt")).into_request_body().unwrap();
+        assert!(matches!(body, RequestBody::File(path, None) if path == Path::new("/tmp/a.txt")));
+
+        assert!(PutSource::Base64("not base64!".to_string()).into_request_body().is_err());
+
+        let chunks = vec![bytes::Bytes::from_static(b"hello "), bytes::Bytes::from_static(b"world")];
+        let body = PutSource::Chunks(chunks.clone()).into_request_body().unwrap();
+        assert!(matches!(&body, RequestBody::Chunks(c) if *c == chunks));
+
+        let request = build_put_object_request("yuanyq", "a.txt", body, &None).unwrap();
+        assert_eq!(Some("11"), request.headers.get("content-length").map(|s| s.as_str()));
+    }
+
+    #[test]
+    fn test_restore_helpers() {
+        use crate::{
+            common::StorageClass,
+            error::{Error, ErrorResponse},
+            object_common::{is_restore_already_in_progress, need_restore},
+        };
+
+        assert!(need_restore(&StorageClass::Archive));
+        assert!(need_restore(&StorageClass::ColdArchive));
+        assert!(need_restore(&StorageClass::DeepColdArchive));
+        assert!(!need_restore(&StorageClass::Standard));
+        assert!(!need_restore(&StorageClass::IA));
+
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+  <Code>RestoreAlreadyInProgress</Code>
+  <Message>The restore operation is in progress.</Message>
+  <RequestId>5CAC0A3DB7AEADE01700****</RequestId>
+  <HostId>examplebucket.oss-cn-hangzhou.aliyuncs.com</HostId>
+</Error>"#;
+
+        let e = Error::ApiError(Box::new(ErrorResponse::from_xml(xml).unwrap()));
+        assert!(is_restore_already_in_progress(&e));
+        assert!(!is_restore_already_in_progress(&Error::Other("RestoreAlreadyInProgress".to_string())));
+    }
+
+    /// Build a select object frame: version 1, the given type, payload with a leading 8-byte offset
+    pub(crate) fn select_frame(frame_type: u32, body: &[u8], with_crc: bool) -> Vec<u8> {
+        let mut payload = 0u64.to_be_bytes().to_vec();
+        payload.extend_from_slice(body);
+
+        let mut frame = (0x0100_0000 | frame_type).to_be_bytes().to_vec();
+        frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&0u32.to_be_bytes());
+        frame.extend_from_slice(&payload);
+        let crc = if with_crc { crate::util::crc32(&payload) } else { 0 };
+        frame.extend_from_slice(&crc.to_be_bytes());
+        frame
+    }
+
+    pub(crate) fn select_end_frame(scanned_bytes: u64, status: u32, message: &str) -> Vec<u8> {
+        let mut body = scanned_bytes.to_be_bytes().to_vec();
+        body.extend_from_slice(&status.to_be_bytes());
+        body.extend_from_slice(message.as_bytes());
+        select_frame(8388613, &body, true)
+    }
+
+    #[test]
+    fn test_build_select_object_request() {
+        use base64::prelude::{Engine, BASE64_STANDARD};
+
+        use crate::{
+            object_common::{
+                build_select_object_request, CsvFileHeaderInfo, CsvInput, JsonInput, JsonInputType, SelectInputSerialization, SelectOutputSerialization,
+                SelectRequest,
+            },
+            request::RequestMethod,
+        };
+
+        let select_request = SelectRequest {
+            expression: "select * from ossobject where _1 > 100".to_string(),
+            input_serialization: SelectInputSerialization::Csv(CsvInput {
+                file_header_info: Some(CsvFileHeaderInfo::Use),
+                field_delimiter: Some(";".to_string()),
+                ..Default::default()
+            }),
+            output_serialization: SelectOutputSerialization {
+                enable_payload_crc: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let request = build_select_object_request("yuanyq", "data/sample.csv", &select_request).unwrap();
+        assert_eq!(RequestMethod::Post, request.method);
+        assert_eq!(Some(&"csv/select".to_string()), request.query.get("x-oss-process"));
+
+        let xml = select_request.to_xml().unwrap();
+        assert!(xml.contains(&format!(
+            "<Expression>{}</Expression>",
+            BASE64_STANDARD.encode("select * from ossobject where _1 > 100")
+        )));
+        assert!(xml.contains("<FileHeaderInfo>USE</FileHeaderInfo>"));
+        assert!(xml.contains(&format!("<FieldDelimiter>{}</FieldDelimiter>", BASE64_STANDARD.encode(";"))));
+        assert!(xml.contains("<OutputRawData>false</OutputRawData>"));
+        assert!(xml.contains("<EnablePayloadCrc>true</EnablePayloadCrc>"));
+        assert!(!xml.contains("<Options>"));
+
+        let select_request = SelectRequest {
+            expression: "select * from ossobject.objects[*] s where s.age > 18".to_string(),
+            input_serialization: SelectInputSerialization::Json(JsonInput {
+                json_type: JsonInputType::Lines,
+                ..Default::default()
+            }),
+            skip_partial_data_record: Some(true),
+            ..Default::default()
+        };
+
+        let request = build_select_object_request("yuanyq", "data/sample.json", &select_request).unwrap();
+        assert_eq!(Some(&"json/select".to_string()), request.query.get("x-oss-process"));
+
+        let xml = select_request.to_xml().unwrap();
+        assert!(xml.contains("<JSON><Type>LINES</Type></JSON>"));
+        assert!(xml.contains("<Options><SkipPartialDataRecord>true</SkipPartialDataRecord></Options>"));
+
+        let empty = SelectRequest::default();
+        assert!(build_select_object_request("yuanyq", "data/sample.csv", &empty).is_err());
+        assert!(build_select_object_request("-invalid", "data/sample.csv", &select_request).is_err());
+    }
+
+    #[test]
+    fn test_select_frame_decoder() {
+        use crate::object_common::{SelectFrame, SelectFrameDecoder};
+
+        let mut data = select_frame(8388609, b"1,foo\n", true);
+        data.extend(select_frame(8388612, b"", true));
+        data.extend(select_frame(8388609, b"2,bar\n", false));
+        data.extend(select_end_frame(1024, 200, ""));
+
+        // 按字节推入，确保跨越多个分片的帧也能正确解析
+        let mut decoder = SelectFrameDecoder::new(true);
+        let mut frames = vec![];
+        for b in &data {
+            decoder.push(std::slice::from_ref(b));
+            while let Some(frame) = decoder.next_frame().unwrap() {
+                frames.push(frame);
+            }
+        }
+
+        assert_eq!(
+            vec![
+                SelectFrame::Data(bytes::Bytes::from_static(b"1,foo\n")),
+                SelectFrame::Continuous,
+                SelectFrame::Data(bytes::Bytes::from_static(b"2,bar\n")),
+                SelectFrame::End { scanned_bytes: 1024 },
+            ],
+            frames
+        );
+        assert!(decoder.is_finished());
+
+        // 查询失败时在结束帧中返回状态码和错误信息
+        let mut decoder = SelectFrameDecoder::new(false);
+        decoder.push(&select_end_frame(0, 400, "InvalidSQL"));
+        let e = decoder.next_frame().unwrap_err();
+        assert!(e.to_string().contains("400") && e.to_string().contains("InvalidSQL"));
+
+        let mut frame = select_frame(8388609, b"1,foo\n", true);
+        let n = frame.len();
+        frame[n - 1] ^= 0xff;
+
+        let mut decoder = SelectFrameDecoder::new(true);
+        decoder.push(&frame);
+        assert!(decoder.next_frame().is_err());
+
+        // 不校验时忽略 CRC
+        let mut decoder = SelectFrameDecoder::new(false);
+        decoder.push(&frame);
+        assert!(matches!(decoder.next_frame(), Ok(Some(SelectFrame::Data(_)))));
+
+        let mut decoder = SelectFrameDecoder::new(false);
+        decoder.push(&select_frame(1234, b"", false));
+        assert!(decoder.next_frame().is_err());
+
+        let mut decoder = SelectFrameDecoder::new(false);
+        decoder.push(&select_frame(8388609, b"1,foo\n", false)[..10]);
+        assert_eq!(None, decoder.next_frame().unwrap());
+        assert!(!decoder.is_finished());
+    }
+
+    #[test]
+    fn test_restore_status() {
+        use crate::object_common::{ObjectMetadata, RestoreStatus};
+
+        let mut meta = ObjectMetadata {
+            restore: Some(r#"ongoing-request="true""#.to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            Some(RestoreStatus {
+                ongoing: true,
+                expiry_date: None
+            }),
+            meta.restore_status()
+        );
+
+        meta.restore = Some(r#"ongoing-request="false", expiry-date="Sun, 16 Apr 2017 08:12:33 GMT""#.to_string());
+        assert_eq!(
+            Some(RestoreStatus {
+                ongoing: false,
+                expiry_date: Some("Sun, 16 Apr 2017 08:12:33 GMT".to_string())
+            }),
+            meta.restore_status()
+        );
+
+        meta.restore = None;
+        assert_eq!(None, meta.restore_status());
+
+        meta.restore = Some("garbage".to_string());
+        assert_eq!(None, meta.restore_status());
+
+        meta.restore = Some(r#"ongoing-request="maybe""#.to_string());
+        assert_eq!(None, meta.restore_status());
+    }
+
     #[test]
     #[cfg(feature = "serde-support")]
     fn test_put_object_result_serde() {
@@ -2088,4 +4204,202 @@ mod test_object_common {
         let s = serde_json::to_string(&ret).unwrap();
         println!("{}", s);
     }
+
+    #[test]
+    fn test_build_delete_versions_requests() {
+        use crate::{common::DELETE_MULTIPLE_OBJECTS_LIMIT, object_common::build_delete_versions_requests};
+
+        assert!(build_delete_versions_requests("a.txt", vec![]).is_empty());
+
+        let ids = (0..DELETE_MULTIPLE_OBJECTS_LIMIT + 1).map(|i| format!("v{}", i)).collect::<Vec<_>>();
+        let requests = build_delete_versions_requests("a.txt", ids);
+        assert_eq!(2, requests.len());
+        assert_eq!(DELETE_MULTIPLE_OBJECTS_LIMIT, requests[0].objects.len());
+        assert_eq!(1, requests[1].objects.len());
+        assert_eq!(Some(false), requests[1].quiet);
+        assert_eq!("a.txt", requests[1].objects[0].key);
+        assert_eq!(Some(format!("v{}", DELETE_MULTIPLE_OBJECTS_LIMIT)), requests[1].objects[0].version_id);
+    }
+
+    #[test]
+    fn test_check_rename_object_keys() {
+        use crate::object_common::check_rename_object_keys;
+
+        assert!(check_rename_object_keys("a/b.txt", "a/c.txt").is_ok());
+        assert!(check_rename_object_keys("a/b.txt", "a/b.txt").is_err());
+    }
+
+    #[test]
+    fn test_build_rename_delete_options() {
+        use crate::object_common::{build_rename_delete_options, CopyObjectOptionsBuilder};
+
+        assert!(build_rename_delete_options(&None).is_none());
+        assert!(build_rename_delete_options(&Some(CopyObjectOptionsBuilder::new().forbid_overwrite(true).build())).is_none());
+
+        let options = build_rename_delete_options(&Some(CopyObjectOptionsBuilder::new().source_version_id("v1").build()));
+        assert_eq!(Some("v1"), options.and_then(|opt| opt.version_id).as_deref());
+    }
+
+    #[test]
+    fn test_delete_multiple_objects_skip_content_md5() {
+        use crate::object_common::{build_delete_multiple_objects_request, DeleteMultipleObjectsConfig, DeleteMultipleObjectsRequest};
+
+        let keys = ["a.txt", "b.txt"];
+        let request = build_delete_multiple_objects_request("test-bucket", DeleteMultipleObjectsConfig::FromKeys(&keys)).unwrap();
+        assert!(request.headers.contains_key("content-md5"));
+
+        let mut payload = DeleteMultipleObjectsRequest::from(&keys[..]);
+        payload.skip_content_md5 = true;
+        let request = build_delete_multiple_objects_request::<&str>("test-bucket", DeleteMultipleObjectsConfig::FullRequest(payload)).unwrap();
+        assert!(!request.headers.contains_key("content-md5"));
+        assert!(request.headers.contains_key("content-length"));
+    }
+
+    #[test]
+    fn test_verify_crc64() {
+        use crate::{
+            error::Error,
+            object_common::{check_verified_download_options, verify_crc64, GetObjectOptionsBuilder},
+        };
+
+        assert!(verify_crc64(Some(1), 1).is_ok());
+        assert!(matches!(verify_crc64(Some(1), 2), Err(Error::CrcMismatch { expected: 1, actual: 2 })));
+        assert!(verify_crc64(None, 1).is_err());
+
+        assert!(check_verified_download_options(&None).is_ok());
+        assert!(check_verified_download_options(&Some(GetObjectOptionsBuilder::new().build())).is_ok());
+        assert!(check_verified_download_options(&Some(GetObjectOptionsBuilder::new().range("bytes=0-9").build())).is_err());
+    }
+
+    #[test]
+    fn test_request_body_crc64() {
+        use std::collections::HashMap;
+
+        use crate::{
+            object_common::{request_body_crc64, response_crc64},
+            RequestBody,
+        };
+
+        let expected = 0x995DC9BBDF1939FA;
+
+        assert_eq!(expected, request_body_crc64(&RequestBody::Text("123456789".to_string())).unwrap());
+        assert_eq!(expected, request_body_crc64(&RequestBody::Bytes(b"123456789".as_slice().into())).unwrap());
+        assert_eq!(
+            expected,
+            request_body_crc64(&RequestBody::Chunks(vec![b"1234".to_vec().into(), b"56789".to_vec().into()])).unwrap()
+        );
+        assert_eq!(0, request_body_crc64(&RequestBody::Empty).unwrap());
+
+        let file = std::env::temp_dir().join("ali-oss-rs-test-request-body-crc64.txt");
+        std::fs::write(&file, b"abc123456789xyz").unwrap();
+        assert_eq!(expected, request_body_crc64(&RequestBody::File(file.clone(), Some(3..12))).unwrap());
+        assert_ne!(expected, request_body_crc64(&RequestBody::File(file.clone(), None)).unwrap());
+        std::fs::remove_file(&file).unwrap();
+
+        let headers = HashMap::from([("x-oss-hash-crc64ecma".to_string(), expected.to_string())]);
+        assert_eq!(Some(expected), response_crc64(&headers));
+        assert_eq!(None, response_crc64(&HashMap::new()));
+    }
+
+    #[test]
+    fn test_put_object_content_md5() {
+        use crate::{
+            multipart_common::build_initiate_multipart_uploads_request,
+            object_common::{build_put_object_request, PutObjectOptionsBuilder},
+            util::content_md5_base64,
+            RequestBody,
+        };
+
+        let body = || RequestBody::Bytes(b"hello".as_slice().into());
+
+        let options = PutObjectOptionsBuilder::new().content_md5("abc").build();
+        let request = build_put_object_request("yuanyq", "a.txt", body(), &Some(options.clone())).unwrap();
+        assert_eq!(Some("abc"), request.headers.get("content-md5").map(|s| s.as_str()));
+
+        let request = build_put_object_request("yuanyq", "a.txt", body(), &None).unwrap();
+        assert!(!request.headers.contains_key("content-md5"));
+
+        let computed = PutObjectOptionsBuilder::new().compute_content_md5(true).build();
+        let request = build_put_object_request("yuanyq", "a.txt", body(), &Some(computed.clone())).unwrap();
+        assert_eq!(Some(content_md5_base64(b"hello")), request.headers.get("content-md5").cloned());
+
+        // explicit value wins
+        let both = PutObjectOptionsBuilder::new().content_md5("abc").compute_content_md5(true).build();
+        let request = build_put_object_request("yuanyq", "a.txt", body(), &Some(both)).unwrap();
+        assert_eq!(Some("abc"), request.headers.get("content-md5").map(|s| s.as_str()));
+
+        let file = std::env::temp_dir().join("ali-oss-rs-test-put-object-content-md5.txt");
+        std::fs::write(&file, b"abchelloxyz").unwrap();
+        let request = build_put_object_request("yuanyq", "a.txt", RequestBody::File(file.clone(), Some(3..8)), &Some(computed.clone())).unwrap();
+        assert_eq!(Some(content_md5_base64(b"hello")), request.headers.get("content-md5").cloned());
+        std::fs::remove_file(&file).unwrap();
+
+        let request = build_initiate_multipart_uploads_request("yuanyq", "a.txt", &Some(options)).unwrap();
+        assert!(!request.headers.contains_key("content-md5"));
+    }
+
+    #[test]
+    fn test_build_resume_get_object_options() {
+        use crate::object_common::{build_resume_get_object_options, check_resilient_download_options, GetObjectOptionsBuilder};
+
+        assert!(check_resilient_download_options(&None).is_ok());
+        assert!(check_resilient_download_options(&Some(GetObjectOptionsBuilder::new().version_id("v1").build())).is_ok());
+        assert!(check_resilient_download_options(&Some(GetObjectOptionsBuilder::new().range("bytes=0-9").build())).is_err());
+        assert!(check_resilient_download_options(&Some(GetObjectOptionsBuilder::new().accept_encoding("gzip").build())).is_err());
+
+        // 第一次请求失败且没有拿到 etag 时，使用原始的参数重新请求
+        let options = Some(GetObjectOptionsBuilder::new().version_id("v1").build());
+        let ret = build_resume_get_object_options(&options, 0, None).unwrap().unwrap();
+        assert!(ret.range.is_none());
+        assert!(ret.if_match.is_none());
+        assert_eq!(Some("v1".to_string()), ret.version_id);
+
+        assert!(build_resume_get_object_options(&None, 0, None).unwrap().is_none());
+        assert!(build_resume_get_object_options(&None, 100, None).is_err());
+
+        let ret = build_resume_get_object_options(&options, 100, Some("\"ABC\"")).unwrap().unwrap();
+        assert_eq!(Some("bytes=100-".to_string()), ret.range);
+        assert_eq!(Some("\"ABC\"".to_string()), ret.if_match);
+        assert!(ret.standard_range_behavior);
+        assert_eq!(Some("v1".to_string()), ret.version_id);
+    }
+
+    #[test]
+    fn test_copy_object_preserve_source() {
+        use crate::{
+            bucket_common::BucketAcl,
+            object_common::{build_copy_object_request, CopyObjectOptionsBuilder, ObjectAcl},
+        };
+
+        assert!(!CopyObjectOptionsBuilder::new().build().preserve_source);
+
+        // `preserve_source` 只影响客户端的行为，不会产生额外的请求头
+        let options = CopyObjectOptionsBuilder::new().preserve_source(true).build();
+        assert!(options.preserve_source);
+        let request = build_copy_object_request("src-bucket", "a.txt", "dest-bucket", "b.txt", &Some(options)).unwrap();
+        assert!(!request.headers.contains_key("x-oss-object-acl"));
+        assert!(!request.headers.contains_key("x-oss-storage-class"));
+
+        assert_eq!(ObjectAcl::PublicRead, ObjectAcl::from(BucketAcl::PublicRead));
+        assert_eq!(ObjectAcl::PublicReadWrite, ObjectAcl::from(BucketAcl::PublicReadWrite));
+        assert_eq!(ObjectAcl::Private, ObjectAcl::from(BucketAcl::Private));
+    }
+
+    #[test]
+    fn test_utf8_metadata_round_trip() {
+        use crate::{
+            object_common::{build_put_object_request, ObjectMetadata, PutObjectOptionsBuilder},
+            request::RequestBody,
+        };
+
+        let options = PutObjectOptionsBuilder::new().metadata("x-oss-meta-greeting", "hello 世界").build();
+        let request = build_put_object_request("yuanyq", "a.txt", RequestBody::Bytes(b"a".to_vec().into()), &Some(options)).unwrap();
+
+        let value = request.headers.get("x-oss-meta-greeting").unwrap();
+        assert!(value.is_ascii());
+        assert!(reqwest::header::HeaderValue::from_str(value).is_ok());
+
+        let metadata = ObjectMetadata::from(request.headers);
+        assert_eq!(Some(&"hello 世界".to_string()), metadata.metadata.get("x-oss-meta-greeting"));
+    }
 }
@@ -2,10 +2,15 @@
 
 use std::collections::HashMap;
 
+use base64::{prelude::BASE64_STANDARD, Engine};
+use chrono::{DateTime, Utc};
+
 use crate::{
     common,
+    error::Error,
     request::{OssRequest, RequestMethod},
-    util,
+    util::{self, explain_invalid_object_key, validate_bucket_name, validate_object_key},
+    Result,
 };
 
 /// Presign options for GET
@@ -33,6 +38,11 @@ pub struct PresignGetOptions {
 
     /// Additional query parameters added to the presigned url
     pub query_parameters: HashMap<String, String>,
+
+    /// Headers signed into the url, e.g. `range`, `if-match`, `x-oss-traffic-limit`.
+    /// The visitor **MUST** send exactly the same headers, so use `Client::presign_get` to get them back.
+    /// Headers other than `content-type`, `content-md5` and `x-oss-*` are declared in `x-oss-additional-headers` query parameter
+    pub headers: HashMap<String, String>,
 }
 
 /// Builder for `PresignGetOptions`
@@ -46,6 +56,7 @@ pub struct PresignGetOptionsBuilder {
     version_id: Option<String>,
     process: Option<String>,
     query_parameters: HashMap<String, String>,
+    headers: HashMap<String, String>,
 }
 
 impl PresignGetOptionsBuilder {
@@ -96,6 +107,11 @@ impl PresignGetOptionsBuilder {
         self
     }
 
+    pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(key.into(), value.into());
+        self
+    }
+
     pub fn build(self) -> PresignGetOptions {
         PresignGetOptions {
             expire_seconds: self.expire_seconds,
@@ -106,26 +122,388 @@ impl PresignGetOptionsBuilder {
             version_id: self.version_id,
             process: self.process,
             query_parameters: self.query_parameters,
+            headers: self.headers,
         }
     }
 }
 
-pub(crate) fn build_presign_get_request(bucket_name: &str, object_key: &str, options: &PresignGetOptions) -> OssRequest {
+/// Presign options for PUT.
+///
+/// `Content-Type`, `Content-MD5` and all `headers` are signed into the url,
+/// the uploader **MUST** send exactly the same headers, otherwise OSS responds `SignatureDoesNotMatch`.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct PresignPutOptions {
+    /// Time to live for this URL in seconds. See [`PresignGetOptions::expire_seconds`]
+    pub expire_seconds: u32,
+
+    /// 上传时必须携带完全相同的 `Content-Type` 请求头
+    pub content_type: Option<String>,
+
+    /// 上传时必须携带完全相同的 `Content-MD5` 请求头，OSS 会据此校验上传的内容
+    pub content_md5: Option<String>,
+
+    /// Other headers signed into the url. e.g. `x-oss-meta-*`, `x-oss-object-acl`, `content-disposition`.
+    /// Headers other than `content-type`, `content-md5` and `x-oss-*` are declared in `x-oss-additional-headers` query parameter
+    pub headers: HashMap<String, String>,
+
+    /// Additional query parameters signed into the url. The uploader does not need to do anything about them
+    pub query_parameters: HashMap<String, String>,
+}
+
+/// Builder for `PresignPutOptions`
+#[derive(Debug, Default)]
+pub struct PresignPutOptionsBuilder {
+    options: PresignPutOptions,
+}
+
+impl PresignPutOptionsBuilder {
+    pub fn new(expire_seconds: u32) -> Self {
+        Self {
+            options: PresignPutOptions {
+                expire_seconds,
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.options.content_type = Some(content_type.into());
+        self
+    }
+
+    pub fn content_md5(mut self, content_md5: impl Into<String>) -> Self {
+        self.options.content_md5 = Some(content_md5.into());
+        self
+    }
+
+    pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.options.headers.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn query_parameter(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.options.query_parameters.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn build(self) -> PresignPutOptions {
+        self.options
+    }
+}
+
+/// Conditions of POST policy for uploading object from browser directly by PostObject
+///
+/// Official document: <https://help.aliyun.com/zh/oss/developer-reference/signature-version-4-recommend>
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct PostPolicyConditions {
+    /// Policy 的有效时间，单位为秒
+    pub expire_seconds: u32,
+
+    /// 上传的 Object 名称必须以该前缀开头，即 `["starts-with", "$key", "<key_prefix>"]`
+    pub key_prefix: Option<String>,
+
+    /// 上传文件大小的范围 `[min, max]`，单位为字节，即 `["content-length-range", min, max]`
+    pub content_length_range: Option<(u64, u64)>,
+
+    /// 其他条件，每一个条件都是一个 JSON 值，
+    /// 例如 `{"x-oss-meta-author": "yuanyq"}` 或者 `["starts-with", "$content-type", "image/"]`
+    pub extra_conditions: Vec<serde_json::Value>,
+}
+
+/// Builder for `PostPolicyConditions`
+#[derive(Debug, Default)]
+pub struct PostPolicyConditionsBuilder {
+    conditions: PostPolicyConditions,
+}
+
+impl PostPolicyConditionsBuilder {
+    pub fn new(expire_seconds: u32) -> Self {
+        Self {
+            conditions: PostPolicyConditions {
+                expire_seconds,
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn key_prefix(mut self, key_prefix: impl Into<String>) -> Self {
+        self.conditions.key_prefix = Some(key_prefix.into());
+        self
+    }
+
+    pub fn content_length_range(mut self, min: u64, max: u64) -> Self {
+        self.conditions.content_length_range = Some((min, max));
+        self
+    }
+
+    pub fn condition(mut self, condition: serde_json::Value) -> Self {
+        self.conditions.extra_conditions.push(condition);
+        self
+    }
+
+    pub fn build(self) -> PostPolicyConditions {
+        self.conditions
+    }
+}
+
+/// Signed POST policy and the form fields required by PostObject.
+/// Post a `multipart/form-data` form with [`PresignedPostPolicy::form_fields`], the `key` field
+/// and the `file` field (which must be the last one) to `url`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct PresignedPostPolicy {
+    /// The url to post the form to, e.g. `https://yuanyq.oss-cn-beijing.aliyuncs.com`
+    pub url: String,
+
+    /// Base64 encoded policy JSON. Form field `policy`
+    pub policy: String,
+
+    /// Form field `x-oss-signature-version`
+    pub signature_version: String,
+
+    /// Form field `x-oss-credential`
+    pub credential: String,
+
+    /// Form field `x-oss-date`
+    pub date: String,
+
+    /// Form field `x-oss-signature`
+    pub signature: String,
+
+    /// Form field `x-oss-security-token`, only present when the client uses STS token
+    pub security_token: Option<String>,
+
+    /// 表单中的 `key` 字段必须以该前缀开头
+    pub key_prefix: Option<String>,
+}
+
+impl PresignedPostPolicy {
+    /// All signed form fields, except `key` and `file`
+    pub fn form_fields(&self) -> HashMap<String, String> {
+        let mut fields = HashMap::from([
+            ("policy".to_string(), self.policy.clone()),
+            ("x-oss-signature-version".to_string(), self.signature_version.clone()),
+            ("x-oss-credential".to_string(), self.credential.clone()),
+            ("x-oss-date".to_string(), self.date.clone()),
+            ("x-oss-signature".to_string(), self.signature.clone()),
+        ]);
+
+        if let Some(s) = &self.security_token {
+            fields.insert("x-oss-security-token".to_string(), s.clone());
+        }
+
+        fields
+    }
+}
+
+/// Build the POST policy JSON. The signing fields (`x-oss-credential`, `x-oss-date` and so on)
+/// must be included in the conditions as well as in the form
+pub(crate) fn build_post_policy(
+    bucket_name: &str,
+    conditions: &PostPolicyConditions,
+    credential: &str,
+    now: DateTime<Utc>,
+    security_token: Option<&str>,
+) -> Result<String> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+    }
+
+    if conditions.expire_seconds == 0 {
+        return Err(Error::Other("invalid expire seconds: 0".to_string()));
+    }
+
+    let mut items = vec![
+        serde_json::json!({ "bucket": bucket_name }),
+        serde_json::json!({ "x-oss-signature-version": common::SIGNATURE_VERSION }),
+        serde_json::json!({ "x-oss-credential": credential }),
+        serde_json::json!({ "x-oss-date": now.format("%Y%m%dT%H%M%SZ").to_string() }),
+    ];
+
+    if let Some(s) = security_token {
+        items.push(serde_json::json!({ "x-oss-security-token": s }));
+    }
+
+    if let Some(s) = &conditions.key_prefix {
+        items.push(serde_json::json!(["starts-with", "$key", s]));
+    }
+
+    if let Some((min, max)) = conditions.content_length_range {
+        if min > max {
+            return Err(Error::Other(format!("invalid content length range: [{}, {}]", min, max)));
+        }
+        items.push(serde_json::json!(["content-length-range", min, max]));
+    }
+
+    items.extend(conditions.extra_conditions.iter().cloned());
+
+    let expiration = now + chrono::Duration::seconds(conditions.expire_seconds as i64);
+
+    let policy = serde_json::json!({
+        "expiration": expiration.format("%Y-%m-%dT%H:%M:%S.000Z").to_string(),
+        "conditions": items,
+    });
+
+    Ok(serde_json::to_string(&policy)?)
+}
+
+/// Build the POST policy and sign it for both clients. `sign` calculates the signature of the base64 encoded policy
+/// with the date string (`yyyyMMdd`). The `url` of the returned value is empty, the client fills it with the bucket url
+pub(crate) fn sign_post_policy<F>(
+    bucket_name: &str,
+    conditions: PostPolicyConditions,
+    access_key_id: &str,
+    region: &str,
+    security_token: Option<&str>,
+    now: DateTime<Utc>,
+    sign: F,
+) -> Result<PresignedPostPolicy>
+where
+    F: FnOnce(&str, &str) -> String,
+{
+    let date_time_string = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let date_string = &date_time_string[..8];
+
+    let credential = format!("{}/{}/{}/oss/aliyun_v4_request", access_key_id, date_string, region);
+
+    let policy = build_post_policy(bucket_name, &conditions, &credential, now, security_token)?;
+    let policy = BASE64_STANDARD.encode(policy.as_bytes());
+
+    let signature = sign(&policy, date_string);
+
+    Ok(PresignedPostPolicy {
+        url: String::new(),
+        policy,
+        signature_version: common::SIGNATURE_VERSION.to_string(),
+        credential,
+        date: date_time_string,
+        signature,
+        security_token: security_token.map(|s| s.to_string()),
+        key_prefix: conditions.key_prefix,
+    })
+}
+
+/// Build a request with query parameters required by presigned url, and no headers set
+fn build_presign_request(method: RequestMethod, bucket_name: &str, object_key: &str, expire_seconds: u32) -> OssRequest {
     let mut request = OssRequest::new()
-        .method(RequestMethod::Get)
+        .method(method)
         .bucket(bucket_name)
         .object(object_key)
-        .add_query("x-oss-expires", options.expire_seconds.to_string())
+        .add_query("x-oss-expires", expire_seconds.to_string())
         .add_query("x-oss-signature-version", common::SIGNATURE_VERSION);
 
     // move the `x-oss-date` from header which is set in `RequestBuilder::new()`
     let oss_date = request.headers_mut().remove("x-oss-date").unwrap_or(util::get_iso8601_date_time_string());
     request = request.add_query("x-oss-date", oss_date);
 
-    // clear all other headers because this is a get request
-    // and we do not support additional request header included in signature calculation so far.
     request.headers_mut().clear();
 
+    request
+}
+
+pub(crate) fn build_presign_put_request(bucket_name: &str, object_key: &str, options: &PresignPutOptions) -> OssRequest {
+    let mut request = build_presign_request(RequestMethod::Put, bucket_name, object_key, options.expire_seconds);
+
+    if let Some(s) = &options.content_type {
+        request = request.add_header("content-type", s);
+    }
+
+    if let Some(s) = &options.content_md5 {
+        request = request.add_header("content-md5", s);
+    }
+
+    for (k, v) in &options.query_parameters {
+        request = request.add_query(k, v);
+    }
+
+    add_presign_headers(request, &options.headers)
+}
+
+/// Add headers which are signed into the url.
+/// Must be called after all other headers are added, because `x-oss-additional-headers` is calculated here
+fn add_presign_headers(mut request: OssRequest, headers: &HashMap<String, String>) -> OssRequest {
+    for (k, v) in headers {
+        let k = k.to_lowercase();
+        // `content-type`, `content-md5` 和 `x-oss-*` 默认参与签名，其他请求头需要通过 `x-oss-additional-headers` 声明
+        let additional = !(k == "content-type" || k == "content-md5" || k.starts_with("x-oss-"));
+        request = request.add_header_ext(&k, v, additional);
+    }
+
+    let additional_headers = request.build_additional_headers();
+    if !additional_headers.is_empty() {
+        request = request.add_query("x-oss-additional-headers", additional_headers);
+    }
+
+    request
+}
+
+/// Build presigned request for uploading a part of multipart upload.
+/// `partNumber` and `uploadId` are query parameters, so they are signed in the url
+pub(crate) fn build_presign_upload_part_request(
+    bucket_name: &str,
+    object_key: &str,
+    upload_id: &str,
+    part_number: u32,
+    expire_seconds: u32,
+) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+    }
+
+    if !validate_object_key(object_key) {
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
+    }
+
+    if !(1..=10000).contains(&part_number) {
+        return Err(Error::Other(format!(
+            "invalid part number: {}. part number should be in range [1, 10000]",
+            part_number
+        )));
+    }
+
+    if upload_id.is_empty() {
+        return Err(Error::Other("invalid upload id. upload id must not be empty".to_string()));
+    }
+
+    let request = build_presign_request(RequestMethod::Put, bucket_name, object_key, expire_seconds)
+        .add_query("partNumber", part_number.to_string())
+        .add_query("uploadId", upload_id);
+
+    Ok(request)
+}
+
+/// Build presigned GET request with `x-oss-process` query, e.g. for sharing resized thumbnail of a private image.
+/// `x-oss-process` is a query parameter, so it is signed in the url
+pub(crate) fn build_presign_process_request(bucket_name: &str, object_key: &str, process: &str, expire_seconds: u32) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+    }
+
+    if !validate_object_key(object_key) {
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
+    }
+
+    if process.trim().is_empty() {
+        return Err(Error::Other("invalid process. process must not be empty".to_string()));
+    }
+
+    let options = PresignGetOptions {
+        expire_seconds,
+        process: Some(process.to_string()),
+        ..Default::default()
+    };
+
+    Ok(build_presign_get_request(bucket_name, object_key, &options))
+}
+
+pub(crate) fn build_presign_get_request(bucket_name: &str, object_key: &str, options: &PresignGetOptions) -> OssRequest {
+    let mut request = build_presign_request(RequestMethod::Get, bucket_name, object_key, options.expire_seconds);
+
     if let Some(s) = &options.response_content_type {
         request = request.add_query("response-content-type", s);
     }
@@ -156,5 +534,201 @@ pub(crate) fn build_presign_get_request(bucket_name: &str, object_key: &str, opt
         }
     }
 
-    request
+    add_presign_headers(request, &options.headers)
+}
+
+#[cfg(test)]
+mod test_presign_common {
+    use super::{
+        build_post_policy, build_presign_get_request, build_presign_process_request, build_presign_put_request, build_presign_upload_part_request,
+        sign_post_policy, PostPolicyConditionsBuilder, PresignGetOptionsBuilder, PresignPutOptionsBuilder, PresignedPostPolicy,
+    };
+    use base64::{prelude::BASE64_STANDARD, Engine};
+
+    use crate::request::RequestMethod;
+
+    #[test]
+    fn test_build_presign_put_request() {
+        let options = PresignPutOptionsBuilder::new(3600)
+            .content_type("image/webp")
+            .content_md5("eB5eJF1ptWaXm4bijSPyxw==")
+            .header("x-oss-meta-author", "yuanyq")
+            .header("Content-Disposition", "attachment")
+            .build();
+
+        let request = build_presign_put_request("yuanyq", "rust-sdk-test/test.webp", &options);
+
+        assert_eq!(RequestMethod::Put, request.method);
+        assert_eq!(4, request.headers.len());
+        assert_eq!(Some(&"image/webp".to_string()), request.headers.get("content-type"));
+        assert_eq!(Some(&"attachment".to_string()), request.headers.get("content-disposition"));
+        assert_eq!(Some(&"content-disposition".to_string()), request.query.get("x-oss-additional-headers"));
+        assert_eq!(Some(&"3600".to_string()), request.query.get("x-oss-expires"));
+        assert!(request.query.contains_key("x-oss-date"));
+
+        // 只有默认参与签名的请求头时不需要 `x-oss-additional-headers`
+        let options = PresignPutOptionsBuilder::new(3600).content_type("image/webp").build();
+        let request = build_presign_put_request("yuanyq", "rust-sdk-test/test.webp", &options);
+        assert!(!request.query.contains_key("x-oss-additional-headers"));
+
+        let options = PresignPutOptionsBuilder::new(3600)
+            .header("x-oss-server-side-encryption", "AES256")
+            .query_parameter("x-oss-traffic-limit", "819200")
+            .build();
+        let request = build_presign_put_request("yuanyq", "rust-sdk-test/test.webp", &options);
+        assert_eq!(Some(&"AES256".to_string()), request.headers.get("x-oss-server-side-encryption"));
+        assert_eq!(Some(&"819200".to_string()), request.query.get("x-oss-traffic-limit"));
+        assert!(!request.query.contains_key("x-oss-additional-headers"));
+    }
+
+    #[test]
+    fn test_build_presign_get_request() {
+        let options = PresignGetOptionsBuilder::new(3600)
+            .version_id("CAEQNhiBgMDJgZCA0BYiIDc4MGZjZGI2OTBjOTRmNTE5NmU5NmFhZjhjYmY0****")
+            .response_content_disposition("attachment; filename=test.webp")
+            .header("Range", "bytes=0-1023")
+            .header("x-oss-range-behavior", "standard")
+            .build();
+
+        let request = build_presign_get_request("yuanyq", "rust-sdk-test/test.webp", &options);
+
+        assert_eq!(RequestMethod::Get, request.method);
+        assert_eq!(2, request.headers.len());
+        assert_eq!(Some(&"bytes=0-1023".to_string()), request.headers.get("range"));
+        assert_eq!(Some(&"range".to_string()), request.query.get("x-oss-additional-headers"));
+        assert_eq!(
+            Some(&"CAEQNhiBgMDJgZCA0BYiIDc4MGZjZGI2OTBjOTRmNTE5NmU5NmFhZjhjYmY0****".to_string()),
+            request.query.get("versionId")
+        );
+        assert_eq!(
+            Some(&"attachment; filename=test.webp".to_string()),
+            request.query.get("response-content-disposition")
+        );
+
+        let request = build_presign_get_request("yuanyq", "rust-sdk-test/test.webp", &PresignGetOptionsBuilder::new(3600).build());
+        assert!(request.headers.is_empty());
+        assert!(!request.query.contains_key("x-oss-additional-headers"));
+    }
+
+    #[test]
+    fn test_build_presign_upload_part_request() {
+        let request = build_presign_upload_part_request("yuanyq", "rust-sdk-test/big.zip", "0004B9894A22E5B1888A1E29F823****", 3, 600).unwrap();
+
+        assert_eq!(RequestMethod::Put, request.method);
+        assert!(request.headers.is_empty());
+        assert_eq!(Some(&"3".to_string()), request.query.get("partNumber"));
+        assert_eq!(Some(&"600".to_string()), request.query.get("x-oss-expires"));
+
+        // 分片号和 upload id 是签名的一部分
+        let canonical_request = request.build_canonical_request();
+        assert!(canonical_request.contains("partNumber=3"));
+        assert!(canonical_request.contains("uploadId=0004B9894A22E5B1888A1E29F823%2A%2A%2A%2A"));
+
+        assert!(build_presign_upload_part_request("yuanyq", "a.zip", "upload-id", 0, 600).is_err());
+        assert!(build_presign_upload_part_request("yuanyq", "a.zip", "upload-id", 10001, 600).is_err());
+        assert!(build_presign_upload_part_request("yuanyq", "a.zip", "", 1, 600).is_err());
+    }
+
+    #[test]
+    fn test_build_presign_process_request() {
+        let request = build_presign_process_request("yuanyq", "rust-sdk-test/test-1.webp", "image/resize,w_100", 3600).unwrap();
+
+        assert_eq!(RequestMethod::Get, request.method);
+        assert!(request.headers.is_empty());
+        assert_eq!(Some(&"3600".to_string()), request.query.get("x-oss-expires"));
+
+        // 处理参数是签名的一部分，否则访问链接时返回 403
+        let canonical_request = request.build_canonical_request();
+        assert!(canonical_request.contains("x-oss-process=image%2Fresize%2Cw_100"));
+
+        assert!(build_presign_process_request("yuanyq", "a.jpg", "", 3600).is_err());
+        assert!(build_presign_process_request("INVALID_BUCKET", "a.jpg", "image/resize,w_100", 3600).is_err());
+    }
+
+    #[test]
+    fn test_build_post_policy() {
+        let now = chrono::DateTime::parse_from_rfc3339("2025-03-01T08:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let credential = "ak/20250301/cn-beijing/oss/aliyun_v4_request";
+
+        let conditions = PostPolicyConditionsBuilder::new(3600)
+            .key_prefix("uploads/")
+            .content_length_range(1, 1024)
+            .condition(serde_json::json!({ "x-oss-meta-author": "yuanyq" }))
+            .build();
+
+        let policy = build_post_policy("yuanyq", &conditions, credential, now, Some("sts")).unwrap();
+        let policy: serde_json::Value = serde_json::from_str(&policy).unwrap();
+
+        assert_eq!("2025-03-01T09:00:00.000Z", policy["expiration"]);
+        assert_eq!(
+            serde_json::json!([
+                { "bucket": "yuanyq" },
+                { "x-oss-signature-version": "OSS4-HMAC-SHA256" },
+                { "x-oss-credential": credential },
+                { "x-oss-date": "20250301T080000Z" },
+                { "x-oss-security-token": "sts" },
+                ["starts-with", "$key", "uploads/"],
+                ["content-length-range", 1, 1024],
+                { "x-oss-meta-author": "yuanyq" },
+            ]),
+            policy["conditions"]
+        );
+
+        let conditions = PostPolicyConditionsBuilder::new(3600).content_length_range(10, 1).build();
+        assert!(build_post_policy("yuanyq", &conditions, credential, now, None).is_err());
+        assert!(build_post_policy("yuanyq", &PostPolicyConditionsBuilder::new(0).build(), credential, now, None).is_err());
+        assert!(build_post_policy("-invalid", &PostPolicyConditionsBuilder::new(60).build(), credential, now, None).is_err());
+
+        let signed = PresignedPostPolicy {
+            url: "https://yuanyq.oss-cn-beijing.aliyuncs.com".to_string(),
+            policy: "cG9saWN5".to_string(),
+            signature_version: "OSS4-HMAC-SHA256".to_string(),
+            credential: credential.to_string(),
+            date: "20250301T080000Z".to_string(),
+            signature: "sig".to_string(),
+            security_token: None,
+            key_prefix: None,
+        };
+        let fields = signed.form_fields();
+        assert_eq!(5, fields.len());
+        assert_eq!(Some(&"sig".to_string()), fields.get("x-oss-signature"));
+        assert!(!fields.contains_key("x-oss-security-token"));
+    }
+
+    #[test]
+    fn test_sign_post_policy() {
+        let now = chrono::DateTime::parse_from_rfc3339("2025-03-01T08:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let conditions = PostPolicyConditionsBuilder::new(3600).key_prefix("uploads/").build();
+
+        let signed = sign_post_policy("yuanyq", conditions, "ak", "cn-beijing", Some("sts"), now, |policy, date| {
+            assert_eq!("20250301", date);
+            format!("sig-{}", policy.len())
+        })
+        .unwrap();
+
+        assert_eq!("ak/20250301/cn-beijing/oss/aliyun_v4_request", signed.credential);
+        assert_eq!("20250301T080000Z", signed.date);
+        assert_eq!(format!("sig-{}", signed.policy.len()), signed.signature);
+        assert_eq!(Some("sts".to_string()), signed.security_token);
+        assert_eq!(Some("uploads/".to_string()), signed.key_prefix);
+
+        let policy = BASE64_STANDARD.decode(&signed.policy).unwrap();
+        let policy: serde_json::Value = serde_json::from_slice(&policy).unwrap();
+        assert_eq!("2025-03-01T09:00:00.000Z", policy["expiration"]);
+
+        assert!(sign_post_policy(
+            "-invalid",
+            PostPolicyConditionsBuilder::new(60).build(),
+            "ak",
+            "cn-beijing",
+            None,
+            now,
+            |_, _| String::new()
+        )
+        .is_err());
+    }
 }
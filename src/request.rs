@@ -3,6 +3,7 @@ use std::{
     fmt::Display,
     ops::Range,
     path::PathBuf,
+    time::Duration,
 };
 
 use crate::{common, util};
@@ -13,10 +14,14 @@ pub enum RequestBody {
     #[default]
     Empty,
     Text(String),
-    Bytes(Vec<u8>),
+    Bytes(bytes::Bytes),
 
     /// `.1` is used when doing multipart uploads from file.
     File(PathBuf, Option<Range<u64>>),
+
+    /// Discontiguous buffers sent one after another without concatenating.
+    /// `content-length` should be the sum of lengths of the chunks
+    Chunks(Vec<bytes::Bytes>),
 }
 
 /// Request method
@@ -54,6 +59,7 @@ impl From<RequestMethod> for reqwest::Method {
 }
 
 /// Raw oss request
+#[derive(Debug, Clone)]
 pub struct OssRequest {
     pub bucket_name: String,
     pub object_key: String,
@@ -67,6 +73,9 @@ pub struct OssRequest {
     pub query: HashMap<String, String>,
 
     pub body: RequestBody,
+
+    /// 本次请求的超时时间，设置后会覆盖客户端的 `timeout`
+    pub timeout: Option<Duration>,
 }
 
 impl Default for OssRequest {
@@ -95,9 +104,16 @@ impl OssRequest {
             additional_headers: HashSet::new(),
             query: HashMap::new(),
             body: RequestBody::Empty,
+            timeout: None,
         }
     }
 
+    /// Set `x-oss-date` to current time. Called before each retry attempt,
+    /// so a retried request is not signed with the time of the first attempt
+    pub(crate) fn refresh_date(&mut self) {
+        self.headers.insert("x-oss-date".to_string(), util::get_iso8601_date_time_string());
+    }
+
     /// Set request method.
     pub fn method(mut self, m: RequestMethod) -> Self {
         self.method = m;
@@ -117,7 +133,8 @@ impl OssRequest {
     }
 
     /// Add header to the builder and **DO NOT** treat this header as additional header.
-    /// The header name should be lowercase string. e.g. `content-type`, `x-oss-meta-my-key`.
+    /// Header names are case-insensitive and stored in lowercase, e.g. `content-type`, `x-oss-meta-my-key`.
+    /// Adding a header with the same name replaces the previous value.
     pub fn add_header<S1, S2>(self, k: S1, v: S2) -> Self
     where
         S1: AsRef<str>,
@@ -126,7 +143,9 @@ impl OssRequest {
         self.add_header_ext(k, v, false)
     }
 
-    /// Add header to the builder. The header name should be lowercase string. e.g. `content-type`, `x-oss-meta-my-key`.
+    /// Add header to the builder. Header names are case-insensitive and stored in lowercase, e.g. `content-type`, `x-oss-meta-my-key`.
+    /// Adding a header with the same name replaces the previous value, so no duplicated headers are sent.
+    /// Values of `x-oss-meta-*` headers containing non-ASCII characters are encoded as RFC 2047 `=?UTF-8?B?...?=`
     ///
     /// `addtional_header` identifies if the header name should be added to additional header,
     /// and being used when calculating canonical request and signature
@@ -135,9 +154,17 @@ impl OssRequest {
         S1: AsRef<str>,
         S2: AsRef<str>,
     {
-        self.headers.insert(k.as_ref().to_string(), v.as_ref().to_string());
+        let key = k.as_ref().to_lowercase();
+
+        let value = if key.starts_with("x-oss-meta-") {
+            util::encode_header_value(v.as_ref())
+        } else {
+            v.as_ref().to_string()
+        };
+
+        self.headers.insert(key.clone(), value);
         if addtional_header {
-            self.additional_headers.insert(k.as_ref().to_lowercase());
+            self.additional_headers.insert(key);
         }
 
         self
@@ -171,6 +198,12 @@ impl OssRequest {
         self
     }
 
+    /// Set the total timeout of this request, overriding the timeout of the client.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// helper method for [`Self::body`]. only the body is set and left `content-length`, `content-type` untouched.
     pub fn text_body(self, text: impl Into<String>) -> Self {
         self.body(RequestBody::Text(text.into()))
@@ -178,7 +211,7 @@ impl OssRequest {
 
     #[allow(dead_code)]
     /// helper method for [`Self::body`]. only the body is set and left `content-length`, `content-type` untouched.
-    pub fn bytes_body(self, bytes: impl Into<Vec<u8>>) -> Self {
+    pub fn bytes_body(self, bytes: impl Into<bytes::Bytes>) -> Self {
         self.body(RequestBody::Bytes(bytes.into()))
     }
 
@@ -236,6 +269,21 @@ impl OssRequest {
         }
     }
 
+    /// The resource string which is signed, it consists of the canonical uri and the canonical query string.
+    /// Useful for debugging access control problems or correlating requests with OSS access logs and policy conditions.
+    ///
+    /// e.g. `/examplebucket/exampleobject?acl`, `/examplebucket/` or `/`
+    pub fn canonical_resource(&self) -> String {
+        let canonical_uri = self.build_canonical_uri();
+        let canonical_query = self.build_canonical_query_string();
+
+        if canonical_query.is_empty() {
+            canonical_uri
+        } else {
+            format!("{}?{}", canonical_uri, canonical_query)
+        }
+    }
+
     /// Build the uri part of real http request
     /// The returned string starts with "/"
     pub(crate) fn build_request_uri(&self) -> String {
@@ -341,7 +389,10 @@ impl OssRequest {
         let additional_headers = self.build_additional_headers();
         let method = self.method.to_string();
 
-        format!("{method}\n{canonical_uri}\n{canonical_query}\n{canonical_headers}\n{additional_headers}\nUNSIGNED-PAYLOAD")
+        // 如果设置了 `x-oss-content-sha256` 为请求体的 SHA-256 值，则由服务端校验请求体
+        let hashed_payload = self.headers.get("x-oss-content-sha256").map(|s| s.as_str()).unwrap_or(common::UNSIGNED_PAYLOAD);
+
+        format!("{method}\n{canonical_uri}\n{canonical_query}\n{canonical_headers}\n{additional_headers}\n{hashed_payload}")
     }
 
     /// 在构建要签名的认证字符串的时候，才生成 `x-oss-date` 头，并放到 headers 里面
@@ -364,3 +415,51 @@ impl OssRequest {
         )
     }
 }
+
+#[cfg(test)]
+mod test_request {
+    use super::{OssRequest, RequestMethod};
+
+    #[test]
+    fn test_add_header_case_insensitive() {
+        let request = OssRequest::new()
+            .add_header("Content-Type", "text/plain")
+            .add_header("content-type", "application/json")
+            .add_header_ext("X-OSS-Meta-A", "1", true)
+            .add_header("x-oss-meta-a", "2");
+
+        assert_eq!(1, request.headers.keys().filter(|k| k.eq_ignore_ascii_case("content-type")).count());
+        assert_eq!(1, request.headers.keys().filter(|k| k.eq_ignore_ascii_case("x-oss-meta-a")).count());
+        assert_eq!(Some("application/json"), request.headers.get("content-type").map(|s| s.as_str()));
+        assert_eq!(Some("2"), request.headers.get("x-oss-meta-a").map(|s| s.as_str()));
+        assert!(request.additional_headers.contains("x-oss-meta-a"));
+    }
+
+    #[test]
+    fn test_refresh_date() {
+        let mut request = OssRequest::new().add_header("x-oss-date", "20200101T000000Z");
+        request.refresh_date();
+
+        let date = request.headers.get("x-oss-date").unwrap();
+        assert_ne!("20200101T000000Z", date);
+        assert_eq!(16, date.len());
+    }
+
+    #[test]
+    fn test_canonical_resource() {
+        assert_eq!("/", OssRequest::new().canonical_resource());
+        assert_eq!("/examplebucket/", OssRequest::new().bucket("examplebucket").canonical_resource());
+
+        let request = OssRequest::new()
+            .method(RequestMethod::Put)
+            .bucket("examplebucket")
+            .object("path/to/测试 file.txt")
+            .add_query("versionId", "CAEQ+1/2=")
+            .add_query("acl", "");
+
+        assert_eq!(
+            "/examplebucket/path/to/%E6%B5%8B%E8%AF%95%20file.txt?acl&versionId=CAEQ%2B1%2F2%3D",
+            request.canonical_resource()
+        );
+    }
+}
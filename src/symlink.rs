@@ -2,7 +2,7 @@
 
 use async_trait::async_trait;
 
-use crate::symlink_common::{build_get_symlink_request, build_put_symlink_request, GetSymlinkOptions, PutSymlinkOptions, PutSymlinkResult};
+use crate::symlink_common::{build_get_symlink_request, build_put_symlink_request, GetSymlinkOptions, GetSymlinkResult, PutSymlinkOptions, PutSymlinkResult};
 use crate::{Client, Result};
 
 #[async_trait]
@@ -24,10 +24,10 @@ pub trait ObjectSymlinkOperations {
         S2: AsRef<str> + Send,
         S3: AsRef<str> + Send;
 
-    /// Get a symlink object. The returned string is the target object key
+    /// Get a symlink object. The result contains the target object key, the etag and user defined metadata of the symlink
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getsymlink>
-    async fn get_symlink<S1, S2>(&self, bucket_name: S1, symlink_object_key: S2, options: Option<GetSymlinkOptions>) -> Result<String>
+    async fn get_symlink<S1, S2>(&self, bucket_name: S1, symlink_object_key: S2, options: Option<GetSymlinkOptions>) -> Result<GetSymlinkResult>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send;
@@ -57,19 +57,17 @@ impl ObjectSymlinkOperations for Client {
         Ok(headers.into())
     }
 
-    /// Get a symlink object. The returned string is the target object key
+    /// Get a symlink object. The result contains the target object key, the etag and user defined metadata of the symlink
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getsymlink>
-    async fn get_symlink<S1, S2>(&self, bucket_name: S1, symlink_object_key: S2, options: Option<GetSymlinkOptions>) -> Result<String>
+    async fn get_symlink<S1, S2>(&self, bucket_name: S1, symlink_object_key: S2, options: Option<GetSymlinkOptions>) -> Result<GetSymlinkResult>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send,
     {
         let request = build_get_symlink_request(bucket_name.as_ref(), symlink_object_key.as_ref(), &options)?;
         let (headers, _) = self.do_request::<()>(request).await?;
-        let object_key = headers.get("x-oss-symlink-target").unwrap_or(&String::new()).to_string();
-
-        Ok(urlencoding::decode(&object_key)?.to_string())
+        GetSymlinkResult::from_headers(headers)
     }
 }
 
@@ -139,7 +137,8 @@ mod test_symlink_async {
         let response = client.get_symlink(bucket_name, &link_name, None).await;
         assert!(response.is_ok());
         let ret = response.unwrap();
-        assert_eq!(ret, object_key);
+        assert_eq!(ret.target_object_key, object_key);
+        assert_eq!(Some(&"meta value b".to_string()), ret.metadata.get("x-oss-meta-a"));
 
         let response = client.delete_object(bucket_name, &link_name, None).await;
         assert!(response.is_ok());
@@ -5,7 +5,7 @@ use crate::{
     error::Error,
     object_common::ObjectAcl,
     request::{OssRequest, RequestMethod},
-    util::{validate_bucket_name, validate_meta_key, validate_object_key},
+    util::{decode_header_value, explain_invalid_object_key, sanitize_etag, validate_bucket_name, validate_meta_key, validate_object_key},
     Result,
 };
 
@@ -78,6 +78,44 @@ impl From<HashMap<String, String>> for PutSymlinkResult {
     }
 }
 
+/// Get symlink result
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct GetSymlinkResult {
+    pub request_id: Option<String>,
+
+    /// The target object key, url decoded from `x-oss-symlink-target` header
+    pub target_object_key: String,
+
+    /// The etag of the symbol link itself
+    pub etag: Option<String>,
+
+    /// The version id of the symbol link
+    pub version_id: Option<String>,
+
+    /// `x-oss-meta-` 开头的用户自定义属性
+    pub metadata: HashMap<String, String>,
+}
+
+impl GetSymlinkResult {
+    pub(crate) fn from_headers(mut headers: HashMap<String, String>) -> Result<Self> {
+        let target = headers.remove("x-oss-symlink-target").unwrap_or_default();
+
+        Ok(Self {
+            request_id: headers.remove("x-oss-request-id"),
+            target_object_key: urlencoding::decode(&target)?.to_string(),
+            etag: headers.remove("etag").map(sanitize_etag),
+            version_id: headers.remove("x-oss-version-id"),
+            metadata: headers
+                .into_iter()
+                .filter(|(k, _)| k.starts_with("x-oss-meta-"))
+                .map(|(k, v)| (k, decode_header_value(v)))
+                .collect(),
+        })
+    }
+}
+
 pub(crate) fn build_put_symlink_request(
     bucket_name: &str,
     symlink_object_key: &str,
@@ -89,11 +127,11 @@ pub(crate) fn build_put_symlink_request(
     }
 
     if !validate_object_key(symlink_object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", symlink_object_key)));
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(symlink_object_key))));
     }
 
     if !validate_object_key(target_object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", target_object_key)));
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(target_object_key))));
     }
 
     let mut request = OssRequest::new()
@@ -138,7 +176,7 @@ pub(crate) fn build_get_symlink_request(bucket_name: &str, symlink_object_key: &
     }
 
     if !validate_object_key(symlink_object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", symlink_object_key)));
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(symlink_object_key))));
     }
 
     let mut request = OssRequest::new()
@@ -155,3 +193,28 @@ pub(crate) fn build_get_symlink_request(bucket_name: &str, symlink_object_key: &
 
     Ok(request)
 }
+
+#[cfg(test)]
+mod test_symlink_common {
+    use std::collections::HashMap;
+
+    use super::GetSymlinkResult;
+
+    #[test]
+    fn test_get_symlink_result_from_headers() {
+        let headers = HashMap::from([
+            ("x-oss-request-id".to_string(), "5650BD72207FB30443962F9A".to_string()),
+            ("x-oss-symlink-target".to_string(), "a/%E4%B8%AD%E6%96%87.webp".to_string()),
+            ("etag".to_string(), "\"A797938C31D59EDD08D86188F6D5B872\"".to_string()),
+            ("x-oss-meta-a".to_string(), "meta value b".to_string()),
+            ("content-length".to_string(), "0".to_string()),
+        ]);
+
+        let ret = GetSymlinkResult::from_headers(headers).unwrap();
+        assert_eq!(Some("5650BD72207FB30443962F9A".to_string()), ret.request_id);
+        assert_eq!("a/中文.webp", ret.target_object_key);
+        assert_eq!(Some("A797938C31D59EDD08D86188F6D5B872".to_string()), ret.etag);
+        assert!(ret.version_id.is_none());
+        assert_eq!(HashMap::from([("x-oss-meta-a".to_string(), "meta value b".to_string())]), ret.metadata);
+    }
+}
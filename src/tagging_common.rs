@@ -5,7 +5,7 @@ use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
 use crate::common::VersionIdOnlyOptions;
 use crate::error::Error;
 use crate::request::{OssRequest, RequestMethod};
-use crate::util::{validate_bucket_name, validate_object_key, validate_tag_key, validate_tag_value};
+use crate::util::{explain_invalid_object_key, validate_bucket_name, validate_object_key, validate_tag_key, validate_tag_value};
 use crate::{common, Result};
 
 pub type PutObjectTagOptions = VersionIdOnlyOptions;
@@ -78,7 +78,7 @@ pub(crate) fn build_get_object_tag_request(bucket_name: &str, object_key: &str,
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
     }
 
     let mut request = OssRequest::new()
@@ -107,7 +107,7 @@ pub(crate) fn build_put_object_tag_request(
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
     }
 
     if tags.is_empty() {
@@ -148,7 +148,7 @@ pub(crate) fn build_delete_object_tag_request(bucket_name: &str, object_key: &st
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::Other(format!("invalid object key: {}", explain_invalid_object_key(object_key))));
     }
 
     let mut request = OssRequest::new()
@@ -165,3 +165,47 @@ pub(crate) fn build_delete_object_tag_request(bucket_name: &str, object_key: &st
 
     Ok(request)
 }
+
+#[cfg(test)]
+mod test_tagging_common {
+    use std::collections::HashMap;
+
+    use super::{build_delete_object_tag_request, build_get_object_tag_request, build_put_object_tag_request, parse_tags_from_xml, tags_to_xml};
+    use crate::{common::VersionIdOnlyOptions, request::RequestMethod};
+
+    #[test]
+    fn test_tags_xml_round_trip() {
+        let tags = HashMap::from([("a".to_string(), "1".to_string()), ("b & c".to_string(), "<2>".to_string())]);
+
+        let xml = tags_to_xml(&tags).unwrap();
+        assert!(xml.contains("<Key>b &amp; c</Key>"));
+
+        assert_eq!(tags, parse_tags_from_xml(xml).unwrap());
+    }
+
+    #[test]
+    fn test_build_object_tag_requests() {
+        let options = Some(VersionIdOnlyOptions {
+            version_id: Some("CAEQNhiBgMDJgZCA0BYiIDc4MGZjZGI2OTBjOTRmNTE5NmU5NmFhZjhjYmY0****".to_string()),
+        });
+
+        let request = build_get_object_tag_request("yuanyq", "a.txt", &options).unwrap();
+        assert_eq!(RequestMethod::Get, request.method);
+        assert_eq!(Some(&"".to_string()), request.query.get("tagging"));
+        assert!(request.query.contains_key("versionId"));
+
+        let tags = HashMap::from([("a".to_string(), "1".to_string())]);
+        let request = build_put_object_tag_request("yuanyq", "a.txt", &tags, &options).unwrap();
+        assert_eq!(RequestMethod::Put, request.method);
+        assert!(request.query.contains_key("versionId"));
+
+        let request = build_delete_object_tag_request("yuanyq", "a.txt", &None).unwrap();
+        assert_eq!(RequestMethod::Delete, request.method);
+        assert!(!request.query.contains_key("versionId"));
+
+        assert!(build_put_object_tag_request("yuanyq", "a.txt", &HashMap::new(), &None).is_err());
+
+        let tags = HashMap::from([("".to_string(), "1".to_string())]);
+        assert!(build_put_object_tag_request("yuanyq", "a.txt", &tags, &None).is_err());
+    }
+}
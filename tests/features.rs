@@ -0,0 +1,123 @@
+//! Compile checks of feature gates. Each check references the items behind a feature under the same `#[cfg]`,
+//! so an item gated by the wrong feature fails to build locally, e.g.:
+//!
+//! - `cargo test --test features --no-default-features --features async`
+//! - `cargo test --test features --features blocking,serde-support,time`
+//! - `cargo test --test features --all-features`
+
+/// 异步客户端和所有操作 trait 在任何 feature 组合下都可用，不依赖 `blocking`
+#[test]
+fn test_async_client_build() {
+    use ali_oss_rs::{
+        acl::{BucketAclOperations, ObjectAclOperations},
+        bucket::BucketOperations,
+        cname::CnameOperations,
+        live_channel::LiveChannelOperations,
+        multipart::MultipartUploadsOperations,
+        object::ObjectOperations,
+        symlink::ObjectSymlinkOperations,
+        tagging::ObjectTagOperations,
+        Client, ClientBuilder,
+    };
+
+    fn assert_operations<
+        T: BucketAclOperations
+            + ObjectAclOperations
+            + BucketOperations
+            + CnameOperations
+            + LiveChannelOperations
+            + MultipartUploadsOperations
+            + ObjectOperations
+            + ObjectSymlinkOperations
+            + ObjectTagOperations
+            + Send
+            + Sync,
+    >(
+        _: &T,
+    ) {
+    }
+
+    let client: Client = ClientBuilder::new("access_key_id", "access_key_secret", "oss-cn-hangzhou.aliyuncs.com")
+        .build()
+        .unwrap();
+    assert_operations(&client);
+}
+
+/// `blocking` 模块只在开启 `blocking` feature 时存在
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_client_build() {
+    use ali_oss_rs::blocking::{
+        acl::{BucketAclOperations, ObjectAclOperations},
+        bucket::BucketOperations,
+        cname::CnameOperations,
+        live_channel::LiveChannelOperations,
+        multipart::MultipartUploadsOperations,
+        object::ObjectOperations,
+        symlink::ObjectSymlinkOperations,
+        tagging::ObjectTagOperations,
+        Client, ClientBuilder,
+    };
+
+    fn assert_operations<
+        T: BucketAclOperations
+            + ObjectAclOperations
+            + BucketOperations
+            + CnameOperations
+            + LiveChannelOperations
+            + MultipartUploadsOperations
+            + ObjectOperations
+            + ObjectSymlinkOperations
+            + ObjectTagOperations
+            + Send
+            + Sync,
+    >(
+        _: &T,
+    ) {
+    }
+
+    let client: Client = ClientBuilder::new("access_key_id", "access_key_secret", "oss-cn-hangzhou.aliyuncs.com")
+        .build()
+        .unwrap();
+    assert_operations(&client);
+}
+
+/// 数据类型只在开启 `serde-support` feature 时实现 `Serialize` 和 `Deserialize`，
+/// 字段名只在开启 `serde-camelcase` feature 时使用 camelCase
+#[cfg(feature = "serde-support")]
+#[test]
+fn test_serde_support_build() {
+    use ali_oss_rs::{
+        bucket_common::{BucketDetail, LifecycleConfiguration},
+        multipart_common::{UploadPartCopyRequest, UploadPartRequest},
+        object_common::{ObjectMetadata, PutObjectOptions},
+        serde::{de::DeserializeOwned, Serialize},
+        serde_json,
+    };
+
+    fn assert_serde<T: Serialize + DeserializeOwned>() {}
+
+    assert_serde::<BucketDetail>();
+    assert_serde::<LifecycleConfiguration>();
+    assert_serde::<UploadPartRequest>();
+    assert_serde::<UploadPartCopyRequest>();
+    assert_serde::<ObjectMetadata>();
+    assert_serde::<PutObjectOptions>();
+
+    let value = serde_json::to_value(ObjectMetadata::default()).unwrap();
+    if cfg!(feature = "serde-camelcase") {
+        assert!(value.get("contentLength").is_some());
+    } else {
+        assert!(value.get("content_length").is_some());
+    }
+}
+
+/// `expires_at` 只在开启 `time` feature 时存在
+#[cfg(feature = "time")]
+#[test]
+fn test_time_build() {
+    use ali_oss_rs::object_common::PutObjectOptionsBuilder;
+
+    let options = PutObjectOptionsBuilder::new().expires_at(time::OffsetDateTime::UNIX_EPOCH).build();
+    assert_eq!(Some("Thu, 01 Jan 1970 00:00:00 GMT"), options.expires.as_deref());
+}